@@ -12,14 +12,23 @@ blueprint! {
             component_system().publish_package(&code)
         }
 
-        /// Creates a resource.
+        /// Creates a resource, whose `locked_metadata` can never be updated.
         pub fn new_resource(
             resource_type: ResourceType,
             metadata: HashMap<String, String>,
             access_rules: HashMap<ResourceMethod, (AccessRule, Mutability)>,
             initial_supply: Option<MintParams>,
+            locked_metadata: BTreeSet<String>,
         ) -> (ResourceAddress, Option<Bucket>) {
-            resource_system().new_resource(resource_type, metadata, access_rules, initial_supply)
+            resource_system().new_resource(
+                resource_type,
+                metadata,
+                access_rules,
+                initial_supply,
+                None,
+                None,
+                locked_metadata,
+            )
         }
 
         /// Mints fungible resource. TODO: Remove
@@ -11,12 +11,12 @@ echo "Building packages..."
 
 echo "Publishing artifacts..."
 wasm-opt \
-  -Os -g \
+  -Os -g --mvp-features \
   --strip-debug --strip-dwarf --strip-producers \
   -o ./account.wasm \
   ./account/target/wasm32-unknown-unknown/release/account.wasm
 wasm-opt \
-  -Os -g \
+  -Os -g --mvp-features \
   --strip-debug --strip-dwarf --strip-producers \
   -o ./system.wasm \
   ./system/target/wasm32-unknown-unknown/release/system.wasm
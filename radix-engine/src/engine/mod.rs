@@ -1,6 +1,7 @@
 mod component_objects;
 mod id_allocator;
 mod id_validator;
+mod native_crypto;
 mod process;
 mod track;
 mod wasm_env;
@@ -8,6 +9,7 @@ mod wasm_env;
 pub use component_objects::*;
 pub use id_allocator::*;
 pub use id_validator::*;
+pub use native_crypto::*;
 pub use process::{Process, SNodeState, SystemApi};
 pub use track::{CommitReceipt, Track};
 pub use wasm_env::{EnvModuleResolver, ENGINE_FUNCTION_INDEX, ENGINE_FUNCTION_NAME};
@@ -0,0 +1,174 @@
+use blake2::digest::consts::U32;
+use blake2::Blake2b;
+use sha3::{Digest, Keccak256};
+
+use scrypto::crypto::*;
+
+/// The cost units charged for each native signature verification, regardless of message size.
+pub const VERIFY_SIGNATURE_COST_UNITS: u32 = 2000;
+
+/// The cost units charged for each native hash computation, regardless of data size.
+pub const COMPUTE_HASH_COST_UNITS: u32 = 100;
+
+/// Verifies a signature with the given scheme.
+///
+/// Returns `false` if the public key or the signature is malformed.
+pub fn verify_signature(
+    scheme: SignatureScheme,
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    match scheme {
+        SignatureScheme::EcdsaSecp256k1 => {
+            use k256::ecdsa::signature::Verifier;
+            match (
+                k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key),
+                k256::ecdsa::Signature::try_from(signature),
+            ) {
+                (Ok(pk), Ok(sig)) => pk.verify(message, &sig).is_ok(),
+                _ => false,
+            }
+        }
+        SignatureScheme::EcdsaSecp256r1 => {
+            match (
+                EcdsaPublicKey::try_from(public_key),
+                EcdsaSignature::try_from(signature),
+            ) {
+                (Ok(pk), Ok(sig)) => EcdsaVerifier::verify(message, &pk, &sig),
+                _ => false,
+            }
+        }
+        SignatureScheme::Ed25519 => {
+            use ed25519_dalek::Verifier;
+            match (
+                ed25519_dalek::PublicKey::from_bytes(public_key),
+                ed25519_dalek::Signature::try_from(signature),
+            ) {
+                (Ok(pk), Ok(sig)) => pk.verify(message, &sig).is_ok(),
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Computes the digest of some data with the given hash function.
+pub fn compute_hash(hash_function: HashFunction, data: &[u8]) -> Hash {
+    match hash_function {
+        HashFunction::Keccak256 => {
+            let mut instance = Keccak256::new();
+            instance.update(data);
+            Hash(instance.finalize().into())
+        }
+        HashFunction::Blake2b256 => {
+            let mut instance = Blake2b::<U32>::new();
+            instance.update(data);
+            Hash(instance.finalize().into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scrypto::rust::str::FromStr;
+
+    #[test]
+    fn test_compute_hash() {
+        assert_eq!(
+            compute_hash(HashFunction::Keccak256, &[]),
+            Hash::from_str("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+                .unwrap()
+        );
+        assert_eq!(
+            compute_hash(HashFunction::Blake2b256, &[]),
+            Hash::from_str("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_ecdsa_secp256k1() {
+        use k256::ecdsa::signature::Signer;
+        let private_key = k256::ecdsa::SigningKey::from_bytes(&[7u8; 32]).unwrap();
+        let public_key = private_key.verifying_key().to_bytes();
+        let signature: k256::ecdsa::Signature = private_key.sign(b"Hello Radix");
+
+        let scheme = SignatureScheme::EcdsaSecp256k1;
+        assert!(verify_signature(
+            scheme,
+            &public_key,
+            b"Hello Radix",
+            signature.as_ref()
+        ));
+        assert!(!verify_signature(
+            scheme,
+            &public_key,
+            b"Hello Radiy",
+            signature.as_ref()
+        ));
+        assert!(!verify_signature(
+            scheme,
+            &[1, 2, 3],
+            b"Hello Radix",
+            signature.as_ref()
+        ));
+    }
+
+    #[test]
+    fn test_verify_ecdsa_secp256r1() {
+        let private_key = EcdsaPrivateKey::from_bytes(&[7u8; 32]).unwrap();
+        let public_key = private_key.public_key().to_vec();
+        let signature = private_key.sign(b"Hello Radix").to_vec();
+
+        let scheme = SignatureScheme::EcdsaSecp256r1;
+        assert!(verify_signature(
+            scheme,
+            &public_key,
+            b"Hello Radix",
+            &signature
+        ));
+        assert!(!verify_signature(
+            scheme,
+            &public_key,
+            b"Hello Radiy",
+            &signature
+        ));
+        assert!(!verify_signature(
+            scheme,
+            &public_key,
+            b"Hello Radix",
+            &[0u8; 3]
+        ));
+    }
+
+    #[test]
+    fn test_verify_ed25519() {
+        use ed25519_dalek::Signer;
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[7u8; 32]).unwrap();
+        let public = ed25519_dalek::PublicKey::from(&secret);
+        let signature = ed25519_dalek::Keypair { secret, public }.sign(b"Hello Radix");
+
+        let scheme = SignatureScheme::Ed25519;
+        let public_key = public.to_bytes();
+        let signature = signature.to_bytes();
+        assert!(verify_signature(
+            scheme,
+            &public_key,
+            b"Hello Radix",
+            &signature
+        ));
+        assert!(!verify_signature(
+            scheme,
+            &public_key,
+            b"Hello Radiy",
+            &signature
+        ));
+        assert!(!verify_signature(
+            scheme,
+            &public_key[1..],
+            b"Hello Radix",
+            &signature
+        ));
+    }
+}
@@ -6,6 +6,7 @@ use scrypto::buffer::*;
 use scrypto::core::{SNodeRef, ScryptoActor};
 use scrypto::engine::api::*;
 use scrypto::engine::types::*;
+use scrypto::resource::AccessRule;
 use scrypto::rust::borrow::ToOwned;
 use scrypto::rust::collections::*;
 use scrypto::rust::fmt;
@@ -74,6 +75,13 @@ pub trait SystemApi {
         non_fungible: Option<NonFungible>,
     );
 
+    fn get_non_fungibles(
+        &mut self,
+        resource_address: ResourceAddress,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(NonFungibleId, Option<NonFungible>)>;
+
     fn borrow_global_mut_resource_manager(
         &mut self,
         resource_address: ResourceAddress,
@@ -96,6 +104,8 @@ pub trait SystemApi {
     fn create_resource(&mut self, resource_manager: ResourceManager) -> ResourceAddress;
 
     fn create_package(&mut self, package: Package) -> PackageAddress;
+
+    fn generate_uuid(&mut self) -> u128;
 }
 
 pub enum SNodeState {
@@ -111,6 +121,8 @@ pub enum SNodeState {
     ProofRef(ProofId, Proof),
     Proof(Proof),
     VaultRef(VaultId, Option<ComponentAddress>, Vault),
+    ComponentRef(ComponentAddress, Component),
+    PackageRef(PackageAddress, Package),
 }
 
 /// Represents an interpreter instance.
@@ -366,6 +378,12 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                 vault
                     .main(*vault_id, function.as_str(), args, self)
                     .map_err(RuntimeError::VaultError),
+            SNodeState::ComponentRef(_, component) => component
+                .main(function.as_str(), args)
+                .map_err(RuntimeError::ComponentError),
+            SNodeState::PackageRef(_, package) => package
+                .main(function.as_str(), args)
+                .map_err(RuntimeError::PackageError),
             _ => Err(RuntimeError::IllegalSystemCall),
         }?;
 
@@ -404,6 +422,106 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         function: String,
         args: Vec<ScryptoValue>,
     ) -> Result<ScryptoValue, RuntimeError> {
+        if let SNodeRef::Scrypto(ScryptoActor::Component(component_address)) = &snode_ref {
+            if function == "migrate" {
+                return Err(RuntimeError::MigrateNotCallable(*component_address));
+            }
+            self.migrate_component(*component_address)?;
+        }
+
+        self.invoke_snode_with_auth(snode_ref, function, args, true)
+    }
+
+    /// Migrates a component to the current code of its package, if it has been upgraded since
+    /// the component was last loaded.
+    ///
+    /// Fields appended to the state are set to `None`, then the `migrate` method of the
+    /// blueprint, if any, is run without authorization.
+    fn migrate_component(
+        &mut self,
+        component_address: ComponentAddress,
+    ) -> Result<(), RuntimeError> {
+        // Components which can't be loaded, or are already running and thus migrated, are
+        // reported when borrowed for the call
+        if self.track.is_component_borrowed(component_address) {
+            return Ok(());
+        }
+        let (package_address, blueprint_name, package_version) =
+            match self.track.get_component(component_address) {
+                Some(component) => (
+                    component.package_address(),
+                    component.blueprint_name().to_string(),
+                    component.package_version(),
+                ),
+                None => return Ok(()),
+            };
+        let package = self
+            .track
+            .get_package(&package_address)
+            .ok_or(RuntimeError::PackageNotFound(package_address))?;
+        if package.version() == package_version {
+            return Ok(());
+        }
+        let new_package_version = package.version();
+        let migrate = package.contains_migration(&blueprint_name);
+        let schema = package
+            .load_blueprint_schema(&blueprint_name)
+            .map_err(RuntimeError::PackageError)?
+            .clone();
+
+        re_debug!(self, "Migrating component: {:?}", component_address);
+        let mut component = self.track.borrow_global_mut_component(component_address)?;
+        let result = component.upgrade_state(&schema, new_package_version);
+        self.track
+            .return_borrowed_global_component(component_address, component);
+        result.map_err(RuntimeError::ComponentError)?;
+
+        if migrate {
+            self.invoke_snode_with_auth(
+                SNodeRef::Scrypto(ScryptoActor::Component(component_address)),
+                "migrate".to_string(),
+                vec![],
+                false,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn invoke_snode_with_auth(
+        &mut self,
+        snode_ref: SNodeRef,
+        function: String,
+        args: Vec<ScryptoValue>,
+        check_auth: bool,
+    ) -> Result<ScryptoValue, RuntimeError> {
+        // Component metadata is read without borrowing the component, and a running component
+        // reads and updates its own metadata without authorization, as with its state.
+        if let SNodeRef::ComponentRef(component_address) = &snode_ref {
+            if let Some(WasmProcess {
+                interpreter_state:
+                    InterpreterState::Component {
+                        component_address: running_address,
+                        component,
+                        ..
+                    },
+                ..
+            }) = &mut self.wasm_process_state
+            {
+                if running_address == component_address {
+                    return component
+                        .main(function.as_str(), args)
+                        .map_err(RuntimeError::ComponentError);
+                }
+            }
+            if function == "get_metadata" {
+                let component = self
+                    .track
+                    .get_component(component_address.clone())
+                    .ok_or(RuntimeError::ComponentNotFound(component_address.clone()))?;
+                return Ok(component.metadata_value());
+            }
+        }
+
         // Authorization and state load
         let (mut snode, method_auths) = match &snode_ref {
             SNodeRef::PackageStatic => Ok((SNodeState::PackageStatic, vec![])),
@@ -542,10 +660,63 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                     vec![method_auth.clone()],
                 ))
             }
+            SNodeRef::ComponentVaultRef(component_address, vault_id) => {
+                // Only resource authorities may access vaults of other components
+                if !matches!(
+                    function.as_str(),
+                    "recall_from_vault" | "freeze_vault" | "unfreeze_vault" | "is_vault_frozen"
+                ) {
+                    return Err(RuntimeError::VaultError(VaultError::MethodNotFound(
+                        function,
+                    )));
+                }
+                let vault = self
+                    .track
+                    .borrow_global_mut_vault(component_address, vault_id)?;
+
+                let resource_address = vault.resource_address();
+                let method_auth = self
+                    .track
+                    .get_resource_manager(&resource_address)
+                    .unwrap()
+                    .get_auth(&function, &args);
+                Ok((
+                    SNodeState::VaultRef(vault_id.clone(), Some(*component_address), vault),
+                    vec![method_auth.clone()],
+                ))
+            }
+            SNodeRef::ComponentRef(component_address) => {
+                let component = self
+                    .track
+                    .borrow_global_mut_component(component_address.clone())?;
+                let package_address = component.package_address();
+                let package = self
+                    .track
+                    .get_package(&package_address)
+                    .ok_or(RuntimeError::PackageNotFound(package_address))?;
+                let schema = package
+                    .load_blueprint_schema(component.blueprint_name())
+                    .unwrap();
+                let method_auths = component.native_method_authorization(schema, &function);
+                Ok((
+                    SNodeState::ComponentRef(component_address.clone(), component),
+                    method_auths,
+                ))
+            }
+            SNodeRef::PackageRef(package_address) => {
+                let package = self
+                    .track
+                    .borrow_global_mut_package(package_address.clone())?;
+                let method_auths = package.method_authorization(&function);
+                Ok((
+                    SNodeState::PackageRef(package_address.clone(), package),
+                    method_auths,
+                ))
+            }
         }?;
 
         // Authorization check
-        if !method_auths.is_empty() {
+        if check_auth && !method_auths.is_empty() {
             let mut auth_zones = Vec::new();
             if let Some(self_auth_zone) = &self.auth_zone {
                 auth_zones.push(self_auth_zone);
@@ -562,9 +733,11 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                 _ => { }
             };
 
+            let current_epoch = self.track.current_epoch();
+            let current_time = self.track.current_time();
             for method_auth in method_auths {
                 method_auth
-                    .check(&auth_zones)
+                    .check(&auth_zones, current_epoch, current_time)
                     .map_err(|error| RuntimeError::AuthorizationError {
                         function: function.clone(),
                         authorization: method_auth,
@@ -656,6 +829,12 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                             self.owned_snodes.return_borrowed_vault_mut(vault);
                         }
                     }
+                    SNodeState::ComponentRef(component_address, component) => {
+                        self.track.return_borrowed_global_component(component_address, component);
+                    }
+                    SNodeState::PackageRef(package_address, package) => {
+                        self.track.return_borrowed_global_package(package_address, package);
+                    }
                     _ => {}
                 }
 
@@ -937,20 +1116,42 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         &mut self,
         input: CreateComponentInput,
     ) -> Result<CreateComponentOutput, RuntimeError> {
-        let data = Self::process_entry_data(&input.state)?;
-        let new_objects = self.owned_snodes.take(data)?;
+        self.handle_create_component_with_metadata(CreateComponentWithMetadataInput {
+            blueprint_name: input.blueprint_name,
+            state: input.state,
+            access_rules_list: input.access_rules_list,
+            metadata: BTreeMap::new(),
+            metadata_access_rule: AccessRule::DenyAll,
+        })
+    }
 
+    fn handle_create_component_with_metadata(
+        &mut self,
+        input: CreateComponentWithMetadataInput,
+    ) -> Result<CreateComponentOutput, RuntimeError> {
         let wasm_process = self
             .wasm_process_state
             .as_mut()
             .ok_or(RuntimeError::IllegalSystemCall)?;
         let package_address = wasm_process.vm.actor.package_address().clone();
+        let package_version = self
+            .track
+            .get_package(&package_address)
+            .ok_or(RuntimeError::PackageNotFound(package_address))?
+            .version();
         let component = Component::new(
             package_address,
             input.blueprint_name,
             input.access_rules_list,
             input.state,
-        );
+            input.metadata,
+            input.metadata_access_rule,
+        )
+        .map_err(RuntimeError::ComponentError)?
+        .with_package_version(package_version);
+
+        let data = Self::process_entry_data(component.state())?;
+        let new_objects = self.owned_snodes.take(data)?;
         let component_address = self.track.create_component(component);
         self.track
             .insert_objects_into_component(new_objects, component_address);
@@ -1234,6 +1435,40 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         })
     }
 
+    fn handle_get_current_time(
+        &mut self,
+        _input: GetCurrentTimeInput,
+    ) -> Result<GetCurrentTimeOutput, RuntimeError> {
+        Ok(GetCurrentTimeOutput {
+            current_time: self.track.current_time(),
+        })
+    }
+
+    fn handle_verify_signature(
+        &mut self,
+        input: VerifySignatureInput,
+    ) -> Result<VerifySignatureOutput, RuntimeError> {
+        self.track.consume_cost_units(VERIFY_SIGNATURE_COST_UNITS);
+        Ok(VerifySignatureOutput {
+            is_valid: verify_signature(
+                input.scheme,
+                &input.public_key,
+                &input.message,
+                &input.signature,
+            ),
+        })
+    }
+
+    fn handle_compute_hash(
+        &mut self,
+        input: ComputeHashInput,
+    ) -> Result<ComputeHashOutput, RuntimeError> {
+        self.track.consume_cost_units(COMPUTE_HASH_COST_UNITS);
+        Ok(ComputeHashOutput {
+            hash: compute_hash(input.hash_function, &input.data),
+        })
+    }
+
     fn handle_generate_uuid(
         &mut self,
         _input: GenerateUuidInput,
@@ -1262,7 +1497,7 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         let mut simulated_auth_zone = AuthZone::new_with_proofs(proofs);
 
         let method_authorization = convert(&Type::Unit, &Value::Unit, &input.access_rule);
-        let is_authorized = method_authorization.check(&[&simulated_auth_zone]).is_ok();
+        let is_authorized = method_authorization.check(&[&simulated_auth_zone], self.track.current_epoch(), self.track.current_time()).is_ok();
         simulated_auth_zone.main("clear", Vec::new(), self).map_err(RuntimeError::AuthZoneError)?;
 
         return Ok(CheckAccessRuleOutput{
@@ -1301,6 +1536,16 @@ impl<'r, 'l, L: SubstateStore> SystemApi for Process<'r, 'l, L> {
             .set_non_fungible(non_fungible_address, non_fungible)
     }
 
+    fn get_non_fungibles(
+        &mut self,
+        resource_address: ResourceAddress,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(NonFungibleId, Option<NonFungible>)> {
+        self.track
+            .get_non_fungibles(resource_address, offset, limit)
+    }
+
     fn borrow_global_mut_resource_manager(
         &mut self,
         resource_address: ResourceAddress,
@@ -1351,6 +1596,10 @@ impl<'r, 'l, L: SubstateStore> SystemApi for Process<'r, 'l, L> {
     fn create_package(&mut self, package: Package) -> PackageAddress {
         self.track.create_package(package)
     }
+
+    fn generate_uuid(&mut self) -> u128 {
+        self.track.new_uuid()
+    }
 }
 
 impl<'r, 'l, L: SubstateStore> Externals for Process<'r, 'l, L> {
@@ -1364,6 +1613,9 @@ impl<'r, 'l, L: SubstateStore> Externals for Process<'r, 'l, L> {
                 let operation: u32 = args.nth_checked(0)?;
                 match operation {
                     CREATE_COMPONENT => self.handle(args, Self::handle_create_component),
+                    CREATE_COMPONENT_WITH_METADATA => {
+                        self.handle(args, Self::handle_create_component_with_metadata)
+                    }
                     GET_COMPONENT_INFO => self.handle(args, Self::handle_get_component_info),
                     GET_COMPONENT_STATE => self.handle(args, Self::handle_get_component_state),
                     PUT_COMPONENT_STATE => self.handle(args, Self::handle_put_component_state),
@@ -1376,10 +1628,14 @@ impl<'r, 'l, L: SubstateStore> Externals for Process<'r, 'l, L> {
 
                     INVOKE_SNODE => self.handle(args, Self::handle_invoke_snode),
 
+                    VERIFY_SIGNATURE => self.handle(args, Self::handle_verify_signature),
+                    COMPUTE_HASH => self.handle(args, Self::handle_compute_hash),
+
                     EMIT_LOG => self.handle(args, Self::handle_emit_log),
                     GET_CALL_DATA => self.handle(args, Self::handle_get_call_data),
                     GET_TRANSACTION_HASH => self.handle(args, Self::handle_get_transaction_hash),
                     GET_CURRENT_EPOCH => self.handle(args, Self::handle_get_current_epoch),
+                    GET_CURRENT_TIME => self.handle(args, Self::handle_get_current_time),
                     GENERATE_UUID => self.handle(args, Self::handle_generate_uuid),
                     GET_ACTOR => self.handle(args, Self::handle_get_actor),
 
@@ -1,5 +1,7 @@
 use indexmap::IndexMap;
+use scrypto::buffer::scrypto_encode;
 use scrypto::constants::*;
+use scrypto::crypto::hash;
 use scrypto::engine::types::*;
 use scrypto::rust::collections::*;
 use scrypto::rust::string::String;
@@ -48,10 +50,14 @@ pub struct Track<'s, S: SubstateStore> {
     substate_store: &'s mut S,
     transaction_hash: Hash,
     transaction_signers: Vec<EcdsaPublicKey>,
+    current_time: u64,
+    cost_units_consumed: u32,
     id_allocator: IdAllocator,
     logs: Vec<(Level, String)>,
 
     packages: IndexMap<PackageAddress, SubstateUpdate<Package>>,
+    borrowed_packages: HashMap<PackageAddress, (Option<(Hash, u32)>, Hash)>,
+    package_upgrades: Vec<PackageUpgrade>,
 
     components: IndexMap<ComponentAddress, SubstateUpdate<Component>>,
     borrowed_components: HashMap<ComponentAddress, Option<(Hash, u32)>>,
@@ -72,14 +78,19 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         substate_store: &'s mut S,
         transaction_hash: Hash,
         transaction_signers: Vec<EcdsaPublicKey>,
+        current_time: u64,
     ) -> Self {
         Self {
             substate_store,
             transaction_hash,
             transaction_signers,
+            current_time,
+            cost_units_consumed: 0,
             id_allocator: IdAllocator::new(IdSpace::Application),
             logs: Vec::new(),
             packages: IndexMap::new(),
+            borrowed_packages: HashMap::new(),
+            package_upgrades: Vec::new(),
             components: IndexMap::new(),
             borrowed_components: HashMap::new(),
             resource_managers: IndexMap::new(),
@@ -110,8 +121,11 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         let mut initial_auth_zone_proofs = Vec::new();
         if !signers.is_empty() {
             // Proofs can't be zero amount
-            let mut ecdsa_bucket =
-                Bucket::new(ResourceContainer::new_non_fungible(ECDSA_TOKEN, signers));
+            let mut ecdsa_bucket = Bucket::new(ResourceContainer::new_non_fungible(
+                ECDSA_TOKEN,
+                NonFungibleIdType::Bytes,
+                signers,
+            ));
             let ecdsa_proof = ecdsa_bucket.create_proof(ECDSA_TOKEN_BUCKET_ID).unwrap();
             initial_auth_zone_proofs.push(ecdsa_proof);
         }
@@ -137,6 +151,21 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         self.substate_store.get_epoch()
     }
 
+    /// Returns the current time, in milliseconds since the Unix epoch.
+    pub fn current_time(&self) -> u64 {
+        self.current_time
+    }
+
+    /// Charges cost units for a native operation.
+    pub fn consume_cost_units(&mut self, cost_units: u32) {
+        self.cost_units_consumed = self.cost_units_consumed.saturating_add(cost_units);
+    }
+
+    /// Returns the cost units consumed so far.
+    pub fn cost_units_consumed(&self) -> u32 {
+        self.cost_units_consumed
+    }
+
     /// Returns the logs collected so far.
     pub fn logs(&self) -> &Vec<(Level, String)> {
         &self.logs
@@ -153,6 +182,11 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         package_addresses
     }
 
+    /// Returns packages upgraded so far.
+    pub fn package_upgrades(&self) -> &Vec<PackageUpgrade> {
+        &self.package_upgrades
+    }
+
     /// Returns new components created so far.
     pub fn new_component_addresses(&self) -> Vec<ComponentAddress> {
         let mut component_addresses = Vec::new();
@@ -214,6 +248,55 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         package_address
     }
 
+    pub fn borrow_global_mut_package(
+        &mut self,
+        package_address: PackageAddress,
+    ) -> Result<Package, RuntimeError> {
+        let maybe_package = self.packages.remove(&package_address);
+        if let Some(SubstateUpdate { value, prev_id }) = maybe_package {
+            self.borrowed_packages
+                .insert(package_address, (prev_id, hash(value.code())));
+            Ok(value)
+        } else if self.borrowed_packages.contains_key(&package_address) {
+            Err(RuntimeError::PackageReentrancy(package_address))
+        } else if let Some((package, phys_id)) = self
+            .substate_store
+            .get_decoded_substate::<_, Package>(&package_address)
+        {
+            self.borrowed_packages
+                .insert(package_address, (Some(phys_id), hash(package.code())));
+            Ok(package)
+        } else {
+            Err(RuntimeError::PackageNotFound(package_address))
+        }
+    }
+
+    pub fn return_borrowed_global_package(
+        &mut self,
+        package_address: PackageAddress,
+        package: Package,
+    ) {
+        if let Some((prev_id, old_code_hash)) = self.borrowed_packages.remove(&package_address) {
+            let new_code_hash = hash(package.code());
+            if new_code_hash != old_code_hash {
+                self.package_upgrades.push(PackageUpgrade {
+                    package_address,
+                    old_code_hash,
+                    new_code_hash,
+                });
+            }
+            self.packages.insert(
+                package_address,
+                SubstateUpdate {
+                    prev_id,
+                    value: package,
+                },
+            );
+        } else {
+            panic!("Package was never borrowed");
+        }
+    }
+
     pub fn borrow_global_mut_component(
         &mut self,
         component_address: ComponentAddress,
@@ -253,6 +336,11 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         }
     }
 
+    /// Returns whether a component is borrowed by a running call.
+    pub fn is_component_borrowed(&self, component_address: ComponentAddress) -> bool {
+        self.borrowed_components.contains_key(&component_address)
+    }
+
     /// Returns an immutable reference to a component, if exists.
     pub fn get_component(&mut self, component_address: ComponentAddress) -> Option<&Component> {
         if self.components.contains_key(&component_address) {
@@ -321,6 +409,37 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         }
     }
 
+    /// Returns up to `limit` non-fungibles of a resource in key order, skipping the first `offset`,
+    /// including the ones updated by this transaction.
+    ///
+    /// Burned non-fungibles are included, with no data.
+    pub fn get_non_fungibles(
+        &mut self,
+        resource_address: ResourceAddress,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(NonFungibleId, Option<NonFungible>)> {
+        // Updates in this transaction never remove an entry, so the first `offset + limit`
+        // committed entries are enough to fill the page.
+        let mut non_fungibles: BTreeMap<Vec<u8>, (NonFungibleId, Option<NonFungible>)> = self
+            .substate_store
+            .get_non_fungibles(resource_address, 0, offset.saturating_add(limit))
+            .into_iter()
+            .map(|(id, non_fungible)| (scrypto_encode(&id), (id, non_fungible)))
+            .collect();
+        for (non_fungible_address, update) in &self.non_fungibles {
+            if non_fungible_address.resource_address() == resource_address {
+                let id = non_fungible_address.non_fungible_id();
+                non_fungibles.insert(scrypto_encode(&id), (id, update.value.clone()));
+            }
+        }
+        non_fungibles
+            .into_values()
+            .skip(offset)
+            .take(limit)
+            .collect()
+    }
+
     /// Sets a non-fungible.
     pub fn set_non_fungible(
         &mut self,
@@ -528,6 +647,29 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         panic!("Should not get here");
     }
 
+    /// Borrows a vault of any component, which may not exist.
+    pub fn borrow_global_mut_vault(
+        &mut self,
+        component_address: &ComponentAddress,
+        vid: &VaultId,
+    ) -> Result<Vault, RuntimeError> {
+        let canonical_id = (component_address.clone(), vid.clone());
+        if self.borrowed_vaults.contains_key(&canonical_id) {
+            Err(RuntimeError::VaultReentrancy(*vid))
+        } else if let Some(SubstateUpdate { value, prev_id }) = self.vaults.remove(&canonical_id) {
+            self.borrowed_vaults.insert(canonical_id, prev_id);
+            Ok(value)
+        } else if let Some((vault, phys_id)) = self
+            .substate_store
+            .get_decoded_child_substate(component_address, vid)
+        {
+            self.borrowed_vaults.insert(canonical_id, Some(phys_id));
+            Ok(vault)
+        } else {
+            Err(RuntimeError::VaultNotFound(*vid))
+        }
+    }
+
     pub fn return_borrowed_vault(
         &mut self,
         component_address: &ComponentAddress,
@@ -626,6 +768,9 @@ impl<'s, S: SubstateStore> Track<'s, S> {
     /// Currently none of these objects are deleted so all commits are puts
     pub fn commit(&mut self) -> CommitReceipt {
         // Sanity check
+        if !self.borrowed_packages.is_empty() {
+            panic!("Borrowed packages should be empty by end of transaction.");
+        }
         if !self.borrowed_components.is_empty() {
             panic!("Borrowed components should be empty by end of transaction.");
         }
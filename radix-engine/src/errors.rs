@@ -64,6 +64,15 @@ pub enum WasmValidationError {
     NoPackageInitExport(WasmiError),
     /// package_init function is not the correct interface
     InvalidPackageInit,
+    /// The `migrate` method of a blueprint does not take `&mut self` alone and return nothing
+    InvalidMigrateMethod(String),
+}
+
+/// Represents an error when moving the current time.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TimeError {
+    /// The new time is earlier than the current time.
+    TimeMovedBackwards { current_time: u64, new_time: u64 },
 }
 
 /// Represents an error when validating a transaction.
@@ -129,6 +138,8 @@ pub enum RuntimeError {
     /// Package does not exist.
     PackageNotFound(PackageAddress),
 
+    PackageReentrancy(PackageAddress),
+
     PackageError(PackageError),
 
     /// Blueprint does not exist.
@@ -139,12 +150,18 @@ pub enum RuntimeError {
 
     ComponentReentrancy(ComponentAddress),
 
+    /// The `migrate` method is only run by the engine, after a package upgrade.
+    MigrateNotCallable(ComponentAddress),
+
     /// Component does not exist.
     ComponentNotFound(ComponentAddress),
 
     /// Component is already loaded
     ComponentAlreadyLoaded(ComponentAddress),
 
+    /// Component access error.
+    ComponentError(ComponentError),
+
     /// Resource manager does not exist.
     ResourceManagerNotFound(ResourceAddress),
 
@@ -169,6 +186,9 @@ pub enum RuntimeError {
     /// Vault does not exist.
     VaultNotFound(VaultId),
 
+    /// Vault is already borrowed.
+    VaultReentrancy(VaultId),
+
     /// Vault removed.
     VaultRemoved(VaultId),
 
@@ -1,10 +1,13 @@
 use sbor::Encode;
-use scrypto::buffer::scrypto_encode;
+use scrypto::buffer::{scrypto_decode, scrypto_encode};
+use scrypto::engine::types::*;
 use scrypto::rust::collections::HashMap;
 use scrypto::rust::vec::Vec;
 
+use crate::errors::TimeError;
 use crate::ledger::traits::Substate;
 use crate::ledger::*;
+use crate::model::NonFungible;
 
 /// An in-memory ledger stores all substates in host memory.
 #[derive(Debug, Clone)]
@@ -12,6 +15,7 @@ pub struct InMemorySubstateStore {
     substates: HashMap<Vec<u8>, Substate>,
     child_substates: HashMap<Vec<u8>, Substate>,
     current_epoch: u64,
+    current_time: u64,
     nonce: u64,
 }
 
@@ -21,6 +25,7 @@ impl InMemorySubstateStore {
             substates: HashMap::new(),
             child_substates: HashMap::new(),
             current_epoch: 0,
+            current_time: 0,
             nonce: 0,
         }
     }
@@ -38,6 +43,23 @@ impl Default for InMemorySubstateStore {
     }
 }
 
+impl QueryableSubstateStore for InMemorySubstateStore {
+    fn get_lazy_map_entries(
+        &self,
+        component_address: ComponentAddress,
+        lazy_map_id: &LazyMapId,
+    ) -> HashMap<Vec<u8>, Vec<u8>> {
+        let mut id = scrypto_encode(&component_address);
+        id.extend(scrypto_encode(lazy_map_id));
+
+        self.child_substates
+            .iter()
+            .filter(|(key, _)| key.starts_with(&id))
+            .map(|(key, substate)| (key[id.len()..].to_vec(), substate.value.clone()))
+            .collect()
+    }
+}
+
 impl SubstateStore for InMemorySubstateStore {
     fn get_substate<T: Encode>(&self, address: &T) -> Option<Substate> {
         self.substates.get(&scrypto_encode(address)).cloned()
@@ -59,6 +81,32 @@ impl SubstateStore for InMemorySubstateStore {
         self.child_substates.insert(id, substate);
     }
 
+    fn get_non_fungibles(
+        &self,
+        resource_address: ResourceAddress,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(NonFungibleId, Option<NonFungible>)> {
+        let id = scrypto_encode(&resource_address);
+
+        let mut entries: Vec<(&Vec<u8>, &Substate)> = self
+            .child_substates
+            .iter()
+            .filter(|(key, _)| key.starts_with(&id))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+            .into_iter()
+            .filter_map(|(key, substate)| {
+                let non_fungible_id: NonFungibleId = scrypto_decode(&key[id.len()..]).ok()?;
+                let non_fungible: Option<NonFungible> = scrypto_decode(&substate.value).unwrap();
+                Some((non_fungible_id, non_fungible))
+            })
+            .skip(offset)
+            .take(limit)
+            .collect()
+    }
+
     fn get_epoch(&self) -> u64 {
         self.current_epoch
     }
@@ -67,6 +115,21 @@ impl SubstateStore for InMemorySubstateStore {
         self.current_epoch = epoch;
     }
 
+    fn get_time(&self) -> u64 {
+        self.current_time
+    }
+
+    fn set_time(&mut self, time: u64) -> Result<(), TimeError> {
+        if time < self.current_time {
+            return Err(TimeError::TimeMovedBackwards {
+                current_time: self.current_time,
+                new_time: time,
+            });
+        }
+        self.current_time = time;
+        Ok(())
+    }
+
     fn get_nonce(&self) -> u64 {
         self.nonce
     }
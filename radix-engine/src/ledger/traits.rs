@@ -5,12 +5,15 @@ use scrypto::constants::*;
 use scrypto::crypto::*;
 use scrypto::engine::types::*;
 use scrypto::prelude::LOCKED;
+use scrypto::resource::AccessRule;
+use scrypto::resource::NonFungibleDataSchema;
 use scrypto::resource::ResourceMethod::Withdraw;
 use scrypto::rust::borrow::ToOwned;
 use scrypto::rust::collections::*;
 use scrypto::rust::vec;
 use scrypto::rust::vec::Vec;
 
+use crate::errors::TimeError;
 use crate::model::*;
 
 const XRD_SYMBOL: &str = "XRD";
@@ -68,6 +71,16 @@ pub trait SubstateStore {
     fn get_child_substate<T: Encode>(&self, address: &T, key: &[u8]) -> Option<Substate>;
     fn put_child_substate<T: Encode>(&mut self, address: &T, key: &[u8], substate: Substate);
 
+    /// Returns up to `limit` non-fungibles of a resource in key order, skipping the first `offset`.
+    ///
+    /// Burned non-fungibles are included, with no data.
+    fn get_non_fungibles(
+        &self,
+        resource_address: ResourceAddress,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(NonFungibleId, Option<NonFungible>)>;
+
     // Temporary Encoded/Decoded interface
     fn get_decoded_substate<A: Encode, T: Decode>(&self, address: &A) -> Option<(T, (Hash, u32))> {
         self.get_substate(address)
@@ -176,6 +189,9 @@ pub trait SubstateStore {
                 ResourceType::Fungible { divisibility: 18 },
                 metadata,
                 resource_auth,
+                Some(XRD_MAX_SUPPLY.into()),
+                None,
+                BTreeSet::new(),
             )
             .unwrap();
             self.put_encoded_substate(&RADIX_TOKEN, &xrd, id_gen.next());
@@ -186,9 +202,18 @@ pub trait SubstateStore {
             let mut ecdsa_resource_auth = HashMap::new();
             ecdsa_resource_auth.insert(Withdraw, (rule!(allow_all), LOCKED));
             let ecdsa_token = ResourceManager::new(
-                ResourceType::NonFungible,
+                ResourceType::NonFungible {
+                    id_type: NonFungibleIdType::Bytes,
+                },
                 HashMap::new(),
                 ecdsa_resource_auth,
+                None,
+                // ECDSA badges are virtual and carry no data
+                Some(NonFungibleDataSchema {
+                    immutable: Type::Unit,
+                    mutable: Type::Unit,
+                }),
+                BTreeSet::new(),
             )
             .unwrap();
             self.put_encoded_substate(&ECDSA_TOKEN, &ecdsa_token, id_gen.next());
@@ -207,7 +232,10 @@ pub trait SubstateStore {
                 SYSTEM_COMPONENT_NAME.to_owned(),
                 vec![],
                 scrypto_encode(&SystemComponentState { xrd: XRD_VAULT }),
-            );
+                BTreeMap::new(),
+                AccessRule::DenyAll,
+            )
+            .unwrap();
             self.put_encoded_substate(&SYSTEM_COMPONENT, &system_component, id_gen.next());
         }
     }
@@ -216,6 +244,12 @@ pub trait SubstateStore {
 
     fn set_epoch(&mut self, epoch: u64);
 
+    /// Returns the current time, in milliseconds since the Unix epoch.
+    fn get_time(&self) -> u64;
+
+    /// Sets the current time, which can never move backwards.
+    fn set_time(&mut self, time: u64) -> Result<(), TimeError>;
+
     // TODO: redefine what nonce is and how it's updated
     // For now, we bump nonce only when a transaction has been committed
     // or when an account is created (for testing).
@@ -1,6 +1,6 @@
 use crate::model::method_authorization::{
     HardAuthRule, HardCount, HardDecimal, HardProofRule, HardProofRuleResourceList,
-    HardResourceOrNonFungible,
+    HardResourceOrNonFungible, HardU64,
 };
 use crate::model::MethodAuthorization;
 use sbor::any::Value;
@@ -9,7 +9,7 @@ use scrypto::engine::types::*;
 use scrypto::prelude::{AccessRuleNode, AccessRule, SoftResource};
 use scrypto::resource::{
     NonFungibleAddress, ProofRule, SoftCount, SoftDecimal, SoftResourceOrNonFungible,
-    SoftResourceOrNonFungibleList,
+    SoftResourceOrNonFungibleList, SoftU64,
 };
 use scrypto::rust::vec::Vec;
 use scrypto::types::ScryptoType;
@@ -53,6 +53,22 @@ fn soft_to_hard_count(schema: &Type, soft_count: &SoftCount, dom: &Value) -> Har
     }
 }
 
+fn soft_to_hard_u64(schema: &Type, soft_u64: &SoftU64, dom: &Value) -> HardU64 {
+    match soft_u64 {
+        SoftU64::Static(value) => HardU64::Value(*value),
+        SoftU64::Dynamic(schema_path) => {
+            let sbor_path = schema_path.to_sbor_path(schema);
+            if let None = sbor_path {
+                return HardU64::SoftU64NotFound;
+            }
+            match sbor_path.unwrap().get_from_value(dom) {
+                Some(Value::U64 { value }) => HardU64::Value(*value),
+                _ => HardU64::SoftU64NotFound,
+            }
+        }
+    }
+}
+
 fn soft_to_hard_resource_list(
     schema: &Type,
     list: &SoftResourceOrNonFungibleList,
@@ -203,6 +219,18 @@ fn soft_to_hard_proof_rule(schema: &Type, proof_rule: &ProofRule, dom: &Value) -
             let hard_resources = soft_to_hard_resource_list(schema, resources, dom);
             HardProofRule::CountOf(hard_count, hard_resources)
         }
+        ProofRule::EpochAtLeast(epoch) => {
+            HardProofRule::EpochAtLeast(soft_to_hard_u64(schema, epoch, dom))
+        }
+        ProofRule::EpochBefore(epoch) => {
+            HardProofRule::EpochBefore(soft_to_hard_u64(schema, epoch, dom))
+        }
+        ProofRule::TimeAtLeast(time) => {
+            HardProofRule::TimeAtLeast(soft_to_hard_u64(schema, time, dom))
+        }
+        ProofRule::TimeBefore(time) => {
+            HardProofRule::TimeBefore(soft_to_hard_u64(schema, time, dom))
+        }
     }
 }
 
@@ -1,12 +1,28 @@
+use sbor::describe::Fields;
+use sbor::rust::boxed::Box;
 use sbor::*;
+use scrypto::buffer::scrypto_decode;
 use scrypto::engine::types::*;
-use scrypto::resource::AccessRules;
+use scrypto::resource::{validate_metadata, AccessRule, AccessRules, MetadataError};
+use scrypto::rust::collections::*;
 use scrypto::rust::string::String;
+use scrypto::rust::string::ToString;
+use scrypto::rust::vec;
 use scrypto::rust::vec::Vec;
 use scrypto::values::*;
 
 use crate::model::{convert, MethodAuthorization};
 
+/// Represents an error when accessing a component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentError {
+    InvalidRequestData(DecodeError),
+    InvalidMetadata(MetadataError),
+    MethodNotFound(String),
+    InvalidState(DecodeError),
+    StateNotStruct,
+}
+
 /// A component is an instance of blueprint.
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct Component {
@@ -14,6 +30,9 @@ pub struct Component {
     blueprint_name: String,
     auths: Vec<AccessRules>,
     state: Vec<u8>,
+    metadata: BTreeMap<String, String>,
+    metadata_access_rule: AccessRule,
+    package_version: u32,
 }
 
 impl Component {
@@ -22,13 +41,28 @@ impl Component {
         blueprint_name: String,
         method_auth: Vec<AccessRules>,
         state: Vec<u8>,
-    ) -> Self {
-        Self {
+        metadata: BTreeMap<String, String>,
+        metadata_access_rule: AccessRule,
+    ) -> Result<Self, ComponentError> {
+        for (key, value) in &metadata {
+            validate_metadata(key, value).map_err(ComponentError::InvalidMetadata)?;
+        }
+
+        Ok(Self {
             package_address,
             blueprint_name,
             auths: method_auth,
             state,
-        }
+            metadata,
+            metadata_access_rule,
+            package_version: 0,
+        })
+    }
+
+    /// Sets the version of the package code this component is instantiated with.
+    pub fn with_package_version(mut self, package_version: u32) -> Self {
+        self.package_version = package_version;
+        self
     }
 
     pub fn method_authorization(
@@ -48,6 +82,21 @@ impl Component {
         (data, authorizations)
     }
 
+    /// Returns the authorization of a native component method.
+    pub fn native_method_authorization(
+        &self,
+        schema: &Type,
+        method_name: &str,
+    ) -> Vec<MethodAuthorization> {
+        match method_name {
+            "set_metadata" => {
+                let data = ScryptoValue::from_slice(&self.state).unwrap();
+                vec![convert(schema, &data.dom, &self.metadata_access_rule)]
+            }
+            _ => vec![],
+        }
+    }
+
     pub fn authorization(&self) -> &[AccessRules] {
         &self.auths
     }
@@ -67,4 +116,136 @@ impl Component {
     pub fn set_state(&mut self, new_state: Vec<u8>) {
         self.state = new_state;
     }
+
+    /// Returns the version of the package code this component was last migrated to.
+    pub fn package_version(&self) -> u32 {
+        self.package_version
+    }
+
+    /// Migrates the state to a newer version of the package code, setting the fields appended
+    /// to the state type to `None`.
+    pub fn upgrade_state(
+        &mut self,
+        schema: &Type,
+        package_version: u32,
+    ) -> Result<(), ComponentError> {
+        let field_count = match schema {
+            Type::Struct { fields, .. } => match fields {
+                Fields::Named { named } => named.len(),
+                Fields::Unnamed { unnamed } => unnamed.len(),
+                Fields::Unit => 0,
+            },
+            _ => return Err(ComponentError::StateNotStruct),
+        };
+        let mut fields = match decode_any(&self.state).map_err(ComponentError::InvalidState)? {
+            Value::Struct { fields } => fields,
+            _ => return Err(ComponentError::StateNotStruct),
+        };
+        if fields.len() < field_count {
+            fields.resize(
+                field_count,
+                Value::Option {
+                    value: Box::new(None),
+                },
+            );
+            let mut state = Vec::new();
+            encode_any(
+                None,
+                &Value::Struct { fields },
+                &mut Encoder::with_type(&mut state),
+            );
+            self.state = state;
+        }
+        self.package_version = package_version;
+        Ok(())
+    }
+
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// Returns the metadata, as returned to Scrypto by `get_metadata`.
+    pub fn metadata_value(&self) -> ScryptoValue {
+        ScryptoValue::from_value(&self.metadata)
+    }
+
+    pub fn metadata_access_rule(&self) -> &AccessRule {
+        &self.metadata_access_rule
+    }
+
+    pub fn main(
+        &mut self,
+        function: &str,
+        args: Vec<ScryptoValue>,
+    ) -> Result<ScryptoValue, ComponentError> {
+        match function {
+            "get_metadata" => Ok(self.metadata_value()),
+            "set_metadata" => {
+                let key: String = scrypto_decode(&args[0].raw)
+                    .map_err(|e| ComponentError::InvalidRequestData(e))?;
+                let value: String = scrypto_decode(&args[1].raw)
+                    .map_err(|e| ComponentError::InvalidRequestData(e))?;
+                validate_metadata(&key, &value).map_err(ComponentError::InvalidMetadata)?;
+                self.metadata.insert(key, value);
+                Ok(ScryptoValue::from_value(&()))
+            }
+            _ => Err(ComponentError::MethodNotFound(function.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scrypto::buffer::scrypto_encode;
+    use scrypto::constants::SYSTEM_PACKAGE;
+
+    #[derive(TypeId, Encode, Decode, Describe)]
+    struct Counter(u32);
+
+    #[derive(Debug, PartialEq, TypeId, Encode, Decode, Describe)]
+    struct CounterV2(u32, Option<u32>);
+
+    fn new_component(state: Vec<u8>) -> Component {
+        Component::new(
+            SYSTEM_PACKAGE,
+            "Counter".to_string(),
+            Vec::new(),
+            state,
+            BTreeMap::new(),
+            AccessRule::AllowAll,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_upgrade_state_with_unnamed_fields() {
+        let mut component = new_component(scrypto_encode(&Counter(5)));
+
+        component.upgrade_state(&CounterV2::describe(), 1).unwrap();
+
+        let state: CounterV2 = scrypto_decode(component.state()).unwrap();
+        assert_eq!(state, CounterV2(5, None));
+        assert_eq!(component.package_version(), 1);
+    }
+
+    #[test]
+    fn test_upgrade_invalid_state_should_fail() {
+        let mut component = new_component(vec![0xff]);
+
+        let result = component.upgrade_state(&CounterV2::describe(), 1);
+
+        assert!(matches!(result, Err(ComponentError::InvalidState(_))));
+        assert_eq!(component.package_version(), 0);
+    }
+
+    #[test]
+    fn test_upgrade_state_which_is_not_a_struct_should_fail() {
+        let mut component = new_component(scrypto_encode(&5u32));
+
+        let result = component.upgrade_state(&CounterV2::describe(), 1);
+
+        assert_eq!(result, Err(ComponentError::StateNotStruct));
+        assert_eq!(component.package_version(), 0);
+    }
 }
@@ -23,6 +23,12 @@ pub enum HardCount {
     SoftCountNotFound,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
+pub enum HardU64 {
+    Value(u64),
+    SoftU64NotFound,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
 pub enum HardResourceOrNonFungible {
     NonFungible(NonFungibleAddress),
@@ -99,10 +105,19 @@ pub enum HardProofRule {
     AllOf(HardProofRuleResourceList),
     AnyOf(HardProofRuleResourceList),
     CountOf(HardCount, HardProofRuleResourceList),
+    EpochAtLeast(HardU64),
+    EpochBefore(HardU64),
+    TimeAtLeast(HardU64),
+    TimeBefore(HardU64),
 }
 
 impl HardProofRule {
-    pub fn check(&self, auth_zones: &[&AuthZone]) -> Result<(), MethodAuthorizationError> {
+    pub fn check(
+        &self,
+        auth_zones: &[&AuthZone],
+        current_epoch: u64,
+        current_time: u64,
+    ) -> Result<(), MethodAuthorizationError> {
         match self {
             HardProofRule::This(resource) => {
                 if resource.check(auth_zones) {
@@ -151,6 +166,34 @@ impl HardProofRule {
                 }
                 Err(NotAuthorized)
             }
+            HardProofRule::EpochAtLeast(HardU64::Value(epoch)) => {
+                if current_epoch >= *epoch {
+                    Ok(())
+                } else {
+                    Err(NotAuthorized)
+                }
+            }
+            HardProofRule::EpochBefore(HardU64::Value(epoch)) => {
+                if current_epoch < *epoch {
+                    Ok(())
+                } else {
+                    Err(NotAuthorized)
+                }
+            }
+            HardProofRule::TimeAtLeast(HardU64::Value(time)) => {
+                if current_time >= *time {
+                    Ok(())
+                } else {
+                    Err(NotAuthorized)
+                }
+            }
+            HardProofRule::TimeBefore(HardU64::Value(time)) => {
+                if current_time < *time {
+                    Ok(())
+                } else {
+                    Err(NotAuthorized)
+                }
+            }
             _ => Err(NotAuthorized),
         }
     }
@@ -164,17 +207,28 @@ pub enum HardAuthRule {
 }
 
 impl HardAuthRule {
-    fn check(&self, auth_zones: &[&AuthZone]) -> Result<(), MethodAuthorizationError> {
+    fn check(
+        &self,
+        auth_zones: &[&AuthZone],
+        current_epoch: u64,
+        current_time: u64,
+    ) -> Result<(), MethodAuthorizationError> {
         match self {
-            HardAuthRule::ProofRule(rule) => rule.check(auth_zones),
+            HardAuthRule::ProofRule(rule) => rule.check(auth_zones, current_epoch, current_time),
             HardAuthRule::AnyOf(rules) => {
-                if !rules.iter().any(|r| r.check(auth_zones).is_ok()) {
+                if !rules
+                    .iter()
+                    .any(|r| r.check(auth_zones, current_epoch, current_time).is_ok())
+                {
                     return Err(NotAuthorized);
                 }
                 Ok(())
             }
             HardAuthRule::AllOf(rules) => {
-                if rules.iter().any(|r| r.check(auth_zones).is_err()) {
+                if rules
+                    .iter()
+                    .any(|r| r.check(auth_zones, current_epoch, current_time).is_err())
+                {
                     return Err(NotAuthorized);
                 }
                 Ok(())
@@ -193,9 +247,18 @@ pub enum MethodAuthorization {
 }
 
 impl MethodAuthorization {
-    pub fn check(&self, auth_zones: &[&AuthZone]) -> Result<(), MethodAuthorizationError> {
+    /// Checks the proofs in the given auth zones, and any epoch and time conditions against the
+    /// current epoch and time, in milliseconds since the Unix epoch.
+    pub fn check(
+        &self,
+        auth_zones: &[&AuthZone],
+        current_epoch: u64,
+        current_time: u64,
+    ) -> Result<(), MethodAuthorizationError> {
         match self {
-            MethodAuthorization::Protected(rule) => rule.check(auth_zones),
+            MethodAuthorization::Protected(rule) => {
+                rule.check(auth_zones, current_epoch, current_time)
+            }
             MethodAuthorization::AllowAll => Ok(()),
             MethodAuthorization::DenyAll => Err(MethodAuthorizationError::NotAuthorized),
             MethodAuthorization::Unsupported => Err(MethodAuthorizationError::UnsupportedMethod),
@@ -18,12 +18,12 @@ mod worktop;
 pub use auth_zone::{AuthZone, AuthZoneError};
 pub use auth_converter::convert;
 pub use bucket::{Bucket, BucketError};
-pub use component::Component;
+pub use component::{Component, ComponentError};
 pub use method_authorization::{
     HardProofRule, HardResourceOrNonFungible, MethodAuthorization, MethodAuthorizationError,
 };
 pub use non_fungible::NonFungible;
-pub use package::{Package, PackageError};
+pub use package::{Package, PackageError, PackageUpgrade};
 pub use proof::*;
 pub use receipt::Receipt;
 pub use resource::*;
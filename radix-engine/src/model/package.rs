@@ -1,7 +1,10 @@
+use sbor::describe::Fields;
 use sbor::*;
-use scrypto::abi::{Function, Method};
+use scrypto::abi::{Function, Method, Mutability};
 use scrypto::buffer::scrypto_decode;
-use scrypto::rust::collections::HashMap;
+use scrypto::engine::types::*;
+use scrypto::resource::{validate_metadata, AccessRule, MetadataError};
+use scrypto::rust::collections::{BTreeMap, BTreeSet, HashMap};
 use scrypto::rust::string::String;
 use scrypto::rust::string::ToString;
 use scrypto::rust::vec;
@@ -14,12 +17,25 @@ use wasmi::{
 
 use crate::engine::{EnvModuleResolver, SystemApi};
 use crate::errors::WasmValidationError;
+use crate::model::{convert, MethodAuthorization};
 
 /// A collection of blueprints, compiled and published as a single unit.
 #[derive(Debug, Clone, TypeId, Encode, Decode)]
 pub struct Package {
     code: Vec<u8>,
     blueprints: HashMap<String, Type>,
+    migrations: BTreeSet<String>,
+    metadata: BTreeMap<String, String>,
+    owner: Option<AccessRule>,
+    version: u32,
+}
+
+/// A record of a package whose code has been replaced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageUpgrade {
+    pub package_address: PackageAddress,
+    pub old_code_hash: Hash,
+    pub new_code_hash: Hash,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -28,6 +44,8 @@ pub enum PackageError {
     BlueprintNotFound,
     WasmValidationError(WasmValidationError),
     MethodNotFound(String),
+    InvalidMetadata(MetadataError),
+    IncompatibleBlueprint(String),
 }
 
 impl Package {
@@ -72,6 +90,7 @@ impl Package {
             .collect();
 
         let mut blueprints = HashMap::new();
+        let mut migrations = BTreeSet::new();
 
         for method_name in blueprint_abi_methods {
             let rtn = module
@@ -79,7 +98,7 @@ impl Package {
                 .map_err(|e| WasmValidationError::NoPackageInitExport(e.into()))?
                 .ok_or(WasmValidationError::InvalidPackageInit)?;
 
-            let blueprint_type: Type = match rtn {
+            let (blueprint_type, migrate): (Type, Option<Method>) = match rtn {
                 RuntimeValue::I32(ptr) => {
                     let len: u32 = memory
                         .get_value(ptr as u32)
@@ -93,25 +112,157 @@ impl Package {
 
                     let result: (Type, Vec<Function>, Vec<Method>) = scrypto_decode(&data)
                         .map_err(|_| WasmValidationError::InvalidPackageInit)?;
-                    Ok(result.0)
+                    let migrate = result.2.into_iter().find(|method| method.name == "migrate");
+                    Ok((result.0, migrate))
                 }
                 _ => Err(WasmValidationError::InvalidPackageInit),
             }?;
 
             if let Type::Struct { name, fields: _ } = &blueprint_type {
+                if let Some(method) = migrate {
+                    if !matches!(method.mutability, Mutability::Mutable)
+                        || !method.inputs.is_empty()
+                        || method.output != Type::Unit
+                    {
+                        return Err(WasmValidationError::InvalidMigrateMethod(name.clone()));
+                    }
+                    migrations.insert(name.clone());
+                }
                 blueprints.insert(name.clone(), blueprint_type);
             } else {
                 return Err(WasmValidationError::InvalidPackageInit);
             }
         }
 
-        Ok(Self { blueprints, code })
+        Ok(Self {
+            blueprints,
+            code,
+            migrations,
+            metadata: BTreeMap::new(),
+            owner: None,
+            version: 0,
+        })
+    }
+
+    /// Attaches metadata to this package, checking the well-known entries.
+    pub fn with_metadata(
+        mut self,
+        metadata: BTreeMap<String, String>,
+    ) -> Result<Self, PackageError> {
+        for (key, value) in &metadata {
+            validate_metadata(key, value).map_err(PackageError::InvalidMetadata)?;
+        }
+        self.metadata = metadata;
+        Ok(self)
+    }
+
+    /// Sets the access rule which may upgrade this package and update its metadata.
+    ///
+    /// Packages without an owner are immutable.
+    pub fn with_owner(mut self, owner: Option<AccessRule>) -> Self {
+        self.owner = owner;
+        self
     }
 
     pub fn code(&self) -> &[u8] {
         &self.code
     }
 
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    pub fn owner(&self) -> Option<&AccessRule> {
+        self.owner.as_ref()
+    }
+
+    /// Returns the number of times this package has been upgraded.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns whether a blueprint migrates its components after an upgrade.
+    pub fn contains_migration(&self, blueprint_name: &str) -> bool {
+        self.migrations.contains(blueprint_name)
+    }
+
+    /// Returns the authorization of a native package method.
+    pub fn method_authorization(&self, method_name: &str) -> Vec<MethodAuthorization> {
+        match method_name {
+            "upgrade" | "set_metadata" => match &self.owner {
+                Some(owner) => vec![convert(&Type::Unit, &Value::Unit, owner)],
+                None => vec![MethodAuthorization::DenyAll],
+            },
+            _ => vec![],
+        }
+    }
+
+    /// Replaces the code of this package, keeping its metadata and owner.
+    ///
+    /// Every existing blueprint must still be present with the same state type, except that a
+    /// blueprint with a `migrate` method may append `Option` fields to it. Existing components
+    /// are migrated when they are first loaded with the new code.
+    pub fn upgrade(&mut self, new: Package) -> Result<(), PackageError> {
+        for (blueprint_name, schema) in &self.blueprints {
+            let compatible = match new.blueprints.get(blueprint_name) {
+                Some(new_schema) if new_schema == schema => true,
+                Some(new_schema) => {
+                    new.migrations.contains(blueprint_name)
+                        && Self::appends_optional_fields(schema, new_schema)
+                }
+                None => false,
+            };
+            if !compatible {
+                return Err(PackageError::IncompatibleBlueprint(blueprint_name.clone()));
+            }
+        }
+
+        self.code = new.code;
+        self.blueprints = new.blueprints;
+        self.migrations = new.migrations;
+        self.version += 1;
+        Ok(())
+    }
+
+    /// Checks whether a state type only differs from another by trailing `Option` fields.
+    fn appends_optional_fields(old: &Type, new: &Type) -> bool {
+        let (old_name, old_fields, new_name, new_fields) = match (old, new) {
+            (
+                Type::Struct {
+                    name: old_name,
+                    fields: old_fields,
+                },
+                Type::Struct {
+                    name: new_name,
+                    fields: new_fields,
+                },
+            ) => (old_name, old_fields, new_name, new_fields),
+            _ => return false,
+        };
+        let appended: Vec<&Type> = match (old_fields, new_fields) {
+            (Fields::Named { named: old }, Fields::Named { named: new })
+                if new.starts_with(old) =>
+            {
+                new[old.len()..]
+                    .iter()
+                    .map(|(_, field_type)| field_type)
+                    .collect()
+            }
+            (Fields::Unnamed { unnamed: old }, Fields::Unnamed { unnamed: new })
+                if new.starts_with(old) =>
+            {
+                new[old.len()..].iter().collect()
+            }
+            _ => return false,
+        };
+
+        old_name == new_name
+            && !appended.is_empty()
+            && appended
+                .iter()
+                .all(|field_type| matches!(field_type, Type::Option { .. }))
+    }
+
     pub fn contains_blueprint(&self, blueprint_name: &str) -> bool {
         self.blueprints.contains_key(blueprint_name)
     }
@@ -158,11 +309,52 @@ impl Package {
             "publish" => {
                 let bytes =
                     scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
-                let package = Package::new(bytes).map_err(PackageError::WasmValidationError)?;
+                let metadata: BTreeMap<String, String> = match args.get(1) {
+                    Some(arg) => {
+                        scrypto_decode(&arg.raw).map_err(PackageError::InvalidRequestData)?
+                    }
+                    None => BTreeMap::new(),
+                };
+                let owner: Option<AccessRule> = match args.get(2) {
+                    Some(arg) => {
+                        scrypto_decode(&arg.raw).map_err(PackageError::InvalidRequestData)?
+                    }
+                    None => None,
+                };
+                let package = Package::new(bytes)
+                    .map_err(PackageError::WasmValidationError)?
+                    .with_metadata(metadata)?
+                    .with_owner(owner);
                 let package_address = system_api.create_package(package);
                 Ok(ScryptoValue::from_value(&package_address))
             }
             _ => Err(PackageError::MethodNotFound(function.to_string())),
         }
     }
+
+    pub fn main(
+        &mut self,
+        function: &str,
+        args: Vec<ScryptoValue>,
+    ) -> Result<ScryptoValue, PackageError> {
+        match function {
+            "upgrade" => {
+                let bytes =
+                    scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
+                let package = Package::new(bytes).map_err(PackageError::WasmValidationError)?;
+                self.upgrade(package)?;
+                Ok(ScryptoValue::from_value(&()))
+            }
+            "set_metadata" => {
+                let key: String =
+                    scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
+                let value: String =
+                    scrypto_decode(&args[1].raw).map_err(PackageError::InvalidRequestData)?;
+                validate_metadata(&key, &value).map_err(PackageError::InvalidMetadata)?;
+                self.metadata.insert(key, value);
+                Ok(ScryptoValue::from_value(&()))
+            }
+            _ => Err(PackageError::MethodNotFound(function.to_string())),
+        }
+    }
 }
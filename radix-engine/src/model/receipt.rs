@@ -22,7 +22,9 @@ pub struct Receipt {
     pub new_package_addresses: Vec<PackageAddress>,
     pub new_component_addresses: Vec<ComponentAddress>,
     pub new_resource_addresses: Vec<ResourceAddress>,
+    pub upgraded_packages: Vec<PackageUpgrade>,
     pub execution_time: Option<u128>,
+    pub cost_units_consumed: u32,
 }
 
 macro_rules! prefix {
@@ -57,6 +59,13 @@ impl fmt::Debug for Receipt {
                 .unwrap_or(String::from("?"))
         )?;
 
+        write!(
+            f,
+            "\n{} {}",
+            "Cost Units Consumed:".bold().green(),
+            self.cost_units_consumed
+        )?;
+
         write!(f, "\n{}", "Instructions:".bold().green())?;
         for (i, inst) in self.validated_transaction.instructions.iter().enumerate() {
             write!(
@@ -65,6 +74,12 @@ impl fmt::Debug for Receipt {
                 prefix!(i, self.validated_transaction.instructions),
                 match inst {
                     ValidatedInstruction::PublishPackage { .. } => "PublishPackage {..}".to_owned(),
+                    ValidatedInstruction::UpgradePackage {
+                        package_address, ..
+                    } => format!(
+                        "UpgradePackage {{ package_address: {}, .. }}",
+                        package_address
+                    ),
                     i @ _ => format!("{:?}", i),
                 }
             )?;
@@ -121,6 +136,25 @@ impl fmt::Debug for Receipt {
             )?;
         }
 
+        if !self.upgraded_packages.is_empty() {
+            write!(
+                f,
+                "\n{} {}",
+                "Upgraded Packages:".bold().green(),
+                self.upgraded_packages.len()
+            )?;
+            for (i, upgrade) in self.upgraded_packages.iter().enumerate() {
+                write!(
+                    f,
+                    "\n{} {}: {} -> {}",
+                    prefix!(i, self.upgraded_packages),
+                    upgrade.package_address,
+                    upgrade.old_code_hash,
+                    upgrade.new_code_hash
+                )?;
+            }
+        }
+
         Ok(())
     }
 }
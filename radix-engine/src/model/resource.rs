@@ -37,6 +37,8 @@ pub enum ResourceContainer {
     NonFungible {
         /// The resource address.
         resource_address: ResourceAddress,
+        /// The type of the non-fungible ids.
+        id_type: NonFungibleIdType,
         /// The locked non-fungible ids and the corresponding times of being locked.
         locked_ids: HashMap<NonFungibleId, usize>,
         /// The liquid non-fungible ids.
@@ -96,10 +98,12 @@ impl ResourceContainer {
 
     pub fn new_non_fungible(
         resource_address: ResourceAddress,
+        id_type: NonFungibleIdType,
         ids: BTreeSet<NonFungibleId>,
     ) -> Self {
         Self::NonFungible {
             resource_address,
+            id_type,
             locked_ids: HashMap::new(),
             liquid_ids: ids.clone(),
         }
@@ -110,7 +114,9 @@ impl ResourceContainer {
             ResourceType::Fungible { divisibility } => {
                 Self::new_fungible(resource_address, divisibility, Decimal::zero())
             }
-            ResourceType::NonFungible => Self::new_non_fungible(resource_address, BTreeSet::new()),
+            ResourceType::NonFungible { id_type } => {
+                Self::new_non_fungible(resource_address, id_type, BTreeSet::new())
+            }
         }
     }
 
@@ -170,13 +176,22 @@ impl ResourceContainer {
     ) -> Result<Self, ResourceContainerError> {
         match self {
             Self::Fungible { .. } => Err(ResourceContainerError::NonFungibleOperationNotAllowed),
-            Self::NonFungible { liquid_ids, .. } => {
+            Self::NonFungible {
+                id_type,
+                liquid_ids,
+                ..
+            } => {
+                let id_type = *id_type;
                 for id in ids {
                     if !liquid_ids.remove(&id) {
                         return Err(ResourceContainerError::InsufficientBalance);
                     }
                 }
-                Ok(Self::new_non_fungible(self.resource_address(), ids.clone()))
+                Ok(Self::new_non_fungible(
+                    self.resource_address(),
+                    id_type,
+                    ids.clone(),
+                ))
             }
         }
     }
@@ -387,7 +402,7 @@ impl ResourceContainer {
             Self::Fungible { divisibility, .. } => ResourceType::Fungible {
                 divisibility: *divisibility,
             },
-            Self::NonFungible { .. } => ResourceType::NonFungible,
+            Self::NonFungible { id_type, .. } => ResourceType::NonFungible { id_type: *id_type },
         }
     }
 
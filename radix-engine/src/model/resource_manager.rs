@@ -6,7 +6,9 @@ use scrypto::engine::types::*;
 use scrypto::prelude::AccessRule::{AllowAll, DenyAll};
 use scrypto::prelude::ResourceMethod::Withdraw;
 use scrypto::resource::Mutability::LOCKED;
-use scrypto::resource::ResourceMethod::{Burn, Mint, UpdateMetadata, UpdateNonFungibleData};
+use scrypto::resource::ResourceMethod::{
+    Burn, Freeze, Mint, Recall, UpdateMetadata, UpdateNonFungibleData,
+};
 use scrypto::resource::*;
 use scrypto::rust::collections::*;
 use scrypto::rust::string::String;
@@ -32,8 +34,18 @@ pub enum ResourceManagerError {
     InvalidResourceFlags(u64),
     InvalidMintPermission,
     ResourceTypeDoesNotMatch,
+    NonFungibleIdTypeDoesNotMatch(NonFungibleIdType, NonFungibleIdType),
+    AutoIdNotSupported(NonFungibleIdType),
     MaxMintAmountExceeded,
+    MaxSupplyExceeded,
+    MaxSupplyCannotBeRaised,
+    MaxSupplyBelowTotalSupply,
+    InvalidMetadata(MetadataError),
+    MetadataLocked(String),
     InvalidNonFungibleData,
+    NonFungibleDataDoesNotMatchSchema,
+    NonFungibleDataSchemaNotFound,
+    NonFungibleDataFieldNotFound(String),
     NonFungibleAlreadyExists(NonFungibleAddress),
     NonFungibleNotFound(NonFungibleAddress),
     InvalidRequestData(DecodeError),
@@ -104,10 +116,15 @@ impl MethodEntry {
 #[derive(Debug, Clone, TypeId, Encode, Decode)]
 pub struct ResourceManager {
     resource_type: ResourceType,
-    metadata: HashMap<String, String>,
+    metadata: BTreeMap<String, String>,
+    locked_metadata: BTreeSet<String>,
     method_table: HashMap<String, Option<ResourceMethod>>,
     authorization: HashMap<ResourceMethod, MethodEntry>,
     total_supply: Decimal,
+    max_supply: Option<Decimal>,
+    next_auto_id: u64,
+    minted_non_fungible_count: u64,
+    non_fungible_data_schema: Option<NonFungibleDataSchema>,
 }
 
 impl ResourceManager {
@@ -115,6 +132,9 @@ impl ResourceManager {
         resource_type: ResourceType,
         metadata: HashMap<String, String>,
         mut auth: HashMap<ResourceMethod, (AccessRule, Mutability)>,
+        max_supply: Option<Decimal>,
+        non_fungible_data_schema: Option<NonFungibleDataSchema>,
+        locked_metadata: BTreeSet<String>,
     ) -> Result<Self, ResourceManagerError> {
         let mut method_table: HashMap<String, Option<ResourceMethod>> = HashMap::new();
         method_table.insert("mint".to_string(), Some(Mint));
@@ -122,7 +142,13 @@ impl ResourceManager {
         method_table.insert("take_from_vault".to_string(), Some(Withdraw));
         method_table.insert("put_into_vault".to_string(), Some(Deposit));
         method_table.insert("update_metadata".to_string(), Some(UpdateMetadata));
-        if let ResourceType::NonFungible = resource_type {
+        method_table.insert("set_metadata".to_string(), Some(UpdateMetadata));
+        method_table.insert("lock_metadata".to_string(), Some(UpdateMetadata));
+        method_table.insert("set_max_supply".to_string(), Some(Mint));
+        method_table.insert("recall_from_vault".to_string(), Some(Recall));
+        method_table.insert("freeze_vault".to_string(), Some(Freeze));
+        method_table.insert("unfreeze_vault".to_string(), Some(Freeze));
+        if let ResourceType::NonFungible { .. } = resource_type {
             method_table.insert("take_non_fungibles_from_vault".to_string(), Some(Withdraw));
         }
 
@@ -131,14 +157,17 @@ impl ResourceManager {
             "create_bucket_proof",
             "create_empty_bucket",
             "get_metadata",
+            "get_locked_metadata",
             "get_resource_type",
             "get_total_supply",
+            "get_max_supply",
             "take_from_bucket",
             "put_into_bucket",
             "get_bucket_amount",
             "get_bucket_resource_address",
             "get_vault_amount",
             "get_vault_resource_address",
+            "is_vault_frozen",
             "create_vault_proof",
             "create_vault_proof_by_amount",
             "create_vault_proof_by_ids",
@@ -146,22 +175,48 @@ impl ResourceManager {
             method_table.insert(pub_method.to_string(), None);
         }
 
-        if let ResourceType::NonFungible = resource_type {
+        if let ResourceType::NonFungible { .. } = resource_type {
             method_table.insert(
                 "update_non_fungible_mutable_data".to_string(),
                 Some(UpdateNonFungibleData),
             );
+            method_table.insert(
+                "update_non_fungible_mutable_data_field".to_string(),
+                Some(UpdateNonFungibleData),
+            );
             for pub_method in [
                 "take_non_fungibles_from_bucket",
                 "non_fungible_exists",
                 "get_non_fungible",
                 "get_non_fungible_ids_in_bucket",
                 "get_non_fungible_ids_in_vault",
+                "get_minted_non_fungible_count",
+                "get_minted_non_fungible_ids",
+                "get_non_fungible_data_schema",
             ] {
                 method_table.insert(pub_method.to_string(), None);
             }
         }
 
+        if let Some(max_supply) = max_supply {
+            if max_supply.is_negative() {
+                return Err(ResourceManagerError::InvalidAmount(
+                    max_supply,
+                    resource_type.divisibility(),
+                ));
+            }
+        }
+
+        if matches!(resource_type, ResourceType::NonFungible { .. })
+            && non_fungible_data_schema.is_none()
+        {
+            return Err(ResourceManagerError::NonFungibleDataSchemaNotFound);
+        }
+
+        for (key, value) in &metadata {
+            validate_metadata(key, value).map_err(ResourceManagerError::InvalidMetadata)?;
+        }
+
         let mut authorization: HashMap<ResourceMethod, MethodEntry> = HashMap::new();
         for (auth_entry_key, default) in [
             (Mint, (DenyAll, LOCKED)),
@@ -170,6 +225,8 @@ impl ResourceManager {
             (Deposit, (AllowAll, LOCKED)),
             (UpdateMetadata, (DenyAll, LOCKED)),
             (UpdateNonFungibleData, (DenyAll, LOCKED)),
+            (Recall, (DenyAll, LOCKED)),
+            (Freeze, (DenyAll, LOCKED)),
         ] {
             let entry = auth.remove(&auth_entry_key).unwrap_or(default);
             authorization.insert(auth_entry_key, MethodEntry::new(entry));
@@ -177,10 +234,15 @@ impl ResourceManager {
 
         let resource_manager = Self {
             resource_type,
-            metadata,
+            metadata: metadata.into_iter().collect(),
+            locked_metadata,
             method_table,
             authorization,
             total_supply: 0.into(),
+            max_supply,
+            next_auto_id: 1,
+            minted_non_fungible_count: 0,
+            non_fungible_data_schema,
         };
 
         Ok(resource_manager)
@@ -213,14 +275,31 @@ impl ResourceManager {
         self.resource_type
     }
 
-    pub fn metadata(&self) -> &HashMap<String, String> {
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
         &self.metadata
     }
 
+    pub fn locked_metadata(&self) -> &BTreeSet<String> {
+        &self.locked_metadata
+    }
+
     pub fn total_supply(&self) -> Decimal {
         self.total_supply
     }
 
+    pub fn max_supply(&self) -> Option<Decimal> {
+        self.max_supply
+    }
+
+    /// Returns the number of non-fungibles ever minted, including the burned ones.
+    pub fn minted_non_fungible_count(&self) -> u64 {
+        self.minted_non_fungible_count
+    }
+
+    pub fn non_fungible_data_schema(&self) -> Option<&NonFungibleDataSchema> {
+        self.non_fungible_data_schema.as_ref()
+    }
+
     fn mint<S: SystemApi>(
         &mut self,
         mint_params: MintParams,
@@ -232,6 +311,9 @@ impl ResourceManager {
             MintParams::NonFungible { entries } => {
                 self.mint_non_fungibles(entries, self_address, system_api)
             }
+            MintParams::NonFungibleAutoId { entries } => {
+                self.mint_non_fungibles_auto_id(entries, self_address, system_api)
+            }
         }
     }
 
@@ -250,6 +332,7 @@ impl ResourceManager {
                 return Err(ResourceManagerError::MaxMintAmountExceeded);
             }
 
+            self.check_max_supply(amount)?;
             self.total_supply += amount;
 
             Ok(ResourceContainer::new_fungible(
@@ -287,8 +370,19 @@ impl ResourceManager {
         system_api: &mut S,
     ) -> Result<ResourceContainer, ResourceManagerError> {
         // check resource type
-        if !matches!(self.resource_type, ResourceType::NonFungible) {
-            return Err(ResourceManagerError::ResourceTypeDoesNotMatch);
+        let id_type = match self.resource_type {
+            ResourceType::NonFungible { id_type } => id_type,
+            _ => return Err(ResourceManagerError::ResourceTypeDoesNotMatch),
+        };
+
+        // check id types
+        for id in entries.keys() {
+            if id.id_type() != id_type {
+                return Err(ResourceManagerError::NonFungibleIdTypeDoesNotMatch(
+                    id_type,
+                    id.id_type(),
+                ));
+            }
         }
 
         // check amount
@@ -301,6 +395,7 @@ impl ResourceManager {
             return Err(ResourceManagerError::MaxMintAmountExceeded);
         }
 
+        self.check_max_supply(amount)?;
         self.total_supply += amount;
 
         // Allocate non-fungibles
@@ -315,13 +410,86 @@ impl ResourceManager {
 
             let immutable_data = Self::process_non_fungible_data(&data.0)?;
             let mutable_data = Self::process_non_fungible_data(&data.1)?;
+            let schema = self
+                .non_fungible_data_schema
+                .as_ref()
+                .ok_or(ResourceManagerError::NonFungibleDataSchemaNotFound)?;
+            if !immutable_data.matches_schema(&schema.immutable)
+                || !mutable_data.matches_schema(&schema.mutable)
+            {
+                return Err(ResourceManagerError::NonFungibleDataDoesNotMatchSchema);
+            }
             let non_fungible = NonFungible::new(immutable_data.raw, mutable_data.raw);
 
             system_api.set_non_fungible(non_fungible_address, Some(non_fungible));
+            self.minted_non_fungible_count += 1;
             ids.insert(id);
         }
 
-        Ok(ResourceContainer::new_non_fungible(self_address, ids))
+        Ok(ResourceContainer::new_non_fungible(
+            self_address,
+            id_type,
+            ids,
+        ))
+    }
+
+    fn mint_non_fungibles_auto_id<S: SystemApi>(
+        &mut self,
+        entries: Vec<(Vec<u8>, Vec<u8>)>,
+        self_address: ResourceAddress,
+        system_api: &mut S,
+    ) -> Result<ResourceContainer, ResourceManagerError> {
+        let id_type = match self.resource_type {
+            ResourceType::NonFungible { id_type } => id_type,
+            _ => return Err(ResourceManagerError::ResourceTypeDoesNotMatch),
+        };
+
+        let mut ids_and_entries = HashMap::new();
+        for entry in entries {
+            let id = match id_type {
+                NonFungibleIdType::U64 => {
+                    // Skip the ids which have been minted explicitly
+                    loop {
+                        let id = NonFungibleId::from_u64(self.next_auto_id);
+                        self.next_auto_id += 1;
+                        let non_fungible_address =
+                            NonFungibleAddress::new(self_address, id.clone());
+                        if system_api.get_non_fungible(&non_fungible_address).is_none() {
+                            break id;
+                        }
+                    }
+                }
+                NonFungibleIdType::UUID => NonFungibleId::from_uuid(system_api.generate_uuid()),
+                _ => return Err(ResourceManagerError::AutoIdNotSupported(id_type)),
+            };
+            ids_and_entries.insert(id, entry);
+        }
+
+        self.mint_non_fungibles(ids_and_entries, self_address, system_api)
+    }
+
+    fn check_max_supply(&self, amount: Decimal) -> Result<(), ResourceManagerError> {
+        match self.max_supply {
+            Some(max_supply) if self.total_supply + amount > max_supply => {
+                Err(ResourceManagerError::MaxSupplyExceeded)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Sets the max supply, which can only be lowered and never below the total supply.
+    fn set_max_supply(&mut self, max_supply: Decimal) -> Result<(), ResourceManagerError> {
+        if let Some(current) = self.max_supply {
+            if max_supply > current {
+                return Err(ResourceManagerError::MaxSupplyCannotBeRaised);
+            }
+        }
+        if max_supply < self.total_supply {
+            return Err(ResourceManagerError::MaxSupplyBelowTotalSupply);
+        }
+        self.max_supply = Some(max_supply);
+
+        Ok(())
     }
 
     pub fn burn(&mut self, amount: Decimal) {
@@ -330,13 +498,31 @@ impl ResourceManager {
 
     fn update_metadata(
         &mut self,
-        new_metadata: HashMap<String, String>,
+        new_metadata: BTreeMap<String, String>,
     ) -> Result<(), ResourceManagerError> {
+        for (key, value) in &new_metadata {
+            validate_metadata(key, value).map_err(ResourceManagerError::InvalidMetadata)?;
+        }
+        for key in &self.locked_metadata {
+            if self.metadata.get(key) != new_metadata.get(key) {
+                return Err(ResourceManagerError::MetadataLocked(key.clone()));
+            }
+        }
         self.metadata = new_metadata;
 
         Ok(())
     }
 
+    fn set_metadata(&mut self, key: String, value: String) -> Result<(), ResourceManagerError> {
+        validate_metadata(&key, &value).map_err(ResourceManagerError::InvalidMetadata)?;
+        if self.locked_metadata.contains(&key) {
+            return Err(ResourceManagerError::MetadataLocked(key));
+        }
+        self.metadata.insert(key, value);
+
+        Ok(())
+    }
+
     fn check_amount(&self, amount: Decimal) -> Result<(), ResourceManagerError> {
         let divisibility = self.resource_type.divisibility();
 
@@ -362,7 +548,30 @@ impl ResourceManager {
                     .map_err(ResourceManagerError::InvalidRequestData)?;
                 let mint_params_maybe: Option<MintParams> = scrypto_decode(&args[3].raw)
                     .map_err(ResourceManagerError::InvalidRequestData)?;
-                let resource_manager = ResourceManager::new(resource_type, metadata, auth)?;
+                // The trailing arguments are optional, for callers which predate them
+                let max_supply: Option<Decimal> = match args.get(4) {
+                    Some(arg) => scrypto_decode(&arg.raw)
+                        .map_err(ResourceManagerError::InvalidRequestData)?,
+                    None => None,
+                };
+                let data_schema: Option<NonFungibleDataSchema> = match args.get(5) {
+                    Some(arg) => scrypto_decode(&arg.raw)
+                        .map_err(ResourceManagerError::InvalidRequestData)?,
+                    None => None,
+                };
+                let locked_metadata: BTreeSet<String> = match args.get(6) {
+                    Some(arg) => scrypto_decode(&arg.raw)
+                        .map_err(ResourceManagerError::InvalidRequestData)?,
+                    None => BTreeSet::new(),
+                };
+                let resource_manager = ResourceManager::new(
+                    resource_type,
+                    metadata,
+                    auth,
+                    max_supply,
+                    data_schema,
+                    locked_metadata,
+                )?;
                 let resource_address = system_api.create_resource(resource_manager);
 
                 let bucket_id = if let Some(mint_params) = mint_params_maybe {
@@ -429,14 +638,36 @@ impl ResourceManager {
                 )))
             }
             "get_metadata" => Ok(ScryptoValue::from_value(&self.metadata)),
+            "get_locked_metadata" => Ok(ScryptoValue::from_value(&self.locked_metadata)),
             "get_resource_type" => Ok(ScryptoValue::from_value(&self.resource_type)),
             "get_total_supply" => Ok(ScryptoValue::from_value(&self.total_supply)),
+            "get_max_supply" => Ok(ScryptoValue::from_value(&self.max_supply)),
+            "set_max_supply" => {
+                let max_supply: Decimal = scrypto_decode(&args[0].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+                self.set_max_supply(max_supply)?;
+                Ok(ScryptoValue::from_value(&()))
+            }
             "update_metadata" => {
-                let new_metadata: HashMap<String, String> = scrypto_decode(&args[0].raw)
+                let new_metadata: BTreeMap<String, String> = scrypto_decode(&args[0].raw)
                     .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
                 self.update_metadata(new_metadata)?;
                 Ok(ScryptoValue::from_value(&()))
             }
+            "set_metadata" => {
+                let key: String = scrypto_decode(&args[0].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+                let value: String = scrypto_decode(&args[1].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+                self.set_metadata(key, value)?;
+                Ok(ScryptoValue::from_value(&()))
+            }
+            "lock_metadata" => {
+                let key: String = scrypto_decode(&args[0].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+                self.locked_metadata.insert(key);
+                Ok(ScryptoValue::from_value(&()))
+            }
             "update_non_fungible_mutable_data" => {
                 let non_fungible_id: NonFungibleId = scrypto_decode(&args[0].raw)
                     .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
@@ -446,6 +677,13 @@ impl ResourceManager {
                 let non_fungible_address =
                     NonFungibleAddress::new(resource_address.clone(), non_fungible_id);
                 let data = Self::process_non_fungible_data(&new_mutable_data)?;
+                let schema = self
+                    .non_fungible_data_schema
+                    .as_ref()
+                    .ok_or(ResourceManagerError::NonFungibleDataSchemaNotFound)?;
+                if !data.matches_schema(&schema.mutable) {
+                    return Err(ResourceManagerError::NonFungibleDataDoesNotMatchSchema);
+                }
                 let mut non_fungible = system_api
                     .get_non_fungible(&non_fungible_address)
                     .cloned()
@@ -457,6 +695,51 @@ impl ResourceManager {
 
                 Ok(ScryptoValue::from_value(&()))
             }
+            "update_non_fungible_mutable_data_field" => {
+                let non_fungible_id: NonFungibleId = scrypto_decode(&args[0].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+                let field: String = scrypto_decode(&args[1].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+                let new_value: Vec<u8> = scrypto_decode(&args[2].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+
+                let schema = self
+                    .non_fungible_data_schema
+                    .as_ref()
+                    .ok_or(ResourceManagerError::NonFungibleDataSchemaNotFound)?;
+                let (index, field_type) = schema
+                    .mutable_field(&field)
+                    .ok_or(ResourceManagerError::NonFungibleDataFieldNotFound(field))?;
+                let value = Self::process_non_fungible_data(&new_value)?;
+                if !value.matches_schema(field_type) {
+                    return Err(ResourceManagerError::NonFungibleDataDoesNotMatchSchema);
+                }
+
+                let non_fungible_address =
+                    NonFungibleAddress::new(resource_address.clone(), non_fungible_id);
+                let mut non_fungible = system_api
+                    .get_non_fungible(&non_fungible_address)
+                    .cloned()
+                    .ok_or(ResourceManagerError::NonFungibleNotFound(
+                        non_fungible_address.clone(),
+                    ))?;
+                let mut data = Self::process_non_fungible_data(&non_fungible.mutable_data())?;
+                match &mut data.dom {
+                    Value::Struct { fields } if index < fields.len() => {
+                        fields[index] = value.dom;
+                    }
+                    _ => return Err(ResourceManagerError::NonFungibleDataDoesNotMatchSchema),
+                }
+                let mut bytes = Vec::new();
+                encode_any(None, &data.dom, &mut Encoder::with_type(&mut bytes));
+                non_fungible.set_mutable_data(bytes);
+                system_api.set_non_fungible(non_fungible_address, Some(non_fungible));
+
+                Ok(ScryptoValue::from_value(&()))
+            }
+            "get_non_fungible_data_schema" => {
+                Ok(ScryptoValue::from_value(&self.non_fungible_data_schema))
+            }
             "non_fungible_exists" => {
                 let non_fungible_id: NonFungibleId = scrypto_decode(&args[0].raw)
                     .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
@@ -465,6 +748,22 @@ impl ResourceManager {
                 let non_fungible = system_api.get_non_fungible(&non_fungible_address);
                 Ok(ScryptoValue::from_value(&non_fungible.is_some()))
             }
+            "get_minted_non_fungible_count" => {
+                Ok(ScryptoValue::from_value(&self.minted_non_fungible_count))
+            }
+            "get_minted_non_fungible_ids" => {
+                let offset: u32 = scrypto_decode(&args[0].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+                let limit: u32 = scrypto_decode(&args[1].raw)
+                    .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
+
+                let ids: Vec<(NonFungibleId, bool)> = system_api
+                    .get_non_fungibles(resource_address, offset as usize, limit as usize)
+                    .into_iter()
+                    .map(|(id, non_fungible)| (id, non_fungible.is_none()))
+                    .collect();
+                Ok(ScryptoValue::from_value(&ids))
+            }
             "get_non_fungible" => {
                 let non_fungible_id: NonFungibleId = scrypto_decode(&args[0].raw)
                     .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
@@ -2,7 +2,8 @@ use sbor::*;
 use scrypto::buffer::scrypto_encode;
 use scrypto::crypto::*;
 use scrypto::engine::types::*;
-use scrypto::rust::collections::BTreeSet;
+use scrypto::resource::AccessRule;
+use scrypto::rust::collections::{BTreeMap, BTreeSet};
 use scrypto::rust::string::String;
 use scrypto::rust::vec;
 use scrypto::rust::vec::Vec;
@@ -63,6 +64,21 @@ pub enum Instruction {
         resource_address: ResourceAddress,
     },
 
+    /// Asserts worktop contains no resource.
+    AssertWorktopIsEmpty,
+
+    /// Asserts worktop contains resource by at most the given amount.
+    AssertWorktopContainsAtMost {
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    },
+
+    /// Asserts worktop contains resource by exactly the given non-fungible IDs.
+    AssertWorktopContainsExactIds {
+        ids: BTreeSet<NonFungibleId>,
+        resource_address: ResourceAddress,
+    },
+
     /// Takes the last proof from the auth zone.
     PopFromAuthZone,
 
@@ -123,7 +139,64 @@ pub enum Instruction {
     },
 
     /// Publishes a package.
-    PublishPackage { code: Vec<u8> },
+    ///
+    /// Packages with an owner can be upgraded by the owner; others are immutable.
+    PublishPackage {
+        code: Vec<u8>,
+        metadata: BTreeMap<String, String>,
+        owner: Option<AccessRule>,
+    },
+
+    /// Replaces the code of a package, whose existing components are migrated when next loaded.
+    ///
+    /// Requires the owner of the package.
+    UpgradePackage {
+        package_address: PackageAddress,
+        code: Vec<u8>,
+    },
+
+    /// Recalls resource from a vault of any component, into the worktop.
+    ///
+    /// Requires the recall authority of the resource.
+    RecallFromVault {
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+        amount: Decimal,
+    },
+
+    /// Freezes a vault of any component, so that no resource can be taken from it.
+    ///
+    /// Requires the freeze authority of the resource.
+    FreezeVault {
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+    },
+
+    /// Unfreezes a vault of any component.
+    ///
+    /// Requires the freeze authority of the resource.
+    UnfreezeVault {
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+    },
+
+    /// Sets a metadata entry of any component.
+    ///
+    /// Requires the metadata access rule of the component.
+    SetComponentMetadata {
+        component_address: ComponentAddress,
+        key: String,
+        value: String,
+    },
+
+    /// Sets a metadata entry of any package.
+    ///
+    /// Requires the owner of the package.
+    SetPackageMetadata {
+        package_address: PackageAddress,
+        key: String,
+        value: String,
+    },
 
     /// Specifies transaction nonce
     Nonce {
@@ -236,6 +309,27 @@ impl SignedTransaction {
                         resource_address,
                     });
                 }
+                Instruction::AssertWorktopIsEmpty => {
+                    instructions.push(ValidatedInstruction::AssertWorktopIsEmpty);
+                }
+                Instruction::AssertWorktopContainsAtMost {
+                    amount,
+                    resource_address,
+                } => {
+                    instructions.push(ValidatedInstruction::AssertWorktopContainsAtMost {
+                        amount,
+                        resource_address,
+                    });
+                }
+                Instruction::AssertWorktopContainsExactIds {
+                    ids,
+                    resource_address,
+                } => {
+                    instructions.push(ValidatedInstruction::AssertWorktopContainsExactIds {
+                        ids,
+                        resource_address,
+                    });
+                }
                 Instruction::PopFromAuthZone => {
                     id_validator
                         .new_proof(ProofKind::AuthZoneProof)
@@ -336,8 +430,76 @@ impl SignedTransaction {
                         method,
                     });
                 }
-                Instruction::PublishPackage { code } => {
-                    instructions.push(ValidatedInstruction::PublishPackage { code });
+                Instruction::PublishPackage {
+                    code,
+                    metadata,
+                    owner,
+                } => {
+                    instructions.push(ValidatedInstruction::PublishPackage {
+                        code,
+                        metadata,
+                        owner,
+                    });
+                }
+                Instruction::UpgradePackage {
+                    package_address,
+                    code,
+                } => {
+                    instructions.push(ValidatedInstruction::UpgradePackage {
+                        package_address,
+                        code,
+                    });
+                }
+                Instruction::RecallFromVault {
+                    component_address,
+                    vault_id,
+                    amount,
+                } => {
+                    instructions.push(ValidatedInstruction::RecallFromVault {
+                        component_address,
+                        vault_id,
+                        amount,
+                    });
+                }
+                Instruction::FreezeVault {
+                    component_address,
+                    vault_id,
+                } => {
+                    instructions.push(ValidatedInstruction::FreezeVault {
+                        component_address,
+                        vault_id,
+                    });
+                }
+                Instruction::UnfreezeVault {
+                    component_address,
+                    vault_id,
+                } => {
+                    instructions.push(ValidatedInstruction::UnfreezeVault {
+                        component_address,
+                        vault_id,
+                    });
+                }
+                Instruction::SetComponentMetadata {
+                    component_address,
+                    key,
+                    value,
+                } => {
+                    instructions.push(ValidatedInstruction::SetComponentMetadata {
+                        component_address,
+                        key,
+                        value,
+                    });
+                }
+                Instruction::SetPackageMetadata {
+                    package_address,
+                    key,
+                    value,
+                } => {
+                    instructions.push(ValidatedInstruction::SetPackageMetadata {
+                        package_address,
+                        key,
+                        value,
+                    });
                 }
                 Instruction::Nonce { .. } => {
                     // TODO: validate nonce
@@ -151,7 +151,40 @@ impl TransactionProcess {
                 } => {
                     system_api.invoke_snode(
                         SNodeRef::WorktopRef,
-                        "assert_contains_amount".to_string(),
+                        "assert_contains_non_fungibles".to_string(),
+                        vec![
+                            ScryptoValue::from_value(ids),
+                            ScryptoValue::from_value(resource_address),
+                        ]
+                    )
+                },
+                ValidatedInstruction::AssertWorktopIsEmpty => {
+                    system_api.invoke_snode(
+                        SNodeRef::WorktopRef,
+                        "assert_is_empty".to_string(),
+                        vec![]
+                    )
+                }
+                ValidatedInstruction::AssertWorktopContainsAtMost {
+                    amount,
+                    resource_address,
+                } => {
+                    system_api.invoke_snode(
+                        SNodeRef::WorktopRef,
+                        "assert_contains_at_most".to_string(),
+                        vec![
+                            ScryptoValue::from_value(amount),
+                            ScryptoValue::from_value(resource_address),
+                        ]
+                    )
+                },
+                ValidatedInstruction::AssertWorktopContainsExactIds {
+                    ids,
+                    resource_address,
+                } => {
+                    system_api.invoke_snode(
+                        SNodeRef::WorktopRef,
+                        "assert_contains_exact_non_fungibles".to_string(),
                         vec![
                             ScryptoValue::from_value(ids),
                             ScryptoValue::from_value(resource_address),
@@ -390,13 +423,88 @@ impl TransactionProcess {
                             )
                         })
                 },
-                ValidatedInstruction::PublishPackage { code } => {
+                ValidatedInstruction::PublishPackage { code, metadata, owner } => {
                     system_api.invoke_snode(
                         SNodeRef::PackageStatic,
                         "publish".to_string(),
+                        vec![
+                            ScryptoValue::from_value(code),
+                            ScryptoValue::from_value(metadata),
+                            ScryptoValue::from_value(owner),
+                        ],
+                    )
+                },
+                ValidatedInstruction::UpgradePackage { package_address, code } => {
+                    system_api.invoke_snode(
+                        SNodeRef::PackageRef(*package_address),
+                        "upgrade".to_string(),
                         vec![ScryptoValue::from_value(code)],
                     )
                 },
+                ValidatedInstruction::RecallFromVault {
+                    component_address,
+                    vault_id,
+                    amount,
+                } => {
+                    system_api.invoke_snode(
+                        SNodeRef::ComponentVaultRef(*component_address, *vault_id),
+                        "recall_from_vault".to_string(),
+                        vec![ScryptoValue::from_value(amount)],
+                    )
+                    .and_then(|result| {
+                        // Auto move into worktop
+                        for (bucket_id, _) in &result.bucket_ids {
+                            system_api.invoke_snode(
+                                SNodeRef::WorktopRef,
+                                "put".to_string(),
+                                vec![ScryptoValue::from_value(&scrypto::resource::Bucket(*bucket_id))]
+                            ).unwrap(); // TODO: Remove unwrap
+                        }
+                        Ok(result)
+                    })
+                },
+                ValidatedInstruction::FreezeVault {
+                    component_address,
+                    vault_id,
+                } => {
+                    system_api.invoke_snode(
+                        SNodeRef::ComponentVaultRef(*component_address, *vault_id),
+                        "freeze_vault".to_string(),
+                        vec![],
+                    )
+                },
+                ValidatedInstruction::UnfreezeVault {
+                    component_address,
+                    vault_id,
+                } => {
+                    system_api.invoke_snode(
+                        SNodeRef::ComponentVaultRef(*component_address, *vault_id),
+                        "unfreeze_vault".to_string(),
+                        vec![],
+                    )
+                },
+                ValidatedInstruction::SetComponentMetadata {
+                    component_address,
+                    key,
+                    value,
+                } => {
+                    system_api.invoke_snode(
+                        SNodeRef::ComponentRef(*component_address),
+                        "set_metadata".to_string(),
+                        vec![ScryptoValue::from_value(key), ScryptoValue::from_value(value)],
+                    )
+                },
+                ValidatedInstruction::SetPackageMetadata {
+                    package_address,
+                    key,
+                    value,
+                } => {
+                    system_api.invoke_snode(
+                        SNodeRef::PackageRef(*package_address),
+                        "set_metadata".to_string(),
+                        vec![ScryptoValue::from_value(key), ScryptoValue::from_value(value)],
+                    )
+                },
             }?;
             self.outputs.push(result);
         }
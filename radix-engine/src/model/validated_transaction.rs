@@ -1,6 +1,7 @@
 use scrypto::crypto::*;
 use scrypto::engine::types::*;
-use scrypto::rust::collections::{BTreeSet};
+use scrypto::resource::AccessRule;
+use scrypto::rust::collections::{BTreeMap, BTreeSet};
 use scrypto::rust::string::String;
 use scrypto::rust::vec::Vec;
 use scrypto::values::*;
@@ -40,6 +41,15 @@ pub enum ValidatedInstruction {
         ids: BTreeSet<NonFungibleId>,
         resource_address: ResourceAddress,
     },
+    AssertWorktopIsEmpty,
+    AssertWorktopContainsAtMost {
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    },
+    AssertWorktopContainsExactIds {
+        ids: BTreeSet<NonFungibleId>,
+        resource_address: ResourceAddress,
+    },
     PopFromAuthZone,
     PushToAuthZone {
         proof_id: ProofId,
@@ -82,5 +92,34 @@ pub enum ValidatedInstruction {
     },
     PublishPackage {
         code: Vec<u8>,
+        metadata: BTreeMap<String, String>,
+        owner: Option<AccessRule>,
+    },
+    UpgradePackage {
+        package_address: PackageAddress,
+        code: Vec<u8>,
+    },
+    RecallFromVault {
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+        amount: Decimal,
+    },
+    FreezeVault {
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+    },
+    UnfreezeVault {
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+    },
+    SetComponentMetadata {
+        component_address: ComponentAddress,
+        key: String,
+        value: String,
+    },
+    SetPackageMetadata {
+        package_address: PackageAddress,
+        key: String,
+        value: String,
     },
 }
\ No newline at end of file
@@ -24,18 +24,21 @@ pub enum VaultError {
     CouldNotTakeBucket,
     ProofError(ProofError),
     CouldNotCreateProof,
+    VaultFrozen,
 }
 
 /// A persistent resource container.
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct Vault {
     container: Rc<RefCell<ResourceContainer>>,
+    frozen: bool,
 }
 
 impl Vault {
     pub fn new(container: ResourceContainer) -> Self {
         Self {
             container: Rc::new(RefCell::new(container)),
+            frozen: false,
         }
     }
 
@@ -64,7 +67,7 @@ impl Vault {
             ResourceType::Fungible { .. } => {
                 self.create_proof_by_amount(self.total_amount(), container_id)
             }
-            ResourceType::NonFungible => {
+            ResourceType::NonFungible { .. } => {
                 self.create_proof_by_ids(&self.total_ids().unwrap(), container_id)
             }
         }
@@ -144,6 +147,18 @@ impl Vault {
         self.borrow_container().is_empty()
     }
 
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    fn check_not_frozen(&self) -> Result<(), VaultError> {
+        if self.frozen {
+            Err(VaultError::VaultFrozen)
+        } else {
+            Ok(())
+        }
+    }
+
     fn borrow_container(&self) -> Ref<ResourceContainer> {
         self.container.borrow()
     }
@@ -170,6 +185,7 @@ impl Vault {
             "take_from_vault" => {
                 let amount: Decimal =
                     scrypto_decode(&args[0].raw).map_err(|e| VaultError::InvalidRequestData(e))?;
+                self.check_not_frozen()?;
                 let container = self.take(amount)?;
                 let bucket_id = system_api.create_bucket(container).map_err(|_| VaultError::CouldNotCreateBucket)?;
                 Ok(ScryptoValue::from_value(&scrypto::resource::Bucket(bucket_id)))
@@ -177,10 +193,27 @@ impl Vault {
             "take_non_fungibles_from_vault" => {
                 let non_fungible_ids: BTreeSet<NonFungibleId> =
                     scrypto_decode(&args[0].raw).map_err(|e| VaultError::InvalidRequestData(e))?;
+                self.check_not_frozen()?;
                 let container = self.take_non_fungibles(&non_fungible_ids)?;
                 let bucket_id = system_api.create_bucket(container).map_err(|_| VaultError::CouldNotCreateBucket)?;
                 Ok(ScryptoValue::from_value(&scrypto::resource::Bucket(bucket_id)))
             }
+            "recall_from_vault" => {
+                let amount: Decimal =
+                    scrypto_decode(&args[0].raw).map_err(|e| VaultError::InvalidRequestData(e))?;
+                let container = self.take(amount)?;
+                let bucket_id = system_api.create_bucket(container).map_err(|_| VaultError::CouldNotCreateBucket)?;
+                Ok(ScryptoValue::from_value(&scrypto::resource::Bucket(bucket_id)))
+            }
+            "freeze_vault" => {
+                self.frozen = true;
+                Ok(ScryptoValue::from_value(&()))
+            }
+            "unfreeze_vault" => {
+                self.frozen = false;
+                Ok(ScryptoValue::from_value(&()))
+            }
+            "is_vault_frozen" => Ok(ScryptoValue::from_value(&self.frozen)),
             "get_vault_amount" => {
                 let amount = self.total_amount();
                 Ok(ScryptoValue::from_value(&amount))
@@ -28,6 +28,17 @@ pub enum WorktopError {
     CouldNotCreateBucket,
     CouldNotTakeBucket,
     AssertionFailed,
+    NotEmpty(Vec<ResourceAddress>),
+    AmountAboveMaximum {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        maximum: Decimal,
+    },
+    NonFungibleIdsMismatch {
+        resource_address: ResourceAddress,
+        expected: BTreeSet<NonFungibleId>,
+        actual: BTreeSet<NonFungibleId>,
+    },
 }
 
 impl Worktop {
@@ -135,6 +146,17 @@ impl Worktop {
         true
     }
 
+    /// Returns the addresses of all resources with a non-zero balance, sorted by address.
+    pub fn non_empty_resource_addresses(&self) -> Vec<ResourceAddress> {
+        let mut resource_addresses: Vec<ResourceAddress> = self
+            .resource_addresses()
+            .into_iter()
+            .filter(|resource_address| !self.total_amount(*resource_address).is_zero())
+            .collect();
+        resource_addresses.sort_by(|a, b| a.0.cmp(&b.0));
+        resource_addresses
+    }
+
     pub fn create_reference_for_proof(
         &self,
         resource_address: ResourceAddress,
@@ -285,6 +307,50 @@ impl Worktop {
                     Ok(ScryptoValue::from_value(&()))
                 }
             }
+            "assert_is_empty" => {
+                let resource_addresses = self.non_empty_resource_addresses();
+                if !resource_addresses.is_empty() {
+                    Err(WorktopError::NotEmpty(resource_addresses))
+                } else {
+                    Ok(ScryptoValue::from_value(&()))
+                }
+            }
+            "assert_contains_at_most" => {
+                let maximum: Decimal =
+                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address: ResourceAddress =
+                    scrypto_decode(&args[1].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+
+                let amount = self.total_amount(resource_address);
+                if amount > maximum {
+                    Err(WorktopError::AmountAboveMaximum {
+                        resource_address,
+                        amount,
+                        maximum,
+                    })
+                } else {
+                    Ok(ScryptoValue::from_value(&()))
+                }
+            }
+            "assert_contains_exact_non_fungibles" => {
+                let expected: BTreeSet<NonFungibleId> =
+                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address: ResourceAddress =
+                    scrypto_decode(&args[1].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+
+                let actual = self
+                    .total_ids(resource_address)
+                    .map_err(WorktopError::ResourceContainerError)?;
+                if actual != expected {
+                    Err(WorktopError::NonFungibleIdsMismatch {
+                        resource_address,
+                        expected,
+                        actual,
+                    })
+                } else {
+                    Ok(ScryptoValue::from_value(&()))
+                }
+            }
             "drain" => {
                 let mut buckets = Vec::new();
                 for (_, container) in self.containers.drain() {
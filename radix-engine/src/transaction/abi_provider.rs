@@ -65,9 +65,10 @@ impl AbiProvider for BasicAbiProvider {
         // Deterministic transaction context
         let mut ledger = self.substate_store.clone();
         let transaction_hash = hash([]);
+        let current_time = ledger.get_time();
 
         // Start a process and run abi generator
-        let mut track = Track::new(&mut ledger, transaction_hash, Vec::new());
+        let mut track = Track::new(&mut ledger, transaction_hash, Vec::new(), current_time);
         let mut proc = track.start_process(self.trace);
         let output: (Type, Vec<abi::Function>, Vec<abi::Method>) = proc
             .call_abi(package_address, blueprint_name)
@@ -77,6 +78,7 @@ impl AbiProvider for BasicAbiProvider {
         Ok(abi::Blueprint {
             package_address: package_address.to_string(),
             blueprint_name: blueprint_name.to_owned(),
+            structure: output.0,
             functions: output.1,
             methods: output.2,
         })
@@ -1,8 +1,11 @@
 use sbor::describe::*;
+use sbor::rust::boxed::Box;
+use sbor::type_id::*;
 use sbor::*;
 use scrypto::buffer::*;
 use scrypto::crypto::*;
 use scrypto::engine::types::*;
+use scrypto::math::{PreciseDecimal, I256, U256};
 use scrypto::prelude::{AccessRuleNode, Burn, AccessRule, Mint, Withdraw};
 use scrypto::resource::{require, LOCKED};
 use scrypto::rust::borrow::ToOwned;
@@ -20,6 +23,7 @@ use scrypto::*;
 
 use crate::engine::*;
 use crate::model::*;
+use crate::transaction::json::*;
 use crate::transaction::*;
 
 /// Utility for building transaction.
@@ -58,7 +62,10 @@ impl TransactionBuilder {
             }
             Instruction::AssertWorktopContains { .. }
             | Instruction::AssertWorktopContainsByAmount { .. }
-            | Instruction::AssertWorktopContainsByIds { .. } => {}
+            | Instruction::AssertWorktopContainsByIds { .. }
+            | Instruction::AssertWorktopIsEmpty
+            | Instruction::AssertWorktopContainsAtMost { .. }
+            | Instruction::AssertWorktopContainsExactIds { .. } => {}
             Instruction::PopFromAuthZone { .. } => {
                 new_proof_id = Some(
                     self.id_validator
@@ -101,7 +108,14 @@ impl TransactionBuilder {
             Instruction::CallMethodWithAllResources { .. } => {
                 self.id_validator.move_all_resources().unwrap();
             }
-            Instruction::PublishPackage { .. } | Instruction::Nonce { .. } => {}
+            Instruction::PublishPackage { .. }
+            | Instruction::UpgradePackage { .. }
+            | Instruction::RecallFromVault { .. }
+            | Instruction::FreezeVault { .. }
+            | Instruction::UnfreezeVault { .. }
+            | Instruction::SetComponentMetadata { .. }
+            | Instruction::SetPackageMetadata { .. }
+            | Instruction::Nonce { .. } => {}
         }
 
         self.instructions.push(inst);
@@ -191,6 +205,37 @@ impl TransactionBuilder {
         .0
     }
 
+    /// Asserts that worktop contains no resource.
+    pub fn assert_worktop_is_empty(&mut self) -> &mut Self {
+        self.add_instruction(Instruction::AssertWorktopIsEmpty).0
+    }
+
+    /// Asserts that worktop contains resource by at most the given amount.
+    pub fn assert_worktop_contains_at_most(
+        &mut self,
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::AssertWorktopContainsAtMost {
+            amount,
+            resource_address,
+        })
+        .0
+    }
+
+    /// Asserts that worktop contains resource by exactly the given non-fungible IDs.
+    pub fn assert_worktop_contains_exact_ids(
+        &mut self,
+        ids: &BTreeSet<NonFungibleId>,
+        resource_address: ResourceAddress,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::AssertWorktopContainsExactIds {
+            ids: ids.clone(),
+            resource_address,
+        })
+        .0
+    }
+
     /// Pops the most recent proof from auth zone.
     pub fn pop_from_auth_zone<F>(&mut self, then: F) -> &mut Self
     where
@@ -407,8 +452,109 @@ impl TransactionBuilder {
 
     /// Publishes a package.
     pub fn publish_package(&mut self, code: &[u8]) -> &mut Self {
+        self.publish_package_with_metadata(code, BTreeMap::new())
+    }
+
+    /// Publishes a package with metadata.
+    pub fn publish_package_with_metadata(
+        &mut self,
+        code: &[u8],
+        metadata: BTreeMap<String, String>,
+    ) -> &mut Self {
         self.add_instruction(Instruction::PublishPackage {
             code: code.to_vec(),
+            metadata,
+            owner: None,
+        })
+        .0
+    }
+
+    /// Publishes a package which can be upgraded by the given owner.
+    pub fn publish_package_with_owner(&mut self, code: &[u8], owner: AccessRule) -> &mut Self {
+        self.add_instruction(Instruction::PublishPackage {
+            code: code.to_vec(),
+            metadata: BTreeMap::new(),
+            owner: Some(owner),
+        })
+        .0
+    }
+
+    /// Upgrades a package, whose existing components are migrated when next loaded.
+    pub fn upgrade_package(&mut self, package_address: PackageAddress, code: &[u8]) -> &mut Self {
+        self.add_instruction(Instruction::UpgradePackage {
+            package_address,
+            code: code.to_vec(),
+        })
+        .0
+    }
+
+    /// Recalls resource from a vault of any component, into the worktop.
+    pub fn recall_from_vault(
+        &mut self,
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+        amount: Decimal,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::RecallFromVault {
+            component_address,
+            vault_id,
+            amount,
+        })
+        .0
+    }
+
+    /// Freezes a vault of any component.
+    pub fn freeze_vault(
+        &mut self,
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::FreezeVault {
+            component_address,
+            vault_id,
+        })
+        .0
+    }
+
+    /// Unfreezes a vault of any component.
+    pub fn unfreeze_vault(
+        &mut self,
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::UnfreezeVault {
+            component_address,
+            vault_id,
+        })
+        .0
+    }
+
+    /// Sets a metadata entry of any component.
+    pub fn set_component_metadata(
+        &mut self,
+        component_address: ComponentAddress,
+        key: &str,
+        value: &str,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::SetComponentMetadata {
+            component_address,
+            key: key.to_owned(),
+            value: value.to_owned(),
+        })
+        .0
+    }
+
+    /// Sets a metadata entry of any package.
+    pub fn set_package_metadata(
+        &mut self,
+        package_address: PackageAddress,
+        key: &str,
+        value: &str,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::SetPackageMetadata {
+            package_address,
+            key: key.to_owned(),
+            value: value.to_owned(),
         })
         .0
     }
@@ -434,6 +580,7 @@ impl TransactionBuilder {
     pub fn new_token_mutable(
         &mut self,
         metadata: HashMap<String, String>,
+        locked_metadata: BTreeSet<String>,
         minter_resource_address: ResourceAddress,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -456,6 +603,7 @@ impl TransactionBuilder {
                 scrypto_encode(&metadata),
                 scrypto_encode(&resource_auth),
                 scrypto_encode::<Option<MintParams>>(&None),
+                scrypto_encode(&locked_metadata),
             ],
         })
         .0
@@ -465,6 +613,7 @@ impl TransactionBuilder {
     pub fn new_token_fixed(
         &mut self,
         metadata: HashMap<String, String>,
+        locked_metadata: BTreeSet<String>,
         initial_supply: Decimal,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -481,6 +630,7 @@ impl TransactionBuilder {
                 scrypto_encode(&Some(MintParams::Fungible {
                     amount: initial_supply.into(),
                 })),
+                scrypto_encode(&locked_metadata),
             ],
         })
         .0
@@ -490,6 +640,7 @@ impl TransactionBuilder {
     pub fn new_badge_mutable(
         &mut self,
         metadata: HashMap<String, String>,
+        locked_metadata: BTreeSet<String>,
         minter_resource_address: ResourceAddress,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -512,6 +663,7 @@ impl TransactionBuilder {
                 scrypto_encode(&metadata),
                 scrypto_encode(&resource_auth),
                 scrypto_encode::<Option<MintParams>>(&None),
+                scrypto_encode(&locked_metadata),
             ],
         })
         .0
@@ -521,6 +673,7 @@ impl TransactionBuilder {
     pub fn new_badge_fixed(
         &mut self,
         metadata: HashMap<String, String>,
+        locked_metadata: BTreeSet<String>,
         initial_supply: Decimal,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -537,6 +690,7 @@ impl TransactionBuilder {
                 scrypto_encode(&Some(MintParams::Fungible {
                     amount: initial_supply.into(),
                 })),
+                scrypto_encode(&locked_metadata),
             ],
         })
         .0
@@ -733,7 +887,7 @@ impl TransactionBuilder {
                 Type::U128 => self.parse_basic_ty::<u128>(i, t, arg),
                 Type::String => self.parse_basic_ty::<String>(i, t, arg),
                 Type::Custom { name, .. } => self.parse_custom_ty(i, t, arg, name, account),
-                _ => Err(BuildArgsError::UnsupportedType(i, t.clone())),
+                _ => self.parse_structured_ty(i, t, arg, account),
             };
             encoded.push(res?);
         }
@@ -757,6 +911,263 @@ impl TransactionBuilder {
         Ok(scrypto_encode(&value))
     }
 
+    /// Parses an argument of composite type, given in JSON.
+    fn parse_structured_ty(
+        &mut self,
+        i: usize,
+        ty: &Type,
+        arg: &str,
+        account: Option<ComponentAddress>,
+    ) -> Result<Vec<u8>, BuildArgsError> {
+        let json = parse_json(arg)
+            .map_err(|_| BuildArgsError::FailedToParse(i, ty.clone(), arg.to_owned()))?;
+        let value = self.parse_json_value(i, ty, &json, account)?;
+
+        let mut bytes = Vec::new();
+        let mut enc = Encoder::with_type(&mut bytes);
+        encode_any(None, &value, &mut enc);
+        Ok(bytes)
+    }
+
+    fn parse_json_value(
+        &mut self,
+        i: usize,
+        ty: &Type,
+        json: &JsonValue,
+        account: Option<ComponentAddress>,
+    ) -> Result<Value, BuildArgsError> {
+        let failed = || BuildArgsError::FailedToParse(i, ty.clone(), json.to_string());
+        let text = || json.as_text().ok_or_else(failed);
+
+        let value = match (ty, json) {
+            (Type::Unit, JsonValue::Null) => Value::Unit,
+            (Type::Bool, JsonValue::Bool(value)) => Value::Bool { value: *value },
+            (Type::I8, _) => Value::I8 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::I16, _) => Value::I16 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::I32, _) => Value::I32 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::I64, _) => Value::I64 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::I128, _) => Value::I128 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::U8, _) => Value::U8 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::U16, _) => Value::U16 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::U32, _) => Value::U32 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::U64, _) => Value::U64 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::U128, _) => Value::U128 {
+                value: text()?.parse().map_err(|_| failed())?,
+            },
+            (Type::String, JsonValue::String(value)) => Value::String {
+                value: value.clone(),
+            },
+            (Type::Option { .. }, JsonValue::Null) => Value::Option {
+                value: Box::new(None),
+            },
+            (Type::Option { value }, _) => Value::Option {
+                value: Box::new(Some(self.parse_json_value(i, value, json, account)?)),
+            },
+            (Type::Array { element, length }, JsonValue::Array(elements))
+                if elements.len() == *length as usize =>
+            {
+                Value::Array {
+                    element_type_id: type_id_of(i, element)?,
+                    elements: self.parse_json_elements(i, element, elements, account)?,
+                }
+            }
+            (Type::Tuple { elements: types }, JsonValue::Array(elements))
+                if elements.len() == types.len() =>
+            {
+                let mut values = Vec::new();
+                for (t, e) in types.iter().zip(elements) {
+                    values.push(self.parse_json_value(i, t, e, account)?);
+                }
+                Value::Tuple { elements: values }
+            }
+            (Type::Struct { fields, .. }, _) => Value::Struct {
+                fields: self
+                    .parse_json_fields(i, fields, json, account)?
+                    .ok_or_else(failed)?,
+            },
+            (Type::Enum { variants, .. }, JsonValue::String(name)) => {
+                let variant = variants
+                    .iter()
+                    .find(|v| &v.name == name && matches!(v.fields, Fields::Unit))
+                    .ok_or_else(failed)?;
+                Value::Enum {
+                    name: variant.name.clone(),
+                    fields: Vec::new(),
+                }
+            }
+            (Type::Enum { variants, .. }, JsonValue::Object(members)) if members.len() == 1 => {
+                let (name, fields) = &members[0];
+                let variant = variants
+                    .iter()
+                    .find(|v| &v.name == name)
+                    .ok_or_else(failed)?;
+                Value::Enum {
+                    name: variant.name.clone(),
+                    fields: self
+                        .parse_json_fields(i, &variant.fields, fields, account)?
+                        .ok_or_else(failed)?,
+                }
+            }
+            (Type::Result { okay, error }, JsonValue::Object(members)) if members.len() == 1 => {
+                let (name, value) = &members[0];
+                Value::Result {
+                    value: Box::new(match name.as_str() {
+                        "Ok" => Ok(self.parse_json_value(i, okay, value, account)?),
+                        "Err" => Err(self.parse_json_value(i, error, value, account)?),
+                        _ => return Err(failed()),
+                    }),
+                }
+            }
+            (Type::Vec { element }, JsonValue::Array(elements)) => Value::Vec {
+                element_type_id: type_id_of(i, element)?,
+                elements: self.parse_json_elements(i, element, elements, account)?,
+            },
+            (Type::TreeSet { element }, JsonValue::Array(elements)) => Value::TreeSet {
+                element_type_id: type_id_of(i, element)?,
+                elements: self.parse_json_elements(i, element, elements, account)?,
+            },
+            (Type::HashSet { element }, JsonValue::Array(elements)) => Value::HashSet {
+                element_type_id: type_id_of(i, element)?,
+                elements: self.parse_json_elements(i, element, elements, account)?,
+            },
+            (Type::TreeMap { key, value }, _) => Value::TreeMap {
+                key_type_id: type_id_of(i, key)?,
+                value_type_id: type_id_of(i, value)?,
+                elements: self
+                    .parse_json_entries(i, key, value, json, account)?
+                    .ok_or_else(failed)?,
+            },
+            (Type::HashMap { key, value }, _) => Value::HashMap {
+                key_type_id: type_id_of(i, key)?,
+                value_type_id: type_id_of(i, value)?,
+                elements: self
+                    .parse_json_entries(i, key, value, json, account)?
+                    .ok_or_else(failed)?,
+            },
+            (Type::Custom { name, .. }, _) => {
+                let bytes = self.parse_custom_ty(i, ty, text()?, name, account)?;
+                decode_any(&bytes).map_err(|_| failed())?
+            }
+            _ => return Err(failed()),
+        };
+        Ok(value)
+    }
+
+    fn parse_json_elements(
+        &mut self,
+        i: usize,
+        ty: &Type,
+        elements: &[JsonValue],
+        account: Option<ComponentAddress>,
+    ) -> Result<Vec<Value>, BuildArgsError> {
+        let mut values = Vec::new();
+        for e in elements {
+            values.push(self.parse_json_value(i, ty, e, account)?);
+        }
+        Ok(values)
+    }
+
+    /// Parses struct or enum variant fields, given as an object for named fields, or an array
+    /// for unnamed fields. A single unnamed field may also be given as is.
+    fn parse_json_fields(
+        &mut self,
+        i: usize,
+        fields: &Fields,
+        json: &JsonValue,
+        account: Option<ComponentAddress>,
+    ) -> Result<Option<Vec<Value>>, BuildArgsError> {
+        let mut values = Vec::new();
+        match (fields, json) {
+            (Fields::Named { named }, JsonValue::Object(members))
+                if members.len() == named.len() =>
+            {
+                for (name, t) in named {
+                    match members.iter().find(|(k, _)| k == name) {
+                        Some((_, v)) => values.push(self.parse_json_value(i, t, v, account)?),
+                        None => return Ok(None),
+                    }
+                }
+            }
+            (Fields::Named { named }, JsonValue::Array(elements))
+                if elements.len() == named.len() =>
+            {
+                for ((_, t), e) in named.iter().zip(elements) {
+                    values.push(self.parse_json_value(i, t, e, account)?);
+                }
+            }
+            (Fields::Unnamed { unnamed }, JsonValue::Array(elements))
+                if elements.len() == unnamed.len() =>
+            {
+                for (t, e) in unnamed.iter().zip(elements) {
+                    values.push(self.parse_json_value(i, t, e, account)?);
+                }
+            }
+            (Fields::Unnamed { unnamed }, _) if unnamed.len() == 1 => {
+                values.push(self.parse_json_value(i, &unnamed[0], json, account)?);
+            }
+            (Fields::Unit, JsonValue::Null) => {}
+            (Fields::Unit, JsonValue::Array(elements)) if elements.is_empty() => {}
+            _ => return Ok(None),
+        }
+        Ok(Some(values))
+    }
+
+    /// Parses map entries, given as an object or an array of key-value pairs.
+    fn parse_json_entries(
+        &mut self,
+        i: usize,
+        key_type: &Type,
+        value_type: &Type,
+        json: &JsonValue,
+        account: Option<ComponentAddress>,
+    ) -> Result<Option<Vec<Value>>, BuildArgsError> {
+        let mut elements = Vec::new();
+        match json {
+            JsonValue::Object(members) => {
+                for (k, v) in members {
+                    elements.push(self.parse_json_value(
+                        i,
+                        key_type,
+                        &JsonValue::string(k),
+                        account,
+                    )?);
+                    elements.push(self.parse_json_value(i, value_type, v, account)?);
+                }
+            }
+            JsonValue::Array(pairs) => {
+                for pair in pairs {
+                    match pair {
+                        JsonValue::Array(kv) if kv.len() == 2 => {
+                            elements.push(self.parse_json_value(i, key_type, &kv[0], account)?);
+                            elements.push(self.parse_json_value(i, value_type, &kv[1], account)?);
+                        }
+                        _ => return Ok(None),
+                    }
+                }
+            }
+            _ => return Ok(None),
+        }
+        Ok(Some(elements))
+    }
+
     fn parse_custom_ty(
         &mut self,
         i: usize,
@@ -772,6 +1183,24 @@ impl TransactionBuilder {
                     .map_err(|_| BuildArgsError::FailedToParse(i, ty.clone(), arg.to_owned()))?;
                 Ok(scrypto_encode(&value))
             }
+            ScryptoType::PreciseDecimal => {
+                let value = arg
+                    .parse::<PreciseDecimal>()
+                    .map_err(|_| BuildArgsError::FailedToParse(i, ty.clone(), arg.to_owned()))?;
+                Ok(scrypto_encode(&value))
+            }
+            ScryptoType::I256 => {
+                let value = arg
+                    .parse::<I256>()
+                    .map_err(|_| BuildArgsError::FailedToParse(i, ty.clone(), arg.to_owned()))?;
+                Ok(scrypto_encode(&value))
+            }
+            ScryptoType::U256 => {
+                let value = arg
+                    .parse::<U256>()
+                    .map_err(|_| BuildArgsError::FailedToParse(i, ty.clone(), arg.to_owned()))?;
+                Ok(scrypto_encode(&value))
+            }
             ScryptoType::PackageAddress => {
                 let value = arg
                     .parse::<PackageAddress>()
@@ -867,6 +1296,40 @@ impl TransactionBuilder {
     }
 }
 
+/// Returns the SBOR type id of values of a type.
+fn type_id_of(i: usize, ty: &Type) -> Result<u8, BuildArgsError> {
+    let type_id = match ty {
+        Type::Unit => TYPE_UNIT,
+        Type::Bool => TYPE_BOOL,
+        Type::I8 => TYPE_I8,
+        Type::I16 => TYPE_I16,
+        Type::I32 => TYPE_I32,
+        Type::I64 => TYPE_I64,
+        Type::I128 => TYPE_I128,
+        Type::U8 => TYPE_U8,
+        Type::U16 => TYPE_U16,
+        Type::U32 => TYPE_U32,
+        Type::U64 => TYPE_U64,
+        Type::U128 => TYPE_U128,
+        Type::String => TYPE_STRING,
+        Type::Option { .. } => TYPE_OPTION,
+        Type::Array { .. } => TYPE_ARRAY,
+        Type::Tuple { .. } => TYPE_TUPLE,
+        Type::Struct { .. } => TYPE_STRUCT,
+        Type::Enum { .. } => TYPE_ENUM,
+        Type::Result { .. } => TYPE_RESULT,
+        Type::Vec { .. } => TYPE_VEC,
+        Type::TreeSet { .. } => TYPE_TREE_SET,
+        Type::TreeMap { .. } => TYPE_TREE_MAP,
+        Type::HashSet { .. } => TYPE_HASH_SET,
+        Type::HashMap { .. } => TYPE_HASH_MAP,
+        Type::Custom { name, .. } => ScryptoType::from_name(name)
+            .ok_or(BuildArgsError::UnsupportedType(i, ty.clone()))?
+            .id(),
+    };
+    Ok(type_id)
+}
+
 enum ResourceSpecifier {
     Amount(Decimal, ResourceAddress),
     Ids(BTreeSet<NonFungibleId>, ResourceAddress),
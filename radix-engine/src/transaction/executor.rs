@@ -16,6 +16,8 @@ use crate::transaction::*;
 pub struct TransactionExecutor<'l, L: SubstateStore> {
     substate_store: &'l mut L,
     trace: bool,
+    /// The time to execute transactions at, instead of the ledger time
+    current_time: Option<u64>,
 }
 
 impl<'l, L: SubstateStore> NonceProvider for TransactionExecutor<'l, L> {
@@ -66,9 +68,28 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
         Self {
             substate_store,
             trace,
+            current_time: None,
         }
     }
 
+    /// Executes all subsequent transactions at the given time, in milliseconds since the Unix
+    /// epoch, regardless of the ledger time.
+    ///
+    /// Like the ledger time, it can never move backwards.
+    pub fn set_current_time(&mut self, current_time: u64) -> Result<(), TimeError> {
+        let previous_time = self
+            .current_time
+            .unwrap_or_else(|| self.substate_store.get_time());
+        if current_time < previous_time {
+            return Err(TimeError::TimeMovedBackwards {
+                current_time: previous_time,
+                new_time: current_time,
+            });
+        }
+        self.current_time = Some(current_time);
+        Ok(())
+    }
+
     /// Returns an immutable reference to the ledger.
     pub fn substate_store(&self) -> &L {
         self.substate_store
@@ -110,9 +131,7 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
     /// Creates a new key and an account which can be accessed using the key.
     pub fn new_account(&mut self) -> (EcdsaPublicKey, EcdsaPrivateKey, ComponentAddress) {
         let (public_key, private_key) = self.new_key_pair();
-        let id = NonFungibleId::from_bytes(public_key.to_vec());
-        let auth_address = NonFungibleAddress::new(ECDSA_TOKEN, id);
-        let withdraw_auth = rule!(require(auth_address));
+        let withdraw_auth = rule!(require_signature(public_key));
         let account = self.new_account_with_auth_rule(&withdraw_auth);
         (public_key, private_key, account)
     }
@@ -138,21 +157,6 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
         }
     }
 
-    /// Overwrites a package.
-    pub fn overwrite_package(
-        &mut self,
-        package_address: PackageAddress,
-        code: Vec<u8>,
-    ) -> Result<(), WasmValidationError> {
-        let tx_hash = hash(self.substate_store.get_and_increase_nonce().to_le_bytes());
-        let mut id_gen = SubstateIdGenerator::new(tx_hash);
-
-        let package = Package::new(code)?;
-        self.substate_store
-            .put_encoded_substate(&package_address, &package, id_gen.next());
-        Ok(())
-    }
-
     pub fn validate_and_execute(
         &mut self,
         signed: &SignedTransaction,
@@ -166,10 +170,14 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
         #[cfg(not(feature = "alloc"))]
         let now = std::time::Instant::now();
 
+        let current_time = self
+            .current_time
+            .unwrap_or_else(|| self.substate_store.get_time());
         let mut track = Track::new(
             self.substate_store,
             validated.raw_hash.clone(),
             validated.signers.clone(),
+            current_time,
         );
         let mut proc = track.start_process(self.trace);
 
@@ -189,7 +197,9 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
         let new_package_addresses = track.new_package_addresses();
         let new_component_addresses = track.new_component_addresses();
         let new_resource_addresses = track.new_resource_addresses();
+        let upgraded_packages = track.package_upgrades().clone();
         let logs = track.logs().clone();
+        let cost_units_consumed = track.cost_units_consumed();
 
         // commit state updates
         let commit_receipt = if error.is_none() {
@@ -219,7 +229,9 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
             new_package_addresses,
             new_component_addresses,
             new_resource_addresses,
+            upgraded_packages,
             execution_time,
+            cost_units_consumed,
         }
     }
 }
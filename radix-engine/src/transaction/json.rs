@@ -0,0 +1,332 @@
+use scrypto::rust::borrow::ToOwned;
+use scrypto::rust::fmt;
+use scrypto::rust::string::String;
+use scrypto::rust::vec::Vec;
+
+/// A JSON value, as accepted for transaction arguments.
+///
+/// Numbers are kept as written, so that they can be parsed into any integer or decimal type
+/// without loss of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// Members in the order they appear
+    Object(Vec<(String, JsonValue)>),
+}
+
+/// Represents an error when parsing JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonError {
+    UnexpectedEnd,
+    UnexpectedChar(usize, char),
+    InvalidEscape(usize),
+    TrailingChars(usize),
+    MaxDepthExceeded(usize),
+}
+
+/// The maximum nesting depth of arrays and objects.
+pub const MAX_JSON_DEPTH: usize = 64;
+
+/// Parses a JSON document.
+pub fn parse_json(input: &str) -> Result<JsonValue, JsonError> {
+    let mut parser = JsonParser {
+        chars: input.chars().collect(),
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos < parser.chars.len() {
+        return Err(JsonError::TrailingChars(parser.pos));
+    }
+    Ok(value)
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    pos: usize,
+    depth: usize,
+}
+
+impl JsonParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Result<char, JsonError> {
+        let c = self.peek().ok_or(JsonError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        let c = self.next()?;
+        if c == expected {
+            Ok(())
+        } else {
+            Err(JsonError::UnexpectedChar(self.pos - 1, c))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, JsonError> {
+        self.skip_whitespace();
+        match self.peek().ok_or(JsonError::UnexpectedEnd)? {
+            'n' => self.parse_keyword("null", JsonValue::Null),
+            't' => self.parse_keyword("true", JsonValue::Bool(true)),
+            'f' => self.parse_keyword("false", JsonValue::Bool(false)),
+            '"' => self.parse_string().map(JsonValue::String),
+            '[' => self.parse_array(),
+            '{' => self.parse_object(),
+            c if c == '-' || c.is_ascii_digit() => Ok(self.parse_number()),
+            c => Err(JsonError::UnexpectedChar(self.pos, c)),
+        }
+    }
+
+    fn parse_keyword(&mut self, keyword: &str, value: JsonValue) -> Result<JsonValue, JsonError> {
+        for expected in keyword.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> JsonValue {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' || c.is_ascii_digit())
+        {
+            self.pos += 1;
+        }
+        JsonValue::Number(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(s),
+                '\\' => {
+                    let escape = self.next()?;
+                    s.push(match escape {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{0008}',
+                        'f' => '\u{000c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => self.parse_unicode_escape()?,
+                        _ => return Err(JsonError::InvalidEscape(self.pos - 1)),
+                    });
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    /// Parses the code units of a `\u` escape, combining a UTF-16 surrogate pair.
+    fn parse_unicode_escape(&mut self) -> Result<char, JsonError> {
+        let start = self.pos - 2;
+        let mut code = self.parse_hex4()?;
+        if (0xD800..0xDC00).contains(&code) {
+            if self.next()? != '\\' || self.next()? != 'u' {
+                return Err(JsonError::InvalidEscape(start));
+            }
+            let low = self.parse_hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(JsonError::InvalidEscape(start));
+            }
+            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+        }
+        char::from_u32(code).ok_or(JsonError::InvalidEscape(start))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, JsonError> {
+        let mut code = 0u32;
+        for _ in 0..4 {
+            let digit = self
+                .next()?
+                .to_digit(16)
+                .ok_or(JsonError::InvalidEscape(self.pos - 1))?;
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    fn enter(&mut self) -> Result<(), JsonError> {
+        if self.depth == MAX_JSON_DEPTH {
+            return Err(JsonError::MaxDepthExceeded(self.pos));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, JsonError> {
+        self.enter()?;
+        let result = self.parse_array_elements();
+        self.depth -= 1;
+        result
+    }
+
+    fn parse_array_elements(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect('[')?;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(elements));
+        }
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.next()? {
+                ',' => continue,
+                ']' => return Ok(JsonValue::Array(elements)),
+                c => return Err(JsonError::UnexpectedChar(self.pos - 1, c)),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, JsonError> {
+        self.enter()?;
+        let result = self.parse_object_members();
+        self.depth -= 1;
+        result
+    }
+
+    fn parse_object_members(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect('{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            members.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.next()? {
+                ',' => continue,
+                '}' => return Ok(JsonValue::Object(members)),
+                c => return Err(JsonError::UnexpectedChar(self.pos - 1, c)),
+            }
+        }
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Bool(b) => write!(f, "{}", b),
+            JsonValue::Number(n) => write!(f, "{}", n),
+            JsonValue::String(s) => write!(f, "{:?}", s),
+            JsonValue::Array(elements) => {
+                write!(f, "[")?;
+                for (i, e) in elements.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", e)?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(members) => {
+                write!(f, "{{")?;
+                for (i, (k, v)) in members.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}: {}", k, v)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl JsonValue {
+    /// Returns the text of a string or number.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) | JsonValue::Number(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn string(s: &str) -> Self {
+        JsonValue::String(s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scrypto::rust::vec;
+
+    #[test]
+    fn test_parse_json() {
+        let json = parse_json(r#" {"a": [1, -2.5e3, true, null], "b\n": "x\"A"} "#).unwrap();
+        assert_eq!(
+            json,
+            JsonValue::Object(vec![
+                (
+                    "a".to_owned(),
+                    JsonValue::Array(vec![
+                        JsonValue::Number("1".to_owned()),
+                        JsonValue::Number("-2.5e3".to_owned()),
+                        JsonValue::Bool(true),
+                        JsonValue::Null,
+                    ])
+                ),
+                ("b\n".to_owned(), JsonValue::string("x\"A")),
+            ])
+        );
+        assert_eq!(parse_json("[]"), Ok(JsonValue::Array(vec![])));
+        assert_eq!(parse_json("{}"), Ok(JsonValue::Object(vec![])));
+        assert_eq!(
+            parse_json(r#""\u00e9\ud83d\ude00""#),
+            Ok(JsonValue::string("\u{e9}\u{1f600}"))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_json() {
+        assert_eq!(parse_json("[1, 2"), Err(JsonError::UnexpectedEnd));
+        assert_eq!(parse_json("[1 2]"), Err(JsonError::UnexpectedChar(3, '2')));
+        assert_eq!(parse_json("nul"), Err(JsonError::UnexpectedEnd));
+        assert_eq!(parse_json("1 2"), Err(JsonError::TrailingChars(2)));
+        assert_eq!(parse_json(r#""\x""#), Err(JsonError::InvalidEscape(2)));
+        assert_eq!(parse_json(r#""\ud83d""#), Err(JsonError::InvalidEscape(1)));
+        assert_eq!(parse_json(r#""\ud83dx""#), Err(JsonError::InvalidEscape(1)));
+        assert_eq!(parse_json(r#""\ude00""#), Err(JsonError::InvalidEscape(1)));
+    }
+
+    #[test]
+    fn test_parse_deeply_nested_json() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(parse_json(&nested(MAX_JSON_DEPTH)).is_ok());
+        assert_eq!(
+            parse_json(&nested(MAX_JSON_DEPTH + 1)),
+            Err(JsonError::MaxDepthExceeded(MAX_JSON_DEPTH))
+        );
+        assert_eq!(
+            parse_json(&"[{\"a\":".repeat(100_000)),
+            Err(JsonError::MaxDepthExceeded(MAX_JSON_DEPTH / 2 * 6))
+        );
+    }
+}
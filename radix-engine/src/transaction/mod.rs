@@ -2,6 +2,7 @@ mod abi_provider;
 mod builder;
 mod error;
 mod executor;
+mod json;
 mod nonce_provider;
 
 pub use abi_provider::{AbiProvider, BasicAbiProvider};
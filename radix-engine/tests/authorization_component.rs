@@ -129,3 +129,52 @@ fn can_make_cross_component_call_with_authorization() {
     // Assert
     receipt.result.expect("Should be okay");
 }
+
+#[test]
+fn time_conditions_restrict_method_calls_to_a_time_window() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let authorization = AccessRules::new().method(
+        "get_component_state",
+        rule!(require_time_at_least(1_000_000) && require_time_before(2_000_000)),
+    );
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component_with_auth",
+            vec![scrypto_encode(&authorization)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+    let secured_component = receipt.new_component_addresses[0];
+
+    for (time, is_authorized) in [
+        (999_999, false),
+        (1_000_000, true),
+        (1_999_999, true),
+        (2_000_000, false),
+    ] {
+        // Act
+        test_runner.set_current_time(time);
+        let transaction = test_runner
+            .new_transaction_builder()
+            .call_method(secured_component, "get_component_state", vec![])
+            .build(test_runner.get_nonce([]))
+            .sign([]);
+        let receipt = test_runner.validate_and_execute(&transaction);
+
+        // Assert
+        if is_authorized {
+            receipt.result.expect("Should be okay");
+        } else {
+            let error = receipt.result.expect_err("Should be error");
+            assert_auth_error!(error);
+        }
+    }
+}
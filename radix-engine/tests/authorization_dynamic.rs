@@ -140,6 +140,47 @@ fn test_dynamic_authlist(
     }
 }
 
+fn test_dynamic_lockup(access_rule: AccessRule, epoch: u64, time: u64, should_succeed: bool) {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package = test_runner.publish_package("component");
+    let access_rules = AccessRules::new()
+        .method("get_secret", access_rule)
+        .default(rule!(allow_all));
+    let transaction1 = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "LockupComponent",
+            "create_component",
+            args![10u64, 1_000_000u64, access_rules],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt1 = test_runner.validate_and_execute(&transaction1);
+    receipt1.result.expect("Should be okay.");
+    let component = receipt1.new_component_addresses[0];
+
+    // Act
+    test_runner.set_current_epoch(epoch);
+    test_runner.set_current_time(time);
+    let transaction2 = test_runner
+        .new_transaction_builder()
+        .call_method(component, "get_secret", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt2 = test_runner.validate_and_execute(&transaction2);
+
+    // Assert
+    if should_succeed {
+        receipt2.result.expect("Should be okay.");
+    } else {
+        let error = receipt2.result.expect_err("Should be an error.");
+        assert_auth_error!(error);
+    }
+}
+
 #[test]
 fn dynamic_auth_should_allow_me_to_call_method_when_signed() {
     test_dynamic_auth(1, 0, None, &[0], true);
@@ -311,3 +352,53 @@ fn chess_should_allow_second_player_to_move_after_first_player() {
     // Assert
     receipt.result.expect("Should be okay.");
 }
+
+#[test]
+fn dynamic_epoch_at_least_should_allow_me_to_call_method_after_unlock() {
+    test_dynamic_lockup(rule!(require_epoch_at_least("unlock_epoch")), 10, 0, true);
+}
+
+#[test]
+fn dynamic_epoch_at_least_should_fail_before_unlock() {
+    test_dynamic_lockup(rule!(require_epoch_at_least("unlock_epoch")), 9, 0, false);
+}
+
+#[test]
+fn dynamic_epoch_before_should_fail_after_unlock() {
+    test_dynamic_lockup(rule!(require_epoch_before("unlock_epoch")), 10, 0, false);
+}
+
+#[test]
+fn dynamic_time_before_should_fail_after_expiry() {
+    test_dynamic_lockup(
+        rule!(require_time_before("expiry_time")),
+        0,
+        1_000_000,
+        false,
+    );
+}
+
+#[test]
+fn dynamic_epoch_and_time_should_allow_me_to_call_method_within_window() {
+    test_dynamic_lockup(
+        rule!(require_epoch_at_least("unlock_epoch") && require_time_before("expiry_time")),
+        12,
+        999_999,
+        true,
+    );
+}
+
+#[test]
+fn dynamic_epoch_or_time_should_allow_me_to_call_method_after_either() {
+    test_dynamic_lockup(
+        rule!(require_epoch_at_least("unlock_epoch") || require_time_at_least("expiry_time")),
+        0,
+        1_000_000,
+        true,
+    );
+}
+
+#[test]
+fn dynamic_epoch_should_fail_if_path_does_not_exist() {
+    test_dynamic_lockup(rule!(require_epoch_at_least("missing")), 100, 0, false);
+}
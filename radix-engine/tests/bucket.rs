@@ -4,10 +4,25 @@ pub mod test_runner;
 use crate::test_runner::TestRunner;
 use radix_engine::errors::*;
 use radix_engine::ledger::*;
-use radix_engine::model::{BucketError, ResourceContainerError};
+use radix_engine::model::{BucketError, Instruction, ResourceContainerError};
 use radix_engine::transaction::*;
+use sbor::Type;
+use scrypto::abi;
 use scrypto::prelude::*;
 
+#[derive(TypeId, Encode, Decode, Describe, Debug, PartialEq)]
+struct Order {
+    price: Decimal,
+    tags: Vec<String>,
+    limit: Option<u32>,
+}
+
+#[derive(TypeId, Encode, Decode, Describe, Debug, PartialEq)]
+enum Side {
+    Buy,
+    Sell { amount: u64 },
+}
+
 #[test]
 fn test_bucket() {
     let mut ledger = InMemorySubstateStore::with_bootstrap();
@@ -161,3 +176,145 @@ fn create_empty_bucket() {
     // Assert
     assert!(receipt.result.is_ok());
 }
+
+#[test]
+fn test_call_with_structured_args() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 2, account);
+    let package_address = test_runner.publish_package("bucket");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function_with_abi(
+            package_address,
+            "BucketTest",
+            "combine_buckets",
+            vec![
+                format!(r#"["10,{0}", "20,{0}"]"#, resource_address),
+                "\"5\"".to_owned(),
+            ],
+            Some(account),
+            &test_runner.export_abi(package_address, "BucketTest"),
+        )
+        .unwrap()
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
+
+#[test]
+fn test_call_with_invalid_structured_args() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 2, account);
+    let package_address = test_runner.publish_package("bucket");
+
+    // Act
+    let mut builder = test_runner.new_transaction_builder();
+    let result = builder.call_function_with_abi(
+        package_address,
+        "BucketTest",
+        "combine_buckets",
+        vec![format!(r#"{{"10,{}"}}"#, resource_address), "null".to_owned()],
+        Some(account),
+        &test_runner.export_abi(package_address, "BucketTest"),
+    );
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(CallWithAbiError::FailedToBuildArgs(
+            BuildArgsError::FailedToParse(0, _, _)
+        ))
+    ));
+}
+
+#[test]
+fn test_structured_args_are_decoded() {
+    // Arrange
+    let blueprint_abi = abi::Blueprint {
+        package_address: SYSTEM_PACKAGE.to_string(),
+        blueprint_name: "Test".to_owned(),
+        structure: Type::Unit,
+        functions: vec![abi::Function {
+            name: "f".to_owned(),
+            inputs: vec![
+                Order::describe(),
+                Side::describe(),
+                Side::describe(),
+                BTreeMap::<String, u8>::describe(),
+                <(u8, bool)>::describe(),
+                <Option<String>>::describe(),
+            ],
+            input_names: vec![
+                "order".to_owned(),
+                "side".to_owned(),
+                "other_side".to_owned(),
+                "limits".to_owned(),
+                "pair".to_owned(),
+                "note".to_owned(),
+            ],
+            output: Type::Unit,
+        }],
+        methods: vec![],
+    };
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function_with_abi(
+            SYSTEM_PACKAGE,
+            "Test",
+            "f",
+            vec![
+                r#"{"price": "12.5", "tags": ["a", "b\"c"], "limit": 3}"#.to_owned(),
+                r#""Buy""#.to_owned(),
+                r#"{"Sell": {"amount": 18446744073709551615}}"#.to_owned(),
+                r#"{"x": 1, "y": 255}"#.to_owned(),
+                "[7, true]".to_owned(),
+                "null".to_owned(),
+            ],
+            None,
+            &blueprint_abi,
+        )
+        .unwrap()
+        .build_with_no_nonce();
+
+    // Assert
+    let args = match &transaction.instructions[0] {
+        Instruction::CallFunction { args, .. } => args,
+        i => panic!("Unexpected instruction: {:?}", i),
+    };
+    assert_eq!(
+        scrypto_decode::<Order>(&args[0]).unwrap(),
+        Order {
+            price: dec!("12.5"),
+            tags: vec!["a".to_owned(), "b\"c".to_owned()],
+            limit: Some(3),
+        }
+    );
+    assert_eq!(scrypto_decode::<Side>(&args[1]).unwrap(), Side::Buy);
+    assert_eq!(
+        scrypto_decode::<Side>(&args[2]).unwrap(),
+        Side::Sell { amount: u64::MAX }
+    );
+    let mut map = BTreeMap::new();
+    map.insert("x".to_owned(), 1u8);
+    map.insert("y".to_owned(), 255u8);
+    assert_eq!(
+        scrypto_decode::<BTreeMap<String, u8>>(&args[3]).unwrap(),
+        map
+    );
+    assert_eq!(scrypto_decode::<(u8, bool)>(&args[4]).unwrap(), (7, true));
+    assert_eq!(scrypto_decode::<Option<String>>(&args[5]).unwrap(), None);
+}
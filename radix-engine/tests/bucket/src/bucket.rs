@@ -1,5 +1,10 @@
 use scrypto::prelude::*;
 
+#[derive(NonFungibleData)]
+pub struct Ticket {
+    pub number: u32,
+}
+
 blueprint! {
     struct BucketTest {
         vault: Vault,
@@ -91,12 +96,22 @@ blueprint! {
             (bucket, x)
         }
 
+        pub fn combine_buckets(mut buckets: Vec<Bucket>, amount: Option<Decimal>) -> (Bucket, Bucket) {
+            let mut bucket = buckets.remove(0);
+            for other in buckets {
+                bucket.put(other);
+            }
+            let x = bucket.take(amount.unwrap_or(Decimal::zero()));
+            (bucket, x)
+        }
+
         pub fn create_empty_bucket_fungible() -> Bucket {
             Bucket::new(RADIX_TOKEN)
         }
         
         pub fn create_empty_bucket_non_fungible() -> Bucket {
-            let resource_address = ResourceBuilder::new_non_fungible().no_initial_supply();
+            let resource_address = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
+                .no_initial_supply::<Ticket>();
             Bucket::new(resource_address)
         }
     }
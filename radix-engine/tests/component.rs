@@ -4,6 +4,7 @@ pub mod test_runner;
 use crate::test_runner::TestRunner;
 use radix_engine::errors::RuntimeError;
 use radix_engine::ledger::InMemorySubstateStore;
+use radix_engine::model::ComponentError;
 use scrypto::prelude::*;
 
 #[test]
@@ -136,3 +137,274 @@ fn missing_component_address_should_cause_error() {
     let error = receipt.result.expect_err("Should be an error.");
     assert_eq!(error, RuntimeError::ComponentNotFound(component_address));
 }
+
+#[test]
+fn can_set_component_metadata_before_globalize() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            args![rule!(deny_all)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let component_address = receipt.new_component_addresses[0];
+    let component = test_runner.component(component_address);
+    assert_eq!(
+        component.metadata().get("name"),
+        Some(&"Metadata Component".to_string())
+    );
+    assert_eq!(
+        component.metadata().get("url"),
+        Some(&"https://radixdlt.com".to_string())
+    );
+}
+
+#[test]
+fn can_read_component_metadata_from_scrypto() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            args![rule!(deny_all)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "read_metadata",
+            args![component_address],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let metadata: BTreeMap<String, String> = scrypto_decode(&receipt.outputs[0].raw).unwrap();
+    assert_eq!(
+        metadata,
+        BTreeMap::from([
+            ("name".to_string(), "Metadata Component".to_string()),
+            ("url".to_string(), "https://radixdlt.com".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn owner_can_update_component_metadata() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, owner) = test_runner.new_key_pair_with_pk_address();
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            args![rule!(require(owner))],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .set_component_metadata(component_address, "name", "Renamed Component")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let component = test_runner.component(component_address);
+    assert_eq!(
+        component.metadata().get("name"),
+        Some(&"Renamed Component".to_string())
+    );
+}
+
+#[test]
+fn update_component_metadata_without_owner_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, owner) = test_runner.new_key_pair_with_pk_address();
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            args![rule!(require(owner))],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .set_component_metadata(component_address, "name", "Renamed Component")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn can_update_component_metadata_from_scrypto() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            args![rule!(allow_all)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "set_metadata",
+            args![
+                component_address,
+                "description".to_string(),
+                "Updated from Scrypto".to_string()
+            ],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let component = test_runner.component(component_address);
+    assert_eq!(
+        component.metadata().get("description"),
+        Some(&"Updated from Scrypto".to_string())
+    );
+}
+
+#[test]
+fn component_can_read_and_update_its_own_metadata() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new",
+            args![rule!(deny_all)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(
+            component_address,
+            "rename",
+            args!["Renamed Component".to_string()],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let metadata: BTreeMap<String, String> = scrypto_decode(&receipt.outputs[0].raw).unwrap();
+    assert_eq!(
+        metadata,
+        BTreeMap::from([
+            ("name".to_string(), "Renamed Component".to_string()),
+            ("url".to_string(), "https://radixdlt.com".to_string()),
+        ])
+    );
+    let component = test_runner.component(component_address);
+    assert_eq!(
+        component.metadata().get("name"),
+        Some(&"Renamed Component".to_string())
+    );
+}
+
+#[test]
+fn invalid_component_metadata_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new_with_invalid_url",
+            args![],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(
+        error,
+        RuntimeError::ComponentError(ComponentError::InvalidMetadata(MetadataError::InvalidUrl(
+            "radixdlt.com".to_string()
+        )))
+    );
+}
@@ -3,6 +3,8 @@ pub mod auth_list_component;
 pub mod chess;
 pub mod component;
 pub mod cross_component;
+pub mod lockup_component;
+pub mod metadata_component;
 pub mod package;
 pub mod reentrant_component;
 pub mod typed_cross_component_call;
@@ -0,0 +1,28 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct LockupComponent {
+        unlock_epoch: u64,
+        expiry_time: u64,
+    }
+
+    impl LockupComponent {
+        pub fn create_component(
+            unlock_epoch: u64,
+            expiry_time: u64,
+            access_rules: AccessRules,
+        ) -> ComponentAddress {
+            Self {
+                unlock_epoch,
+                expiry_time,
+            }
+            .instantiate()
+            .add_access_check(access_rules)
+            .globalize()
+        }
+
+        pub fn get_secret(&self) -> String {
+            "Secret".to_owned()
+        }
+    }
+}
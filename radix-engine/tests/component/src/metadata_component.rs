@@ -0,0 +1,37 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct MetadataComponent {}
+
+    impl MetadataComponent {
+        pub fn new(metadata_rule: AccessRule) -> ComponentAddress {
+            Self {}
+                .instantiate()
+                .metadata("name", "Metadata Component")
+                .metadata("url", "https://radixdlt.com")
+                .updateable_metadata(metadata_rule)
+                .globalize()
+        }
+
+        pub fn new_with_invalid_url() -> ComponentAddress {
+            Self {}
+                .instantiate()
+                .metadata("url", "radixdlt.com")
+                .globalize()
+        }
+
+        pub fn read_metadata(component_address: ComponentAddress) -> BTreeMap<String, String> {
+            borrow_component!(component_address).metadata()
+        }
+
+        pub fn set_metadata(component_address: ComponentAddress, key: String, value: String) {
+            borrow_component!(component_address).set_metadata(key, value)
+        }
+
+        pub fn rename(&self, name: String) -> BTreeMap<String, String> {
+            let component = borrow_component!(Runtime::actor().component_address().unwrap());
+            component.set_metadata("name", name);
+            component.metadata()
+        }
+    }
+}
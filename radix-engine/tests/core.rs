@@ -1,6 +1,9 @@
+use radix_engine::engine::{COMPUTE_HASH_COST_UNITS, VERIFY_SIGNATURE_COST_UNITS};
+use radix_engine::errors::TimeError;
 use radix_engine::ledger::*;
 use radix_engine::transaction::*;
 use scrypto::prelude::*;
+use scrypto::values::ScryptoValue;
 
 #[test]
 fn test_process_and_transaction() {
@@ -36,3 +39,122 @@ fn test_call() {
     let receipt = executor.validate_and_execute(&transaction).unwrap();
     receipt.result.expect("Should be okay.");
 }
+
+#[test]
+fn test_current_epoch_and_time() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    ledger.set_epoch(15);
+    ledger.set_time(1_650_000_000_000).unwrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction1 = TransactionBuilder::new()
+        .call_function(package, "CoreTest", "query_time", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt1 = executor.validate_and_execute(&transaction1).unwrap();
+    assert_eq!(
+        receipt1.outputs[0],
+        ScryptoValue::from_value(&(15u64, 1_650_000_000_000u64))
+    );
+
+    executor.set_current_time(1_700_000_000_000).unwrap();
+    let transaction2 = TransactionBuilder::new()
+        .call_function(package, "CoreTest", "query_time", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt2 = executor.validate_and_execute(&transaction2).unwrap();
+    assert_eq!(
+        receipt2.outputs[0],
+        ScryptoValue::from_value(&(15u64, 1_700_000_000_000u64))
+    );
+}
+
+#[test]
+fn test_time_cannot_move_backwards() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    ledger.set_time(1_650_000_000_000).unwrap();
+    assert_eq!(
+        ledger.set_time(1_600_000_000_000),
+        Err(TimeError::TimeMovedBackwards {
+            current_time: 1_650_000_000_000,
+            new_time: 1_600_000_000_000
+        })
+    );
+
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    assert_eq!(
+        executor.set_current_time(1_600_000_000_000),
+        Err(TimeError::TimeMovedBackwards {
+            current_time: 1_650_000_000_000,
+            new_time: 1_600_000_000_000
+        })
+    );
+    executor.set_current_time(1_700_000_000_000).unwrap();
+    assert_eq!(
+        executor.set_current_time(1_650_000_000_000),
+        Err(TimeError::TimeMovedBackwards {
+            current_time: 1_700_000_000_000,
+            new_time: 1_650_000_000_000
+        })
+    );
+}
+
+#[test]
+fn test_native_crypto() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+    let private_key = EcdsaPrivateKey::from_bytes(&[7u8; 32]).unwrap();
+    let public_key = private_key.public_key().to_vec();
+    let signature = private_key.sign(b"Hello Radix").to_vec();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "CryptoTest", "hash", args![Vec::<u8>::new()])
+        .call_function(
+            package,
+            "CryptoTest",
+            "verify",
+            args![
+                SignatureScheme::EcdsaSecp256r1,
+                public_key.clone(),
+                b"Hello Radix".to_vec(),
+                signature.clone()
+            ],
+        )
+        .call_function(
+            package,
+            "CryptoTest",
+            "verify",
+            args![
+                SignatureScheme::EcdsaSecp256r1,
+                public_key,
+                b"Hello Radiy".to_vec(),
+                signature
+            ],
+        )
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    receipt.result.expect("Should be okay.");
+    assert_eq!(
+        receipt.outputs[0],
+        ScryptoValue::from_value(&(
+            Hash::from_str("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+                .unwrap(),
+            Hash::from_str("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+                .unwrap()
+        ))
+    );
+    assert_eq!(receipt.outputs[1], ScryptoValue::from_value(&true));
+    assert_eq!(receipt.outputs[2], ScryptoValue::from_value(&false));
+    assert_eq!(
+        receipt.cost_units_consumed,
+        2 * COMPUTE_HASH_COST_UNITS + 2 * VERIFY_SIGNATURE_COST_UNITS
+    );
+}
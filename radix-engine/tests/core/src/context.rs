@@ -12,5 +12,9 @@ blueprint! {
                 Runtime::generate_uuid(),
             )
         }
+
+        pub fn query_time() -> (u64, u64) {
+            (Runtime::current_epoch(), Runtime::current_time())
+        }
     }
 }
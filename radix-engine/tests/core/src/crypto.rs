@@ -0,0 +1,28 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct CryptoTest;
+
+    impl CryptoTest {
+        pub fn hash(data: Vec<u8>) -> (Hash, Hash) {
+            (keccak256(&data), blake2b(&data))
+        }
+
+        pub fn verify(
+            scheme: SignatureScheme,
+            public_key: Vec<u8>,
+            message: Vec<u8>,
+            signature: Vec<u8>,
+        ) -> bool {
+            match scheme {
+                SignatureScheme::EcdsaSecp256k1 => {
+                    verify_ecdsa_secp256k1(&public_key, &message, &signature)
+                }
+                SignatureScheme::EcdsaSecp256r1 => {
+                    verify_ecdsa_secp256r1(&public_key, &message, &signature)
+                }
+                SignatureScheme::Ed25519 => verify_ed25519(&public_key, &message, &signature),
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct Counter {
+        count: u64,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self { count: 0 }.instantiate().globalize()
+        }
+
+        pub fn get(&self) -> u64 {
+            self.count
+        }
+    }
+}
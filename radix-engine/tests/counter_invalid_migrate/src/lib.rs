@@ -0,0 +1,21 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self { count: 0 }.instantiate().globalize()
+        }
+
+        pub fn get(&self) -> u32 {
+            self.count
+        }
+
+        pub fn migrate(&self, count: u32) -> u32 {
+            count
+        }
+    }
+}
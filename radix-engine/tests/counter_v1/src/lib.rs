@@ -0,0 +1,21 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self { count: 0 }.instantiate().globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn get(&self) -> u32 {
+            self.count
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self { count: 0 }.instantiate().globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 2;
+        }
+
+        pub fn get(&self) -> u32 {
+            self.count
+        }
+
+        pub fn migrate(&mut self) {
+            self.count *= 100;
+        }
+    }
+}
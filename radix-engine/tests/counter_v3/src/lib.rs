@@ -0,0 +1,31 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct Counter {
+        count: u32,
+        step: Option<u32>,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self {
+                count: 0,
+                step: Some(10),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += self.step.unwrap();
+        }
+
+        pub fn get(&self) -> u32 {
+            self.count
+        }
+
+        pub fn migrate(&mut self) {
+            self.step = Some(10);
+        }
+    }
+}
@@ -2,7 +2,9 @@
 pub mod test_runner;
 
 use crate::test_runner::TestRunner;
+use radix_engine::errors::RuntimeError;
 use radix_engine::ledger::*;
+use radix_engine::model::{Instruction, ResourceManagerError};
 use radix_engine::transaction::*;
 use scrypto::prelude::*;
 
@@ -141,3 +143,326 @@ fn test_singleton_non_fungible() {
     println!("{:?}", receipt);
     assert!(receipt.result.is_ok());
 }
+
+#[test]
+fn mint_non_fungibles_beyond_max_supply_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction1 = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_non_fungible_with_max_supply_and_mint",
+            args![2u32],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt1 = test_runner.validate_and_execute(&transaction1);
+    let transaction2 = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_non_fungible_with_max_supply_and_mint",
+            args![3u32],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt2 = test_runner.validate_and_execute(&transaction2);
+
+    // Assert
+    assert!(receipt1.result.is_ok());
+    let runtime_error = receipt2.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::MaxSupplyExceeded)
+    );
+}
+
+#[test]
+fn create_non_fungible_with_mismatched_id_type_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_non_fungible_with_mismatched_id_type",
+            args![],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::NonFungibleIdTypeDoesNotMatch(
+            NonFungibleIdType::U64,
+            NonFungibleIdType::U32
+        ))
+    );
+}
+
+#[test]
+fn mint_non_fungibles_with_auto_ids() {
+    for id_type in [NonFungibleIdType::U64, NonFungibleIdType::UUID] {
+        // Arrange
+        let mut substate_store = InMemorySubstateStore::with_bootstrap();
+        let mut test_runner = TestRunner::new(&mut substate_store);
+        let (_, _, account) = test_runner.new_account();
+        let package = test_runner.publish_package("non_fungible");
+
+        // Act
+        let transaction = test_runner
+            .new_transaction_builder()
+            .call_function(
+                package,
+                "NonFungibleTest",
+                "create_non_fungible_with_auto_ids",
+                args![id_type],
+            )
+            .call_method_with_all_resources(account, "deposit_batch")
+            .build(test_runner.get_nonce([]))
+            .sign([]);
+        let receipt = test_runner.validate_and_execute(&transaction);
+
+        // Assert
+        receipt.result.expect("Should be okay");
+    }
+}
+
+#[test]
+fn mint_non_fungibles_with_auto_ids_of_unsupported_type_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_non_fungible_with_auto_ids",
+            args![NonFungibleIdType::String],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::AutoIdNotSupported(
+            NonFungibleIdType::String
+        ))
+    );
+}
+
+#[test]
+fn can_enumerate_minted_non_fungibles() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "enumerate_minted_non_fungibles",
+            vec![],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+    let resource_address = receipt.new_resource_addresses[0];
+    let non_fungibles = substate_store.get_non_fungibles(resource_address, 0, 10);
+    assert_eq!(non_fungibles.len(), 2);
+    assert_eq!(non_fungibles[0].0, NonFungibleId::from_u32(0));
+    assert!(non_fungibles[0].1.is_none());
+    assert_eq!(non_fungibles[1].0, NonFungibleId::from_u32(1));
+    assert!(non_fungibles[1].1.is_some());
+}
+
+#[test]
+fn can_update_non_fungible_data_field() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "update_non_fungible_data_field",
+            args![],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+}
+
+#[test]
+fn update_non_fungible_data_field_with_wrong_type_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "update_non_fungible_data_field_with_wrong_type",
+            args![],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::NonFungibleDataDoesNotMatchSchema)
+    );
+}
+
+#[test]
+fn update_immutable_non_fungible_data_field_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "update_immutable_non_fungible_data_field",
+            args![],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::NonFungibleDataFieldNotFound(
+            "name".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn mint_non_fungible_with_mismatched_data_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let package = test_runner.publish_package("non_fungible");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "mint_non_fungible_with_mismatched_data",
+            args![],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::NonFungibleDataDoesNotMatchSchema)
+    );
+}
+
+#[test]
+fn create_non_fungible_without_data_schema_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .add_instruction(Instruction::CallFunction {
+            package_address: SYSTEM_PACKAGE,
+            blueprint_name: "System".to_owned(),
+            function: "new_resource".to_owned(),
+            args: vec![
+                scrypto_encode(&ResourceType::NonFungible {
+                    id_type: NonFungibleIdType::U32,
+                }),
+                scrypto_encode(&HashMap::<String, String>::new()),
+                scrypto_encode(&HashMap::<ResourceMethod, (AccessRule, Mutability)>::new()),
+                scrypto_encode::<Option<MintParams>>(&None),
+                scrypto_encode(&BTreeSet::<String>::new()),
+            ],
+        })
+        .0
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::NonFungibleDataSchemaNotFound)
+    );
+}
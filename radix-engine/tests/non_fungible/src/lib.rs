@@ -7,6 +7,13 @@ pub struct Sandwich {
     pub available: bool,
 }
 
+#[derive(NonFungibleData)]
+pub struct Bagel {
+    pub price: u32,
+    #[scrypto(mutable)]
+    pub available: bool,
+}
+
 blueprint! {
     struct NonFungibleTest {
         vault: Vault,
@@ -20,12 +27,12 @@ blueprint! {
                 .initial_supply(1);
 
             // Create non-fungible resource with mutable supply
-            let resource_address = ResourceBuilder::new_non_fungible()
+            let resource_address = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
                 .metadata("name", "Katz's Sandwiches")
                 .mintable(rule!(require(mint_badge.resource_address())), LOCKED)
                 .burnable(rule!(allow_all), LOCKED)
                 .updateable_non_fungible_data(rule!(require(mint_badge.resource_address())), LOCKED)
-                .no_initial_supply();
+                .no_initial_supply::<Sandwich>();
 
             // Mint a non-fungible
             let non_fungible = mint_badge.authorize(|| {
@@ -42,7 +49,7 @@ blueprint! {
         }
 
         pub fn create_burnable_non_fungible() -> Bucket {
-            ResourceBuilder::new_non_fungible()
+            ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
                 .metadata("name", "Katz's Sandwiches")
                 .burnable(rule!(allow_all), LOCKED)
                 .initial_supply([
@@ -64,7 +71,7 @@ blueprint! {
         }
 
         pub fn create_non_fungible_fixed() -> Bucket {
-            ResourceBuilder::new_non_fungible()
+            ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
                 .metadata("name", "Katz's Sandwiches")
                 .initial_supply([
                     (
@@ -91,6 +98,105 @@ blueprint! {
                 ])
         }
 
+        pub fn create_non_fungible_with_max_supply_and_mint(count: u32) -> (Bucket, Bucket) {
+            let mint_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let resource_address = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
+                .metadata("name", "Katz's Sandwiches")
+                .mintable(rule!(require(mint_badge.resource_address())), LOCKED)
+                .max_supply(2)
+                .no_initial_supply::<Sandwich>();
+
+            let resource_manager = borrow_resource_manager!(resource_address);
+            let mut bucket = Bucket::new(resource_address);
+            for i in 0..count {
+                bucket.put(mint_badge.authorize(|| {
+                    resource_manager.mint_non_fungible(
+                        &NonFungibleId::from_u32(i),
+                        Sandwich {
+                            name: "Test".to_owned(),
+                            available: false,
+                        },
+                    )
+                }));
+            }
+
+            (mint_badge, bucket)
+        }
+
+        pub fn create_non_fungible_with_mismatched_id_type() -> Bucket {
+            ResourceBuilder::new_non_fungible(NonFungibleIdType::U64)
+                .metadata("name", "Katz's Sandwiches")
+                .initial_supply([(
+                    NonFungibleId::from_u32(1),
+                    Sandwich {
+                        name: "One".to_owned(),
+                        available: true,
+                    },
+                )])
+        }
+
+        pub fn create_non_fungible_with_auto_ids(id_type: NonFungibleIdType) -> (Bucket, Bucket) {
+            let mint_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let resource_address = ResourceBuilder::new_non_fungible(id_type)
+                .metadata("name", "Katz's Sandwiches")
+                .mintable(rule!(require(mint_badge.resource_address())), LOCKED)
+                .no_initial_supply::<Sandwich>();
+
+            let resource_manager = borrow_resource_manager!(resource_address);
+            let mut bucket = Bucket::new(resource_address);
+            for _ in 0..2 {
+                bucket.put(mint_badge.authorize(|| {
+                    resource_manager.mint_non_fungible_auto_id(Sandwich {
+                        name: "Test".to_owned(),
+                        available: false,
+                    })
+                }));
+            }
+            assert_eq!(bucket.amount(), 2.into());
+            for id in bucket.non_fungible_ids() {
+                assert_eq!(id.id_type(), id_type);
+            }
+            if id_type == NonFungibleIdType::U64 {
+                assert_eq!(
+                    bucket.non_fungible_ids(),
+                    BTreeSet::from([NonFungibleId::from_u64(1), NonFungibleId::from_u64(2)])
+                );
+            }
+
+            (mint_badge, bucket)
+        }
+
+        pub fn enumerate_minted_non_fungibles() -> Bucket {
+            let mut bucket = Self::create_burnable_non_fungible();
+            bucket.take_non_fungible(&NonFungibleId::from_u32(0)).burn();
+
+            let resource_manager = borrow_resource_manager!(bucket.resource_address());
+            assert_eq!(resource_manager.minted_non_fungible_count(), 2);
+            assert_eq!(resource_manager.total_supply(), 1.into());
+            assert_eq!(
+                resource_manager.minted_non_fungible_ids(0, 10),
+                vec![
+                    (NonFungibleId::from_u32(0), true),
+                    (NonFungibleId::from_u32(1), false)
+                ]
+            );
+            assert_eq!(
+                resource_manager.minted_non_fungible_ids(0, 1),
+                vec![(NonFungibleId::from_u32(0), true)]
+            );
+            assert_eq!(
+                resource_manager.minted_non_fungible_ids(1, 1),
+                vec![(NonFungibleId::from_u32(1), false)]
+            );
+            assert_eq!(resource_manager.minted_non_fungible_ids(2, 1), vec![]);
+
+            bucket
+        }
+
         pub fn verify_does_not_exist(address: NonFungibleAddress) {
             assert_eq!(
                 borrow_resource_manager!(address.resource_address())
@@ -117,6 +223,68 @@ blueprint! {
             (mint_badge, bucket)
         }
 
+        pub fn update_non_fungible_data_field() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) = Self::create_non_fungible_mutable();
+            let resource_manager = borrow_resource_manager!(resource_address);
+            assert_eq!(
+                resource_manager.non_fungible_data_schema(),
+                Some(NonFungibleDataSchema::of::<Sandwich>())
+            );
+
+            mint_badge.authorize(|| {
+                resource_manager.update_non_fungible_data_field(
+                    &NonFungibleId::from_u32(0),
+                    "available",
+                    true,
+                );
+            });
+
+            let data: Sandwich =
+                resource_manager.get_non_fungible_data(&NonFungibleId::from_u32(0));
+            assert_eq!(data.name, "Test");
+            assert_eq!(data.available, true);
+            (mint_badge, bucket)
+        }
+
+        pub fn update_non_fungible_data_field_with_wrong_type() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) = Self::create_non_fungible_mutable();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                    &NonFungibleId::from_u32(0),
+                    "available",
+                    "yes".to_owned(),
+                );
+            });
+            (mint_badge, bucket)
+        }
+
+        pub fn update_immutable_non_fungible_data_field() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) = Self::create_non_fungible_mutable();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                    &NonFungibleId::from_u32(0),
+                    "name",
+                    "Other".to_owned(),
+                );
+            });
+            (mint_badge, bucket)
+        }
+
+        pub fn mint_non_fungible_with_mismatched_data() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, mut bucket) = Self::create_non_fungible_mutable();
+            let bagel = mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).mint_non_fungible(
+                    &NonFungibleId::from_u32(1),
+                    Bagel {
+                        price: 5,
+                        available: true,
+                    },
+                )
+            });
+            bucket.put(bagel);
+            (mint_badge, bucket)
+        }
+
         pub fn non_fungible_exists() -> (Bucket, Bucket) {
             let (mint_badge, resource_address, bucket) = Self::create_non_fungible_mutable();
             assert_eq!(
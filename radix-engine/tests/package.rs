@@ -3,10 +3,10 @@ pub mod test_runner;
 
 use crate::test_runner::TestRunner;
 use radix_engine::errors::RuntimeError;
-use radix_engine::errors::WasmValidationError::NoValidMemoryExport;
+use radix_engine::errors::WasmValidationError::{InvalidMigrateMethod, NoValidMemoryExport};
 use radix_engine::ledger::InMemorySubstateStore;
 use scrypto::prelude::*;
-use radix_engine::model::PackageError;
+use radix_engine::model::{PackageError, PackageUpgrade};
 
 #[test]
 fn missing_memory_should_cause_error() {
@@ -101,3 +101,355 @@ fn zero_return_len_should_cause_data_validation_error() {
         panic!("{} should be data validation error", error);
     }
 }
+
+#[test]
+fn can_publish_package_with_metadata() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let code = TestRunner::compile("package");
+    let metadata = BTreeMap::from([
+        ("name".to_string(), "Test Package".to_string()),
+        ("url".to_string(), "https://radixdlt.com".to_string()),
+    ]);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .publish_package_with_metadata(&code, metadata.clone())
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let package = test_runner.package(receipt.new_package_addresses[0]);
+    assert_eq!(package.metadata(), &metadata);
+}
+
+#[test]
+fn invalid_package_metadata_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let code = TestRunner::compile("package");
+    let metadata = BTreeMap::from([("url".to_string(), "radixdlt.com".to_string())]);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .publish_package_with_metadata(&code, metadata)
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(
+        error,
+        RuntimeError::PackageError(PackageError::InvalidMetadata(MetadataError::InvalidUrl(
+            "radixdlt.com".to_string()
+        )))
+    );
+}
+
+fn publish_counter(
+    test_runner: &mut TestRunner,
+    owner: Option<AccessRule>,
+) -> (PackageAddress, ComponentAddress) {
+    let code = TestRunner::compile("counter_v1");
+    let package_address = match owner {
+        Some(owner) => {
+            let transaction = test_runner
+                .new_transaction_builder()
+                .publish_package_with_owner(&code, owner)
+                .build(test_runner.get_nonce([]))
+                .sign([]);
+            test_runner
+                .validate_and_execute(&transaction)
+                .new_package_addresses[0]
+        }
+        None => test_runner.publish_package("counter_v1"),
+    };
+
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(package_address, "Counter", "new", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let component_address = test_runner
+        .validate_and_execute(&transaction)
+        .new_component_addresses[0];
+
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component_address, "increment", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    test_runner
+        .validate_and_execute(&transaction)
+        .result
+        .expect("Should be okay");
+
+    (package_address, component_address)
+}
+
+#[test]
+fn owner_can_upgrade_package() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, owner) = test_runner.new_key_pair_with_pk_address();
+    let (package_address, _) = publish_counter(&mut test_runner, Some(rule!(require(owner))));
+    let old_code = TestRunner::compile("counter_v1");
+    let new_code = TestRunner::compile("counter_v2");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(package_address, &new_code)
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    assert_eq!(
+        receipt.upgraded_packages,
+        vec![PackageUpgrade {
+            package_address,
+            old_code_hash: hash(&old_code),
+            new_code_hash: hash(&new_code),
+        }]
+    );
+    assert_eq!(test_runner.package(package_address).code(), &new_code[..]);
+}
+
+#[test]
+fn upgrade_package_should_migrate_existing_components() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package_address, component_address) =
+        publish_counter(&mut test_runner, Some(rule!(allow_all)));
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(package_address, &TestRunner::compile("counter_v2"))
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    test_runner
+        .validate_and_execute(&transaction)
+        .result
+        .expect("Should be okay");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component_address, "increment", args![])
+        .call_method(component_address, "get", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let count: u32 = scrypto_decode(&receipt.outputs[1].raw).unwrap();
+    assert_eq!(count, 102);
+}
+
+#[test]
+fn upgrade_package_can_append_optional_state_fields() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package_address, component_address) =
+        publish_counter(&mut test_runner, Some(rule!(allow_all)));
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(package_address, &TestRunner::compile("counter_v3"))
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    test_runner
+        .validate_and_execute(&transaction)
+        .result
+        .expect("Should be okay");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component_address, "increment", args![])
+        .call_method(component_address, "get", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    let count: u32 = scrypto_decode(&receipt.outputs[1].raw).unwrap();
+    assert_eq!(count, 11);
+}
+
+#[test]
+fn migrate_should_not_be_callable() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package_address, component_address) =
+        publish_counter(&mut test_runner, Some(rule!(allow_all)));
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(package_address, &TestRunner::compile("counter_v2"))
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    test_runner
+        .validate_and_execute(&transaction)
+        .result
+        .expect("Should be okay");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component_address, "migrate", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(error, RuntimeError::MigrateNotCallable(component_address));
+}
+
+#[test]
+fn publish_package_with_invalid_migrate_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .publish_package(&TestRunner::compile("counter_invalid_migrate"))
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(
+        error,
+        RuntimeError::PackageError(PackageError::WasmValidationError(InvalidMigrateMethod(
+            "Counter".to_string()
+        )))
+    );
+}
+
+#[test]
+fn upgrade_package_without_owner_signature_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, owner) = test_runner.new_key_pair_with_pk_address();
+    let (package_address, _) = publish_counter(&mut test_runner, Some(rule!(require(owner))));
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(package_address, &TestRunner::compile("counter_v2"))
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn package_without_owner_should_not_be_upgradeable() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package_address, _) = publish_counter(&mut test_runner, None);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(package_address, &TestRunner::compile("counter_v2"))
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn upgrade_package_with_incompatible_state_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package_address, _) = publish_counter(&mut test_runner, Some(rule!(allow_all)));
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(
+            package_address,
+            &TestRunner::compile("counter_incompatible"),
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(
+        error,
+        RuntimeError::PackageError(PackageError::IncompatibleBlueprint("Counter".to_string()))
+    );
+}
+
+#[test]
+fn owner_can_update_package_metadata() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, owner) = test_runner.new_key_pair_with_pk_address();
+    let (package_address, _) = publish_counter(&mut test_runner, Some(rule!(require(owner))));
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .set_package_metadata(package_address, "name", "Counter")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+    assert_eq!(
+        test_runner.package(package_address).metadata().get("name"),
+        Some(&"Counter".to_string())
+    );
+}
+
+#[test]
+fn update_package_metadata_without_owner_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package_address, _) = publish_counter(&mut test_runner, None);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .set_package_metadata(package_address, "name", "Counter")
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_auth_error!(error);
+}
@@ -94,3 +94,390 @@ fn mint_too_much_should_fail() {
         RuntimeError::ResourceManagerError(ResourceManagerError::MaxMintAmountExceeded)
     );
 }
+
+#[test]
+fn mint_within_max_supply_should_succeed() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "create_fungible_with_max_supply_and_mint",
+            args![dec!("60"), dec!("100"), dec!("40")],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
+
+#[test]
+fn mint_beyond_max_supply_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "create_fungible_with_max_supply_and_mint",
+            args![dec!("60"), dec!("100"), dec!("41")],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::MaxSupplyExceeded)
+    );
+}
+
+#[test]
+fn initial_supply_beyond_max_supply_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "create_fungible_with_max_supply_and_mint",
+            args![dec!("101"), dec!("100"), dec!("0")],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::MaxSupplyExceeded)
+    );
+}
+
+#[test]
+fn lowering_max_supply_should_succeed() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "update_max_supply",
+            args![dec!("50")],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
+
+#[test]
+fn raising_max_supply_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "update_max_supply",
+            args![dec!("101")],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::MaxSupplyCannotBeRaised)
+    );
+}
+
+#[test]
+fn lowering_max_supply_below_total_supply_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "update_max_supply",
+            args![dec!("49")],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::MaxSupplyBelowTotalSupply)
+    );
+}
+
+#[test]
+fn create_fungible_with_valid_metadata_should_succeed() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "create_fungible_with_metadata",
+            args!["TT".to_owned(), "https://example.com".to_owned()],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+}
+
+#[test]
+fn create_fungible_with_invalid_symbol_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "create_fungible_with_metadata",
+            args!["T T".to_owned(), "https://example.com".to_owned()],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::InvalidMetadata(
+            MetadataError::InvalidSymbol("T T".to_owned())
+        ))
+    );
+}
+
+#[test]
+fn create_fungible_with_invalid_url_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "create_fungible_with_metadata",
+            args!["TT".to_owned(), "example.com".to_owned()],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::InvalidMetadata(
+            MetadataError::InvalidUrl("example.com".to_owned())
+        ))
+    );
+}
+
+#[test]
+fn new_resource_from_system_should_lock_metadata() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let mut metadata = HashMap::new();
+    metadata.insert("name".to_owned(), "Locked".to_owned());
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .new_token_fixed(metadata, BTreeSet::from(["name".to_owned()]), 100.into())
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+    let resource_address = receipt.new_resource_addresses[0];
+    let (resource_manager, _): (radix_engine::model::ResourceManager, _) = executor
+        .substate_store()
+        .get_decoded_substate(&resource_address)
+        .unwrap();
+    assert_eq!(
+        resource_manager.locked_metadata(),
+        &BTreeSet::from(["name".to_owned()])
+    );
+}
+
+#[test]
+fn set_unlocked_metadata_should_succeed() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "set_metadata_with_locked_name",
+            args!["description".to_owned()],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+}
+
+#[test]
+fn set_locked_metadata_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "ResourceTest",
+            "set_metadata_with_locked_name",
+            args!["name".to_owned()],
+        )
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::MetadataLocked("name".to_owned()))
+    );
+}
+
+#[test]
+fn update_metadata_after_locking_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "resource")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "ResourceTest", "lock_and_update_metadata", args![])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceManagerError(ResourceManagerError::MetadataLocked(
+            "description".to_owned()
+        ))
+    );
+}
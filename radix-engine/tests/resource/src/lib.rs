@@ -64,7 +64,41 @@ blueprint! {
             (badge, token_address)
         }
 
-        pub fn query() -> (Bucket, HashMap<String, String>, Decimal) {
+        pub fn create_fungible_with_max_supply_and_mint(
+            initial_supply: Decimal,
+            max_supply: Decimal,
+            amount: Decimal,
+        ) -> (Bucket, Bucket, Bucket) {
+            let badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let tokens = ResourceBuilder::new_fungible()
+                .metadata("name", "TestToken")
+                .mintable(rule!(require(badge.resource_address())), LOCKED)
+                .max_supply(max_supply)
+                .initial_supply(initial_supply);
+            let resource_manager = borrow_resource_manager!(tokens.resource_address());
+            assert_eq!(resource_manager.max_supply(), Some(max_supply));
+            let minted = badge.authorize(|| resource_manager.mint(amount));
+            (badge, tokens, minted)
+        }
+
+        pub fn update_max_supply(max_supply: Decimal) -> (Bucket, Bucket) {
+            let badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let tokens = ResourceBuilder::new_fungible()
+                .metadata("name", "TestToken")
+                .mintable(rule!(require(badge.resource_address())), LOCKED)
+                .max_supply(100)
+                .initial_supply(50);
+            let resource_manager = borrow_resource_manager!(tokens.resource_address());
+            badge.authorize(|| resource_manager.set_max_supply(max_supply));
+            assert_eq!(resource_manager.max_supply(), Some(max_supply));
+            (badge, tokens)
+        }
+
+        pub fn query() -> (Bucket, BTreeMap<String, String>, Decimal) {
             let (badge, resource_address) = Self::create_fungible();
             let resource_manager = borrow_resource_manager!(resource_address);
             (
@@ -94,7 +128,7 @@ blueprint! {
                 .metadata("name", "TestToken")
                 .no_initial_supply());
 
-            let mut new_metadata = HashMap::new();
+            let mut new_metadata = BTreeMap::new();
             new_metadata.insert("a".to_owned(), "b".to_owned());
             badge.authorize(|| {
                 token_resource_manager.update_metadata(new_metadata.clone());
@@ -103,5 +137,50 @@ blueprint! {
 
             badge
         }
+
+        pub fn create_fungible_with_metadata(symbol: String, url: String) -> Bucket {
+            ResourceBuilder::new_fungible()
+                .metadata(MetadataKey::Symbol, symbol)
+                .metadata(MetadataKey::Url, url)
+                .initial_supply(100)
+        }
+
+        pub fn set_metadata_with_locked_name(key: String) -> Bucket {
+            let badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let token_resource_manager = borrow_resource_manager!(ResourceBuilder::new_fungible()
+                .updateable_metadata(rule!(require(badge.resource_address())), LOCKED)
+                .metadata(MetadataKey::Name, "TestToken")
+                .lock_metadata(MetadataKey::Name)
+                .no_initial_supply());
+            assert!(token_resource_manager.locked_metadata().contains("name"));
+
+            badge.authorize(|| {
+                token_resource_manager.set_metadata(key.clone(), "Updated");
+            });
+            assert_eq!(token_resource_manager.metadata().get(&key).unwrap(), "Updated");
+
+            badge
+        }
+
+        pub fn lock_and_update_metadata() -> Bucket {
+            let badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let token_resource_manager = borrow_resource_manager!(ResourceBuilder::new_fungible()
+                .updateable_metadata(rule!(require(badge.resource_address())), LOCKED)
+                .metadata(MetadataKey::Description, "A token")
+                .no_initial_supply());
+
+            let mut new_metadata = BTreeMap::new();
+            new_metadata.insert("description".to_owned(), "Another token".to_owned());
+            badge.authorize(|| {
+                token_resource_manager.lock_metadata(MetadataKey::Description);
+                token_resource_manager.update_metadata(new_metadata);
+            });
+
+            badge
+        }
     }
 }
@@ -75,7 +75,7 @@ blueprint! {
         }
 
         pub fn create_non_fungible_fixed() -> Bucket {
-            ResourceBuilder::new_non_fungible()
+            ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
                 .metadata("name", "Katz's Sandwiches")
                 .initial_supply([
                     (
@@ -1,5 +1,5 @@
 use radix_engine::ledger::*;
-use radix_engine::model::{Component, Receipt, SignedTransaction};
+use radix_engine::model::{Component, Package, Receipt, SignedTransaction};
 use radix_engine::transaction::*;
 use scrypto::abi;
 use scrypto::prelude::*;
@@ -27,11 +27,7 @@ impl<'l> TestRunner<'l> {
         &mut self,
     ) -> (EcdsaPublicKey, EcdsaPrivateKey, NonFungibleAddress) {
         let (pk, sk) = self.new_key_pair();
-        (
-            pk,
-            sk,
-            NonFungibleAddress::new(ECDSA_TOKEN, NonFungibleId::from_bytes(pk.to_vec())),
-        )
+        (pk, sk, NonFungibleAddress::from_public_key(&pk))
     }
 
     pub fn new_account_with_auth_rule(&mut self, withdraw_auth: &AccessRule) -> ComponentAddress {
@@ -42,6 +38,14 @@ impl<'l> TestRunner<'l> {
         self.executor.new_account()
     }
 
+    pub fn set_current_epoch(&mut self, epoch: u64) {
+        self.executor.substate_store_mut().set_epoch(epoch)
+    }
+
+    pub fn set_current_time(&mut self, current_time: u64) {
+        self.executor.set_current_time(current_time).unwrap()
+    }
+
     pub fn validate_and_execute(&mut self, transaction: &SignedTransaction) -> Receipt {
         self.executor.validate_and_execute(transaction).unwrap()
     }
@@ -62,6 +66,14 @@ impl<'l> TestRunner<'l> {
             .unwrap()
     }
 
+    pub fn package(&self, package_address: PackageAddress) -> Package {
+        self.executor
+            .substate_store()
+            .get_decoded_substate(&package_address)
+            .map(|(package, _)| package)
+            .unwrap()
+    }
+
     pub fn export_abi(
         &self,
         package_address: PackageAddress,
@@ -1,7 +1,9 @@
 use radix_engine::errors::ResourceFailure;
 use radix_engine::errors::RuntimeError;
 use radix_engine::ledger::*;
+use radix_engine::model::{MethodAuthorizationError, VaultError};
 use radix_engine::transaction::*;
+use scrypto::engine::types::VaultId;
 use scrypto::prelude::*;
 
 #[test]
@@ -462,3 +464,139 @@ fn create_mutable_vault_with_get_resource_manager() {
     // Assert
     receipt.result.expect("Should be okay");
 }
+
+fn create_recallable_vault(
+    executor: &mut TransactionExecutor<InMemorySubstateStore>,
+    account: ComponentAddress,
+) -> (ComponentAddress, VaultId, ResourceAddress) {
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "vault")))
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "RecallTest", "new", args![])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let component_address = receipt.new_component_addresses[0];
+    let admin_badge = receipt.new_resource_addresses[0];
+
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "vault_id", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let vault_id: VaultId = scrypto_decode(&receipt.outputs[0].raw).unwrap();
+
+    (component_address, vault_id, admin_badge)
+}
+
+#[test]
+fn can_recall_from_vault_with_authority() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let (component_address, vault_id, admin_badge) =
+        create_recallable_vault(&mut executor, account);
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .create_proof_from_account(admin_badge, account)
+        .recall_from_vault(component_address, vault_id, Decimal::from(10))
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    receipt.result.expect("Should be okay");
+}
+
+#[test]
+fn cannot_recall_from_vault_without_authority() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let (component_address, vault_id, _) = create_recallable_vault(&mut executor, account);
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .recall_from_vault(component_address, vault_id, Decimal::from(10))
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    match runtime_error {
+        RuntimeError::AuthorizationError {
+            error: MethodAuthorizationError::NotAuthorized,
+            ..
+        } => {}
+        _ => panic!("Should be auth error but was {}", runtime_error),
+    }
+}
+
+#[test]
+fn cannot_take_from_frozen_vault() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let (component_address, vault_id, admin_badge) =
+        create_recallable_vault(&mut executor, account);
+    let transaction = TransactionBuilder::new()
+        .create_proof_from_account(admin_badge, account)
+        .freeze_vault(component_address, vault_id)
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    receipt.result.expect("Should be okay");
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "take", args![Decimal::one()])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    match runtime_error {
+        RuntimeError::VaultError(VaultError::VaultFrozen) => {}
+        _ => panic!("Should be vault frozen error but was {}", runtime_error),
+    }
+}
+
+#[test]
+fn can_take_from_unfrozen_vault() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk, account) = executor.new_account();
+    let (component_address, vault_id, admin_badge) =
+        create_recallable_vault(&mut executor, account);
+    let transaction = TransactionBuilder::new()
+        .create_proof_from_account(admin_badge, account)
+        .freeze_vault(component_address, vault_id)
+        .unfreeze_vault(component_address, vault_id)
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    receipt.result.expect("Should be okay");
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "take", args![Decimal::one()])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    receipt.result.expect("Should be okay");
+}
@@ -1,2 +1,3 @@
 pub mod non_existent_vault;
+pub mod recall;
 pub mod vault;
@@ -0,0 +1,40 @@
+use scrypto::prelude::*;
+use scrypto::engine::types::VaultId;
+
+blueprint! {
+    struct RecallTest {
+        vault: Vault,
+    }
+
+    impl RecallTest {
+        pub fn new() -> (ComponentAddress, Bucket) {
+            let admin_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("name", "TestToken")
+                .recallable(rule!(require(admin_badge.resource_address())), LOCKED)
+                .freezable(rule!(require(admin_badge.resource_address())), LOCKED)
+                .initial_supply(100);
+            let component_address = RecallTest {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .globalize();
+            (component_address, admin_badge)
+        }
+
+        pub fn vault_id(&self) -> VaultId {
+            self.vault.0
+        }
+
+        pub fn take(&mut self, amount: Decimal) -> Bucket {
+            self.vault.take(amount)
+        }
+
+        pub fn is_frozen(&self) -> bool {
+            self.vault.is_frozen()
+        }
+    }
+}
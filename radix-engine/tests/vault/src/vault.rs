@@ -125,7 +125,7 @@ blueprint! {
         }
 
         fn create_non_fungible_vault() -> Vault {
-            let bucket = ResourceBuilder::new_non_fungible()
+            let bucket = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
                 .metadata("name", "TestToken")
                 .initial_supply([(NonFungibleId::from_u32(1), Data {})]);
             Vault::with_bucket(bucket)
@@ -0,0 +1,196 @@
+#[rustfmt::skip]
+pub mod test_runner;
+
+use crate::test_runner::TestRunner;
+use radix_engine::errors::*;
+use radix_engine::ledger::*;
+use radix_engine::model::WorktopError;
+use scrypto::prelude::*;
+
+#[test]
+fn assert_worktop_is_empty_should_succeed_on_empty_worktop() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account_by_amount(10.into(), resource_address, account)
+        .call_method_with_all_resources(account, "deposit_batch")
+        .assert_worktop_is_empty()
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
+
+#[test]
+fn assert_worktop_is_empty_should_fail_on_non_empty_worktop() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account_by_amount(10.into(), resource_address, account)
+        .assert_worktop_is_empty()
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert_eq!(
+        receipt.result,
+        Err(RuntimeError::WorktopError(WorktopError::NotEmpty(vec![
+            resource_address
+        ])))
+    );
+}
+
+#[test]
+fn assert_worktop_contains_at_most_should_succeed_within_maximum() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account_by_amount(10.into(), resource_address, account)
+        .assert_worktop_contains_at_most(10.into(), resource_address)
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
+
+#[test]
+fn assert_worktop_contains_at_most_should_fail_above_maximum() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account_by_amount(10.into(), resource_address, account)
+        .assert_worktop_contains_at_most(5.into(), resource_address)
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert_eq!(
+        receipt.result,
+        Err(RuntimeError::WorktopError(
+            WorktopError::AmountAboveMaximum {
+                resource_address,
+                amount: 10.into(),
+                maximum: 5.into(),
+            }
+        ))
+    );
+}
+
+#[test]
+fn assert_worktop_contains_exact_ids_should_succeed_on_same_ids() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let ids = BTreeSet::from([NonFungibleId::from_u32(1), NonFungibleId::from_u32(2)]);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account_by_ids(&ids, resource_address, account)
+        .assert_worktop_contains_exact_ids(&ids, resource_address)
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
+
+#[test]
+fn assert_worktop_contains_exact_ids_should_fail_on_extra_ids() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let ids = BTreeSet::from([NonFungibleId::from_u32(1), NonFungibleId::from_u32(2)]);
+    let expected = BTreeSet::from([NonFungibleId::from_u32(1)]);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account_by_ids(&ids, resource_address, account)
+        .assert_worktop_contains_exact_ids(&expected, resource_address)
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert_eq!(
+        receipt.result,
+        Err(RuntimeError::WorktopError(
+            WorktopError::NonFungibleIdsMismatch {
+                resource_address,
+                expected,
+                actual: ids,
+            }
+        ))
+    );
+}
+
+#[test]
+fn assert_worktop_contains_by_ids_should_check_ids() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk, sk, account) = test_runner.new_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let ids = BTreeSet::from([NonFungibleId::from_u32(1), NonFungibleId::from_u32(2)]);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account_by_ids(&ids, resource_address, account)
+        .assert_worktop_contains_by_ids(&ids, resource_address)
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(test_runner.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    println!("{:?}", receipt);
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
@@ -16,16 +16,28 @@ use sbor::{Decode, Encode, TypeId};
 pub struct Blueprint {
     pub package_address: String,
     pub blueprint_name: String,
+    /// The type of the component state
+    #[cfg_attr(feature = "serde", serde(default = "unknown_structure"))]
+    pub structure: Type,
     pub functions: Vec<Function>,
     pub methods: Vec<Method>,
 }
 
+/// The component state type assumed for ABIs exported without one.
+#[cfg(feature = "serde")]
+fn unknown_structure() -> Type {
+    Type::Unit
+}
+
 /// Represents a function.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, TypeId, Encode, Decode)]
 pub struct Function {
     pub name: String,
     pub inputs: Vec<Type>,
+    /// The parameter names of the inputs, which may be absent from older ABIs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub input_names: Vec<String>,
     pub output: Type,
 }
 
@@ -36,6 +48,9 @@ pub struct Method {
     pub name: String,
     pub mutability: Mutability,
     pub inputs: Vec<Type>,
+    /// The parameter names of the inputs, which may be absent from older ABIs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub input_names: Vec<String>,
     pub output: Type,
 }
 
@@ -260,6 +260,7 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<(Vec<Expr>, Vec<
                     let name = m.sig.ident.to_string();
                     let mut mutability = None;
                     let mut inputs = vec![];
+                    let mut input_names = vec![];
                     for input in &m.sig.inputs {
                         match input {
                             FnArg::Receiver(ref r) => {
@@ -281,6 +282,13 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<(Vec<Expr>, Vec<
                                 inputs.push(quote! {
                                     <#ty>::describe()
                                 });
+                                let input_name = match t.pat.as_ref() {
+                                    Pat::Ident(p) => p.ident.to_string(),
+                                    _ => format!("arg{}", input_names.len()),
+                                };
+                                input_names.push(quote! {
+                                    #input_name.to_owned()
+                                });
                             }
                         }
                     }
@@ -302,6 +310,7 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<(Vec<Expr>, Vec<
                             ::scrypto::abi::Function {
                                 name: #name.to_owned(),
                                 inputs: vec![#(#inputs),*],
+                                input_names: vec![#(#input_names),*],
                                 output: #output,
                             }
                         });
@@ -311,6 +320,7 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<(Vec<Expr>, Vec<
                                 name: #name.to_owned(),
                                 mutability: #mutability,
                                 inputs: vec![#(#inputs),*],
+                                input_names: vec![#(#input_names),*],
                                 output: #output,
                             }
                         });
@@ -547,6 +557,7 @@ mod tests {
                         name: "x".to_owned(),
                         mutability: ::scrypto::abi::Mutability::Immutable,
                         inputs: vec![],
+                        input_names: vec![],
                         output: <u32>::describe(),
                     }];
                     let schema: Type = blueprint::Test::describe();
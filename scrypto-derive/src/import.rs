@@ -131,7 +131,7 @@ pub fn handle_import(input: TokenStream) -> Result<TokenStream> {
     Ok(output)
 }
 
-fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
+pub fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
     let mut structs = Vec::<Item>::new();
 
     let t: Type = match ty {
@@ -324,6 +324,9 @@ fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
                 "EcdsaPublicKey" => "::scrypto::crypto::EcdsaPublicKey",
                 "EcdsaSignature" => "::scrypto::crypto::EcdsaSignature",
                 "Decimal" => "::scrypto::math::Decimal",
+                "PreciseDecimal" => "::scrypto::math::PreciseDecimal",
+                "I256" => "::scrypto::math::I256",
+                "U256" => "::scrypto::math::U256",
                 "Bucket" => "::scrypto::resource::Bucket",
                 "Proof" => "::scrypto::resource::Proof",
                 "Vault" => "::scrypto::resource::Vault",
@@ -0,0 +1,290 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::*;
+
+use sbor::describe as des;
+use scrypto_abi as abi;
+
+use crate::import::get_native_type;
+
+macro_rules! trace {
+    ($($arg:expr),*) => {{
+        #[cfg(feature = "trace")]
+        println!($($arg),*);
+    }};
+}
+
+pub fn handle_import_client(input: TokenStream) -> Result<TokenStream> {
+    trace!("Started processing import_client macro");
+
+    let content = parse2::<LitStr>(input)?;
+    let blueprint: abi::Blueprint = match serde_json::from_str(content.value().as_str()) {
+        Ok(o) => o,
+        Err(e) => {
+            return Err(Error::new(content.span(), e));
+        }
+    };
+    trace!("Parsed ABI: {:?}", blueprint);
+
+    let package_address = blueprint.package_address;
+    let blueprint_name = blueprint.blueprint_name;
+    let ident = format_ident!("{}Client", blueprint_name);
+    trace!("Blueprint name: {}", blueprint_name);
+
+    let mut structs: Vec<Item> = vec![];
+
+    let mut functions = Vec::<ItemFn>::new();
+    for function in &blueprint.functions {
+        trace!("Processing function: {:?}", function);
+
+        let func_name = &function.name;
+        let func_indent = format_ident!("{}", func_name);
+        let mut func_types = Vec::<Type>::new();
+        let mut func_args = Vec::<Ident>::new();
+        let mut func_values = Vec::<Expr>::new();
+
+        for (i, input) in function.inputs.iter().enumerate() {
+            let ident = input_ident(&function.input_names, i);
+            let (new_type, new_value, new_structs) = get_client_type(input, &ident)?;
+            func_args.push(ident);
+            func_types.push(new_type);
+            func_values.push(new_value);
+            structs.extend(new_structs);
+        }
+
+        functions.push(parse_quote! {
+            pub fn #func_indent<'a>(
+                builder: &'a mut ::radix_engine::transaction::TransactionBuilder
+                #(, #func_args: #func_types)*
+            ) -> &'a mut ::radix_engine::transaction::TransactionBuilder {
+                builder.call_function(
+                    Self::package_address(),
+                    #blueprint_name,
+                    #func_name,
+                    ::scrypto::args!(#(#func_values),*)
+                )
+            }
+        });
+    }
+
+    let mut methods = Vec::<ItemFn>::new();
+    for method in &blueprint.methods {
+        trace!("Processing method: {:?}", method);
+
+        let method_name = &method.name;
+        let method_indent = format_ident!("{}", method_name);
+        let mut method_types = Vec::<Type>::new();
+        let mut method_args = Vec::<Ident>::new();
+        let mut method_values = Vec::<Expr>::new();
+
+        for (i, input) in method.inputs.iter().enumerate() {
+            let ident = input_ident(&method.input_names, i);
+            let (new_type, new_value, new_structs) = get_client_type(input, &ident)?;
+            method_args.push(ident);
+            method_types.push(new_type);
+            method_values.push(new_value);
+            structs.extend(new_structs);
+        }
+
+        methods.push(parse_quote! {
+            pub fn #method_indent<'a>(
+                &self,
+                builder: &'a mut ::radix_engine::transaction::TransactionBuilder
+                #(, #method_args: #method_types)*
+            ) -> &'a mut ::radix_engine::transaction::TransactionBuilder {
+                builder.call_method(
+                    self.component_address,
+                    #method_name,
+                    ::scrypto::args!(#(#method_values),*)
+                )
+            }
+        });
+    }
+
+    let output = quote! {
+        #(#structs)*
+
+        pub struct #ident {
+            component_address: ::scrypto::component::ComponentAddress,
+        }
+
+        impl #ident {
+            pub fn package_address() -> ::scrypto::component::PackageAddress {
+                ::scrypto::component::PackageAddress::from_str(#package_address).unwrap()
+            }
+
+            #(#functions)*
+
+            #(#methods)*
+        }
+
+        impl From<::scrypto::component::ComponentAddress> for #ident {
+            fn from(component_address: ::scrypto::component::ComponentAddress) -> Self {
+                Self {
+                    component_address
+                }
+            }
+        }
+
+        impl From<#ident> for ::scrypto::component::ComponentAddress {
+            fn from(a: #ident) -> ::scrypto::component::ComponentAddress {
+                a.component_address
+            }
+        }
+    };
+    trace!("Finished processing import_client macro");
+
+    #[cfg(feature = "trace")]
+    crate::utils::print_generated_code("import_client!", &output);
+
+    Ok(output)
+}
+
+/// Returns the client parameter type, the value to encode and any new structs.
+///
+/// Buckets and proofs are passed by the IDs returned from the worktop and auth zone
+/// helpers of the transaction builder.
+/// Returns the identifier of the i-th argument, named as in the ABI unless the name is absent or
+/// would shadow the transaction builder.
+fn input_ident(input_names: &[String], i: usize) -> Ident {
+    match input_names.get(i) {
+        Some(name) if name != "builder" => format_ident!("{}", name),
+        _ => format_ident!("arg{}", i),
+    }
+}
+
+fn get_client_type(ty: &des::Type, ident: &Ident) -> Result<(Type, Expr, Vec<Item>)> {
+    match ty {
+        des::Type::Custom { name, .. } if name == "Bucket" => Ok((
+            parse_quote! { ::scrypto::engine::types::BucketId },
+            parse_quote! { ::scrypto::resource::Bucket(#ident) },
+            vec![],
+        )),
+        des::Type::Custom { name, .. } if name == "Proof" => Ok((
+            parse_quote! { ::scrypto::engine::types::ProofId },
+            parse_quote! { ::scrypto::resource::Proof(#ident) },
+            vec![],
+        )),
+        _ => {
+            let (new_type, new_structs) = get_native_type(ty)?;
+            Ok((new_type, parse_quote! { #ident }, new_structs))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn assert_code_eq(a: TokenStream, b: TokenStream) {
+        assert_eq!(a.to_string(), b.to_string());
+    }
+
+    #[test]
+    fn test_import_client() {
+        let input = TokenStream::from_str(
+            r###"
+                r#"
+                {
+                    "package_address": "056967d3d49213394892980af59be76e9b3e7cc4cb78237460d0c7",
+                    "blueprint_name": "Simple",
+                    "functions": [
+                        {
+                            "name": "new",
+                            "inputs": [
+                                {
+                                    "type": "U32"
+                                }
+                            ],
+                            "output": {
+                                "type": "Custom",
+                                "name": "ComponentAddress",
+                                "generics": []
+                            }
+                        }
+                    ],
+                    "methods": [
+                        {
+                            "name": "deposit",
+                            "mutability": "Mutable",
+                            "inputs": [
+                                {
+                                    "type": "Custom",
+                                    "name": "Bucket",
+                                    "generics": []
+                                },
+                                {
+                                    "type": "Custom",
+                                    "name": "Proof",
+                                    "generics": []
+                                }
+                            ],
+                            "input_names": ["bucket", "builder"],
+                            "output": {
+                                "type": "Unit"
+                            }
+                        }
+                    ]
+                }
+                "#
+            "###,
+        )
+        .unwrap();
+        let output = handle_import_client(input).unwrap();
+
+        assert_code_eq(
+            output,
+            quote! {
+                pub struct SimpleClient {
+                    component_address: ::scrypto::component::ComponentAddress,
+                }
+                impl SimpleClient {
+                    pub fn package_address() -> ::scrypto::component::PackageAddress {
+                        ::scrypto::component::PackageAddress::from_str("056967d3d49213394892980af59be76e9b3e7cc4cb78237460d0c7").unwrap()
+                    }
+                    pub fn new<'a>(
+                        builder: &'a mut ::radix_engine::transaction::TransactionBuilder,
+                        arg0: u32
+                    ) -> &'a mut ::radix_engine::transaction::TransactionBuilder {
+                        builder.call_function(
+                            Self::package_address(),
+                            "Simple",
+                            "new",
+                            ::scrypto::args!(arg0)
+                        )
+                    }
+                    pub fn deposit<'a>(
+                        &self,
+                        builder: &'a mut ::radix_engine::transaction::TransactionBuilder,
+                        bucket: ::scrypto::engine::types::BucketId,
+                        arg1: ::scrypto::engine::types::ProofId
+                    ) -> &'a mut ::radix_engine::transaction::TransactionBuilder {
+                        builder.call_method(
+                            self.component_address,
+                            "deposit",
+                            ::scrypto::args!(
+                                ::scrypto::resource::Bucket(bucket),
+                                ::scrypto::resource::Proof(arg1)
+                            )
+                        )
+                    }
+                }
+                impl From<::scrypto::component::ComponentAddress> for SimpleClient {
+                    fn from(component_address: ::scrypto::component::ComponentAddress) -> Self {
+                        Self {
+                            component_address
+                        }
+                    }
+                }
+                impl From<SimpleClient> for ::scrypto::component::ComponentAddress {
+                    fn from(a: SimpleClient) -> ::scrypto::component::ComponentAddress {
+                        a.component_address
+                    }
+                }
+            },
+        );
+    }
+}
@@ -1,6 +1,7 @@
 mod ast;
 mod blueprint;
 mod import;
+mod import_client;
 mod non_fungible_data;
 mod utils;
 
@@ -98,6 +99,62 @@ pub fn import(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Imports a blueprint from its ABI, as a transaction-building client.
+///
+/// Unlike `import!`, which generates stubs for calling the blueprint from within
+/// another blueprint, this macro generates a `<Blueprint>Client` for off-ledger code.
+/// Each function or method appends a call instruction to a `TransactionBuilder`, and
+/// buckets and proofs are passed by the IDs obtained from the builder's worktop and
+/// auth zone helpers.
+///
+/// # Example
+/// ```ignore
+/// use radix_engine::transaction::*;
+/// use scrypto::prelude::*;
+///
+/// scrypto::import_client! {
+/// r#"
+/// {
+///     "package_address": "01a405d3129b61e86c51c3168d553d2ffd7a3f0bd2f66b5a3e9876",
+///     "blueprint_name": "GumballMachine",
+///     "functions": [],
+///     "methods": [
+///         {
+///             "name": "get_gumball",
+///             "mutability": "Mutable",
+///             "inputs": [
+///                 {
+///                     "type": "Custom",
+///                     "name": "Bucket"
+///                 }
+///             ],
+///             "output": {
+///                 "type": "Custom",
+///                 "name": "Bucket"
+///             }
+///         }
+///     ]
+/// }
+/// "#
+/// }
+///
+/// let machine = GumballMachineClient::from(component_address);
+/// let transaction = TransactionBuilder::new()
+///     .withdraw_from_account_by_amount(1.into(), RADIX_TOKEN, account)
+///     .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+///         machine.get_gumball(builder, bucket_id)
+///     })
+///     .call_method_with_all_resources(account, "deposit_batch")
+///     .build(nonce)
+///     .sign([&sk]);
+/// ```
+#[proc_macro]
+pub fn import_client(input: TokenStream) -> TokenStream {
+    import_client::handle_import_client(proc_macro2::TokenStream::from(input))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 /// Derive code that describe a non-fungible data structure.
 ///
 /// # Example
@@ -65,6 +65,7 @@ fn test_simple_abi() {
                 {
                     "name": "new",
                     "inputs": [],
+                    "input_names": [],
                     "output": {
                         "type": "Custom",
                         "name": "ComponentAddress",
@@ -74,6 +75,7 @@ fn test_simple_abi() {
                 {
                     "name": "custom_types",
                     "inputs": [],
+                    "input_names": [],
                     "output": {
                         "type": "Tuple",
                         "elements": [
@@ -128,6 +130,7 @@ fn test_simple_abi() {
                     "name": "get_state",
                     "mutability": "Immutable",
                     "inputs": [],
+                    "input_names": [],
                     "output": {
                         "type": "U32"
                     }
@@ -140,6 +143,7 @@ fn test_simple_abi() {
                             "type": "U32"
                         }
                     ],
+                    "input_names": ["new_state"],
                     "output": {
                         "type": "Unit"
                     }
@@ -2,6 +2,7 @@
 
 use sbor::describe::*;
 use scrypto::prelude::*;
+use scrypto::values::ScryptoValue;
 
 #[derive(NonFungibleData, Debug, Eq, PartialEq)]
 pub struct Sample {
@@ -42,3 +43,36 @@ fn test_non_fungible_data() {
         }
     );
 }
+
+#[test]
+fn test_non_fungible_data_schema() {
+    let schema = NonFungibleDataSchema::of::<Sample>();
+    assert_eq!(schema.immutable, Sample::immutable_data_schema());
+    assert_eq!(schema.mutable, Sample::mutable_data_schema());
+    assert_eq!(schema.mutable_field("b"), Some((0, &Type::String)));
+    assert_eq!(schema.mutable_field("a"), None);
+
+    let instance = Sample {
+        a: 1,
+        b: "Test".to_owned(),
+    };
+    let mutable_data = ScryptoValue::from_slice(&instance.mutable_data()).unwrap();
+    assert!(mutable_data.matches_schema(&schema.mutable));
+    assert!(!mutable_data.matches_schema(&schema.immutable));
+}
+
+#[test]
+fn test_empty_collections_match_schema_by_element_type() {
+    let empty_vec = ScryptoValue::from_value(&Vec::<String>::new());
+    assert!(empty_vec.matches_schema(&Vec::<String>::describe()));
+    assert!(!empty_vec.matches_schema(&Vec::<u32>::describe()));
+
+    let empty_map = ScryptoValue::from_value(&BTreeMap::<String, u32>::new());
+    assert!(empty_map.matches_schema(&BTreeMap::<String, u32>::describe()));
+    assert!(!empty_map.matches_schema(&BTreeMap::<String, String>::describe()));
+    assert!(!empty_map.matches_schema(&BTreeMap::<u32, u32>::describe()));
+
+    let empty_decimals = ScryptoValue::from_value(&Vec::<Decimal>::new());
+    assert!(empty_decimals.matches_schema(&Vec::<Decimal>::describe()));
+    assert!(!empty_decimals.matches_schema(&Vec::<ResourceAddress>::describe()));
+}
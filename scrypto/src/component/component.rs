@@ -1,15 +1,18 @@
 use sbor::*;
 
+use crate::args;
 use crate::buffer::*;
 use crate::component::*;
 use crate::core::*;
 use crate::engine::{api::*, call_engine};
 use crate::misc::*;
-use crate::resource::AccessRules;
+use crate::resource::{AccessRule, AccessRules};
 use crate::rust::borrow::ToOwned;
+use crate::rust::collections::BTreeMap;
 use crate::rust::fmt;
 use crate::rust::str::FromStr;
 use crate::rust::string::String;
+use crate::rust::string::ToString;
 use crate::rust::vec::Vec;
 use crate::types::*;
 
@@ -17,6 +20,8 @@ pub struct LocalComponent {
     blueprint_name: String,
     state: Vec<u8>,
     access_rules_list: Vec<AccessRules>,
+    metadata: BTreeMap<String, String>,
+    metadata_access_rule: AccessRule,
 }
 
 impl LocalComponent {
@@ -25,6 +30,8 @@ impl LocalComponent {
             blueprint_name,
             state,
             access_rules_list: Vec::new(),
+            metadata: BTreeMap::new(),
+            metadata_access_rule: AccessRule::DenyAll,
         }
     }
 
@@ -33,13 +40,28 @@ impl LocalComponent {
         self
     }
 
+    /// Adds a metadata entry to this component.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, name: K, value: V) -> Self {
+        self.metadata
+            .insert(name.as_ref().to_owned(), value.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the rule for updating the metadata once globalized, which defaults to `deny_all`.
+    pub fn updateable_metadata(mut self, method_auth: AccessRule) -> Self {
+        self.metadata_access_rule = method_auth;
+        self
+    }
+
     pub fn globalize(self) -> ComponentAddress {
-        let input = CreateComponentInput {
+        let input = CreateComponentWithMetadataInput {
             blueprint_name: self.blueprint_name,
             state: self.state,
             access_rules_list: self.access_rules_list,
+            metadata: self.metadata,
+            metadata_access_rule: self.metadata_access_rule,
         };
-        let output: CreateComponentOutput = call_engine(CREATE_COMPONENT, input);
+        let output: CreateComponentOutput = call_engine(CREATE_COMPONENT_WITH_METADATA, input);
         output.component_address
     }
 }
@@ -101,6 +123,31 @@ impl Component {
         let output: GetComponentInfoOutput = call_engine(GET_COMPONENT_INFO, input);
         output.blueprint_name
     }
+
+    /// Returns the metadata associated with this component.
+    pub fn metadata(&self) -> BTreeMap<String, String> {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ComponentRef(self.0),
+            function: "get_metadata".to_string(),
+            args: args![],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
+    /// Sets a single metadata entry of this component, keeping the others.
+    ///
+    /// # Panics
+    /// Panics if the metadata access rule is not satisfied, or the entry is a well-known one
+    /// with an invalid value.
+    pub fn set_metadata<K: AsRef<str>, V: AsRef<str>>(&self, name: K, value: V) {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ComponentRef(self.0),
+            function: "set_metadata".to_string(),
+            args: args![name.as_ref().to_owned(), value.as_ref().to_owned()],
+        };
+        let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+    }
 }
 
 //========
@@ -1,3 +1,4 @@
+use crate::component::{ComponentAddress, PackageAddress};
 use crate::core::ScryptoActor;
 use crate::engine::types::{BucketId, ProofId, VaultId};
 use crate::resource::ResourceAddress;
@@ -17,4 +18,7 @@ pub enum SNodeRef {
     ProofRef(ProofId),
     Proof(ProofId),
     VaultRef(VaultId),
+    ComponentVaultRef(ComponentAddress, VaultId),
+    ComponentRef(ComponentAddress),
+    PackageRef(PackageAddress),
 }
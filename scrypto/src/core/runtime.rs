@@ -82,4 +82,11 @@ impl Runtime {
         let output: GetCurrentEpochOutput = call_engine(GET_CURRENT_EPOCH, input);
         output.current_epoch
     }
+
+    /// Returns the current time, in milliseconds since the Unix epoch.
+    pub fn current_time() -> u64 {
+        let input = GetCurrentTimeInput {};
+        let output: GetCurrentTimeOutput = call_engine(GET_CURRENT_TIME, input);
+        output.current_time
+    }
 }
@@ -25,6 +25,8 @@ pub struct EcdsaSignature(Signature);
 pub enum SignatureValidationError {}
 
 /// Ecdsa signature verifier.
+///
+/// Verification runs inside WASM; blueprints should prefer the native `verify_ecdsa_secp256r1`.
 pub struct EcdsaVerifier;
 
 /// Represents an ECDSA private key.
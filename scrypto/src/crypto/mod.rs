@@ -1,5 +1,6 @@
 mod ecdsa;
 mod hash;
+mod native;
 mod sha2;
 mod sha3;
 
@@ -7,3 +8,4 @@ pub use self::ecdsa::*;
 pub use self::sha2::{sha256, sha256_twice};
 pub use self::sha3::sha3;
 pub use hash::*;
+pub use native::*;
@@ -0,0 +1,99 @@
+use sbor::*;
+
+use crate::crypto::*;
+use crate::engine::{api::*, call_engine};
+
+/// Represents a signature scheme that Radix Engine can verify natively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TypeId, Encode, Decode, Describe)]
+pub enum SignatureScheme {
+    /// ECDSA over secp256k1, with a SEC1-encoded public key and a 64-byte `r || s` signature
+    EcdsaSecp256k1,
+
+    /// ECDSA over secp256r1, with a SEC1-encoded public key and a 64-byte `r || s` signature
+    EcdsaSecp256r1,
+
+    /// Ed25519, with a 32-byte public key and a 64-byte signature
+    Ed25519,
+}
+
+/// Represents a hash function that Radix Engine can compute natively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TypeId, Encode, Decode, Describe)]
+pub enum HashFunction {
+    Keccak256,
+    Blake2b256,
+}
+
+/// Computes the Keccak-256 digest of a message, as used by Ethereum.
+///
+/// The digest is computed by Radix Engine, at a fixed cost per call.
+pub fn keccak256<T: AsRef<[u8]>>(data: T) -> Hash {
+    compute_hash(HashFunction::Keccak256, data.as_ref())
+}
+
+/// Computes the BLAKE2b digest of a message, with a 256-bit output.
+///
+/// The digest is computed by Radix Engine, at a fixed cost per call.
+pub fn blake2b<T: AsRef<[u8]>>(data: T) -> Hash {
+    compute_hash(HashFunction::Blake2b256, data.as_ref())
+}
+
+/// Verifies an ECDSA secp256k1 signature of the SHA-256 digest of a message.
+///
+/// The signature is verified by Radix Engine, at a fixed cost per call. Malformed public
+/// keys or signatures are reported as invalid.
+pub fn verify_ecdsa_secp256k1(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    verify_signature(
+        SignatureScheme::EcdsaSecp256k1,
+        public_key,
+        message,
+        signature,
+    )
+}
+
+/// Verifies an ECDSA secp256r1 signature of the SHA-256 digest of a message.
+///
+/// The signature is verified by Radix Engine, at a fixed cost per call. Malformed public
+/// keys or signatures are reported as invalid.
+pub fn verify_ecdsa_secp256r1(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    verify_signature(
+        SignatureScheme::EcdsaSecp256r1,
+        public_key,
+        message,
+        signature,
+    )
+}
+
+/// Verifies an Ed25519 signature of a message.
+///
+/// The signature is verified by Radix Engine, at a fixed cost per call. Malformed public
+/// keys or signatures are reported as invalid.
+pub fn verify_ed25519(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    verify_signature(SignatureScheme::Ed25519, public_key, message, signature)
+}
+
+fn compute_hash(hash_function: HashFunction, data: &[u8]) -> Hash {
+    let input = ComputeHashInput {
+        hash_function,
+        data: data.to_vec(),
+    };
+    let output: ComputeHashOutput = call_engine(COMPUTE_HASH, input);
+
+    output.hash
+}
+
+fn verify_signature(
+    scheme: SignatureScheme,
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    let input = VerifySignatureInput {
+        scheme,
+        public_key: public_key.to_vec(),
+        message: message.to_vec(),
+        signature: signature.to_vec(),
+    };
+    let output: VerifySignatureOutput = call_engine(VERIFY_SIGNATURE, input);
+
+    output.is_valid
+}
@@ -3,6 +3,7 @@ use sbor::*;
 use scrypto::prelude::{AccessRule, AccessRules};
 
 use crate::engine::types::*;
+use crate::rust::collections::BTreeMap;
 use crate::rust::string::String;
 use crate::rust::vec::Vec;
 
@@ -20,6 +21,8 @@ pub const GET_COMPONENT_INFO: u32 = 0x11;
 pub const GET_COMPONENT_STATE: u32 = 0x12;
 /// Update component state
 pub const PUT_COMPONENT_STATE: u32 = 0x13;
+/// Create a component with metadata
+pub const CREATE_COMPONENT_WITH_METADATA: u32 = 0x14;
 
 /// Create a lazy map
 pub const CREATE_LAZY_MAP: u32 = 0x20;
@@ -33,6 +36,11 @@ pub const CREATE_EMPTY_VAULT: u32 = 0x40;
 
 pub const INVOKE_SNODE: u32 = 0x70;
 
+/// Verify a signature natively
+pub const VERIFY_SIGNATURE: u32 = 0x80;
+/// Compute a hash natively
+pub const COMPUTE_HASH: u32 = 0x81;
+
 /// Log a message
 pub const EMIT_LOG: u32 = 0xf0;
 /// Generate a UUID
@@ -48,6 +56,8 @@ pub const GET_ACTOR: u32 = 0xf5;
 
 /// Check that an access rule is satisfied
 pub const CHECK_ACCESS_RULE: u32 = 0xf6;
+/// Retrieve current time
+pub const GET_CURRENT_TIME: u32 = 0xf7;
 
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct InvokeSNodeInput {
@@ -72,6 +82,15 @@ pub struct CreateComponentInput {
     pub access_rules_list: Vec<AccessRules>,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct CreateComponentWithMetadataInput {
+    pub blueprint_name: String,
+    pub state: Vec<u8>,
+    pub access_rules_list: Vec<AccessRules>,
+    pub metadata: BTreeMap<String, String>,
+    pub metadata_access_rule: AccessRule,
+}
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct CreateComponentOutput {
     pub component_address: ComponentAddress,
@@ -151,6 +170,34 @@ pub struct CreateEmptyVaultOutput {
     pub vault_id: VaultId,
 }
 
+//==========
+// crypto
+//==========
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct VerifySignatureInput {
+    pub scheme: SignatureScheme,
+    pub public_key: Vec<u8>,
+    pub message: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct VerifySignatureOutput {
+    pub is_valid: bool,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct ComputeHashInput {
+    pub hash_function: HashFunction,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct ComputeHashOutput {
+    pub hash: Hash,
+}
+
 //=======
 // others
 //=======
@@ -181,6 +228,14 @@ pub struct GetCurrentEpochOutput {
     pub current_epoch: u64,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetCurrentTimeInput {}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetCurrentTimeOutput {
+    pub current_time: u64,
+}
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct GetTransactionHashInput {}
 
@@ -9,10 +9,13 @@ pub use crate::crypto::EcdsaPrivateKey;
 pub use crate::crypto::EcdsaPublicKey;
 pub use crate::crypto::EcdsaSignature;
 pub use crate::crypto::Hash;
+pub use crate::crypto::HashFunction;
+pub use crate::crypto::SignatureScheme;
 pub use crate::math::Decimal;
 pub use crate::resource::MintParams;
 pub use crate::resource::NonFungibleAddress;
 pub use crate::resource::NonFungibleId;
+pub use crate::resource::NonFungibleIdType;
 pub use crate::resource::ResourceAddress;
 pub use crate::resource::ResourceType;
 
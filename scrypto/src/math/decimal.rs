@@ -1,10 +1,12 @@
 use core::ops::*;
 use num_bigint::BigInt;
-use num_traits::Signed;
+use num_traits::{Signed, ToPrimitive, Zero};
 use sbor::rust::iter;
 use sbor::*;
 
+use super::precise_decimal::div_rounded;
 use crate::misc::*;
+use crate::rust::cmp;
 use crate::rust::convert::TryFrom;
 use crate::rust::fmt;
 use crate::rust::str::FromStr;
@@ -23,7 +25,7 @@ use crate::types::*;
 pub struct Decimal(pub i128);
 
 /// Defines how rounding should be done.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RoundingMode {
     /// Rounds towards positive infinity, e.g. `3.1 -> 4`, `-3.1 -> -3`.
     TowardsPositiveInfinity,
@@ -182,6 +184,297 @@ impl Decimal {
     }
 }
 
+//============
+// arithmetic
+//============
+
+/// The scale used for the intermediate results of `powi`, `ln` and `exp`, which are rounded
+/// to `Decimal::SCALE` only once.
+const WORKING_SCALE: u32 = 64;
+
+impl Decimal {
+    /// Adds two decimals, returning `None` if overflow.
+    pub fn checked_add<T: Into<Decimal>>(&self, other: T) -> Option<Self> {
+        self.0.checked_add(other.into().0).map(Self)
+    }
+
+    /// Subtracts two decimals, returning `None` if overflow.
+    pub fn checked_sub<T: Into<Decimal>>(&self, other: T) -> Option<Self> {
+        self.0.checked_sub(other.into().0).map(Self)
+    }
+
+    /// Multiplies two decimals, returning `None` if overflow.
+    pub fn checked_mul<T: Into<Decimal>>(&self, other: T) -> Option<Self> {
+        let a = BigInt::from(self.0);
+        let b = BigInt::from(other.into().0);
+        try_big_int_to_decimal(&(a * b / Self::ONE.0))
+    }
+
+    /// Divides two decimals, returning `None` if the divisor is zero or overflow.
+    pub fn checked_div<T: Into<Decimal>>(&self, other: T) -> Option<Self> {
+        let b = other.into().0;
+        if b == 0 {
+            return None;
+        }
+        let a = BigInt::from(self.0);
+        try_big_int_to_decimal(&(a * Self::ONE.0 / b))
+    }
+
+    /// Adds two decimals, saturating at the numeric bounds instead of overflowing.
+    pub fn saturating_add<T: Into<Decimal>>(&self, other: T) -> Self {
+        Self(self.0.saturating_add(other.into().0))
+    }
+
+    /// Subtracts two decimals, saturating at the numeric bounds instead of overflowing.
+    pub fn saturating_sub<T: Into<Decimal>>(&self, other: T) -> Self {
+        Self(self.0.saturating_sub(other.into().0))
+    }
+
+    /// Multiplies two decimals, saturating at the numeric bounds instead of overflowing.
+    pub fn saturating_mul<T: Into<Decimal>>(&self, other: T) -> Self {
+        let other = other.into();
+        self.checked_mul(other).unwrap_or_else(|| {
+            if self.is_negative() == other.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        })
+    }
+
+    /// Divides two decimals, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// Panics if the divisor is zero.
+    pub fn saturating_div<T: Into<Decimal>>(&self, other: T) -> Self {
+        let other = other.into();
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        self.checked_div(other).unwrap_or_else(|| {
+            if self.is_negative() == other.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        })
+    }
+
+    /// Raises this number to an integer power.
+    ///
+    /// The result is truncated towards zero, like the result of `*`. Panics if overflow or if
+    /// zero is raised to a negative power.
+    pub fn powi(&self, exp: i64) -> Self {
+        if self.is_zero() && exp < 0 {
+            panic!("Division by zero");
+        }
+        self.checked_powi(exp).expect("Overflow")
+    }
+
+    /// Raises this number to an integer power, returning `None` if overflow or if zero is
+    /// raised to a negative power.
+    ///
+    /// The result is truncated towards zero, like the result of `*`.
+    pub fn checked_powi(&self, exp: i64) -> Option<Self> {
+        let one = working_one();
+        let base = to_working(self);
+        let power = if exp >= 0 {
+            working_pow(&base, exp.unsigned_abs())?
+        } else if self.is_zero() {
+            return None;
+        } else {
+            match working_pow(&base, exp.unsigned_abs()) {
+                Some(power) => {
+                    let power = &one * &one / power;
+                    if power.is_zero() {
+                        // The true value is tiny but never zero.
+                        non_zero_sign(self, exp)
+                    } else {
+                        power
+                    }
+                }
+                None => non_zero_sign(self, exp),
+            }
+        };
+        from_working(&power, RoundingMode::TowardsZero)
+    }
+
+    /// Returns the square root of this number, rounded with the given mode.
+    ///
+    /// Returns `None` if this number is negative.
+    pub fn sqrt(&self, mode: RoundingMode) -> Option<Self> {
+        self.nth_root(2, mode)
+    }
+
+    /// Returns the `n`-th root of this number, rounded with the given mode.
+    ///
+    /// Returns `None` if `n` is zero, or if this number is negative and `n` is even.
+    pub fn nth_root(&self, n: u32, mode: RoundingMode) -> Option<Self> {
+        if n == 0 || (self.is_negative() && n % 2 == 0) {
+            return None;
+        }
+
+        // root(m / 10^18) = root(m * 10^(18 * (n - 1))) / 10^18
+        let target = BigInt::from(self.0) * BigInt::from(10).pow(Self::SCALE * (n - 1));
+        let root = target.nth_root(n);
+        let root = if num_traits::pow(root.clone(), n as usize) == target {
+            root
+        } else {
+            let away_from_zero = if target.is_negative() {
+                &root - 1
+            } else {
+                &root + 1
+            };
+            // Halfway can't happen, as `(2 * root + 1)^n` is odd and `2^n * target` is even.
+            let closer_to_away = num_traits::pow(root.abs() * 2 + 1, n as usize)
+                < target.abs() * BigInt::from(2).pow(n);
+            let round_away = match mode {
+                RoundingMode::TowardsPositiveInfinity => target.is_positive(),
+                RoundingMode::TowardsNegativeInfinity => target.is_negative(),
+                RoundingMode::TowardsZero => false,
+                RoundingMode::AwayFromZero => true,
+                RoundingMode::TowardsNearestAndHalfTowardsZero
+                | RoundingMode::TowardsNearestAndHalfAwayFromZero => closer_to_away,
+            };
+            if round_away {
+                away_from_zero
+            } else {
+                root
+            }
+        };
+        try_big_int_to_decimal(&root)
+    }
+
+    /// Returns the natural logarithm of this number, rounded with the given mode.
+    ///
+    /// Returns `None` if this number is not positive.
+    pub fn ln(&self, mode: RoundingMode) -> Option<Self> {
+        if !self.is_positive() {
+            return None;
+        }
+
+        // ln(x) = k * ln(2) + ln(y), where x = y * 2^k and 0.75 <= y < 1.5
+        let one = working_one();
+        let low = &one * 3 / 4;
+        let high = &one * 3 / 2;
+        let mut y = to_working(self);
+        let mut k = 0i64;
+        while y >= high {
+            y /= 2;
+            k += 1;
+        }
+        while y < low {
+            y *= 2;
+            k -= 1;
+        }
+
+        let ln2 = working_ln(&(&one * 2));
+        let result = working_ln(&y) + ln2 * k;
+        from_working(&result, mode)
+    }
+
+    /// Returns `e` raised to the power of this number, rounded with the given mode.
+    ///
+    /// Returns `None` if overflow.
+    pub fn exp(&self, mode: RoundingMode) -> Option<Self> {
+        // e^47 is beyond `Decimal::MAX`
+        if *self > Self::from(47) {
+            return None;
+        }
+
+        // Below e^-64, every rounding mode gives the same result, so clamp to keep `k` small.
+        let x = to_working(cmp::max(self, &Self::from(-64)));
+
+        // e^x = 2^k * e^r, where x = k * ln(2) + r and |r| <= ln(2) / 2
+        let one = working_one();
+        let ln2 = working_ln(&(&one * 2));
+        let k = div_rounded(&x, &ln2, RoundingMode::TowardsNearestAndHalfAwayFromZero);
+        let r = x - &ln2 * &k;
+
+        let mut result = one.clone();
+        let mut term = one.clone();
+        for i in 1.. {
+            term = term * &r / (&one * i);
+            if term.is_zero() {
+                break;
+            }
+            result += &term;
+        }
+
+        let k = k.to_i64().unwrap();
+        if k >= 0 {
+            result <<= k as usize;
+        } else {
+            result >>= k.unsigned_abs() as usize;
+        }
+        from_working(&result, mode)
+    }
+}
+
+fn working_one() -> BigInt {
+    BigInt::from(10).pow(WORKING_SCALE)
+}
+
+fn to_working(d: &Decimal) -> BigInt {
+    BigInt::from(d.0) * BigInt::from(10).pow(WORKING_SCALE - Decimal::SCALE)
+}
+
+fn from_working(v: &BigInt, mode: RoundingMode) -> Option<Decimal> {
+    let divisor = BigInt::from(10).pow(WORKING_SCALE - Decimal::SCALE);
+    try_big_int_to_decimal(&div_rounded(v, &divisor, mode))
+}
+
+/// Returns the smallest working unit with the sign of `base^exp`.
+fn non_zero_sign(base: &Decimal, exp: i64) -> BigInt {
+    if base.is_negative() && exp % 2 != 0 {
+        BigInt::from(-1)
+    } else {
+        BigInt::from(1)
+    }
+}
+
+/// Raises a working-scale number to a power, by squaring, returning `None` once the result is
+/// known to be out of the range of `Decimal`.
+fn working_pow(base: &BigInt, mut exp: u64) -> Option<BigInt> {
+    let one = working_one();
+    let bound = BigInt::from(i128::MAX) * BigInt::from(10).pow(WORKING_SCALE - Decimal::SCALE);
+    let mut result = one.clone();
+    let mut base = base.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * &base / &one;
+            if result.abs() > bound {
+                return None;
+            }
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = &base * &base / &one;
+            if base.abs() > bound {
+                return None;
+            }
+        }
+    }
+    Some(result)
+}
+
+/// Computes `ln(y)` for a working-scale `y` close to 1, as `2 * atanh((y - 1) / (y + 1))`.
+fn working_ln(y: &BigInt) -> BigInt {
+    let one = working_one();
+    let z = (y - &one) * &one / (y + &one);
+    let z2 = &z * &z / &one;
+    let mut sum = BigInt::zero();
+    let mut power = z;
+    for i in (1u32..).step_by(2) {
+        let term = &power / i;
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        power = power * &z2 / &one;
+    }
+    sum * 2
+}
+
 macro_rules! from_int {
     ($type:ident) => {
         impl From<$type> for Decimal {
@@ -277,9 +570,13 @@ impl<T: Into<Decimal>> Sub<T> for Decimal {
 }
 
 fn big_int_to_decimal(v: BigInt) -> Decimal {
+    try_big_int_to_decimal(&v).expect("Overflow")
+}
+
+fn try_big_int_to_decimal(v: &BigInt) -> Option<Decimal> {
     let bytes = v.to_signed_bytes_le();
     if bytes.len() > 16 {
-        panic!("Overflow");
+        None
     } else {
         let mut buf = if v.is_negative() {
             [255u8; 16]
@@ -287,7 +584,7 @@ fn big_int_to_decimal(v: BigInt) -> Decimal {
             [0u8; 16]
         };
         buf[..bytes.len()].copy_from_slice(&bytes);
-        Decimal(i128::from_le_bytes(buf))
+        Some(Decimal(i128::from_le_bytes(buf)))
     }
 }
 
@@ -811,4 +1108,155 @@ mod tests {
         assert_eq!(sum1, dec!("6"));
         assert_eq!(sum2, dec!("6"));
     }
+
+    #[test]
+    fn test_checked_and_saturating_ops() {
+        assert_eq!(dec!("1.5").checked_add(2), Some(dec!("3.5")));
+        assert_eq!(Decimal::MAX.checked_add(1), None);
+        assert_eq!(Decimal::MIN.checked_sub(1), None);
+        assert_eq!(dec!("1.5").checked_mul(dec!("-2")), Some(dec!("-3")));
+        assert_eq!(Decimal::MAX.checked_mul(2), None);
+        assert_eq!(dec!("1").checked_div(3), Some(dec!("0.333333333333333333")));
+        assert_eq!(dec!("1").checked_div(0), None);
+        assert_eq!(Decimal::MAX.saturating_add(1), Decimal::MAX);
+        assert_eq!(Decimal::MIN.saturating_sub(1), Decimal::MIN);
+        assert_eq!(Decimal::MAX.saturating_mul(-2), Decimal::MIN);
+        assert_eq!(Decimal::MIN.saturating_div(dec!("0.5")), Decimal::MIN);
+        assert_eq!(dec!("6").saturating_div(4), dec!("1.5"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_saturating_div_by_zero() {
+        Decimal::ONE.saturating_div(0);
+    }
+
+    #[test]
+    fn test_powi() {
+        assert_eq!(dec!("2").powi(10), dec!("1024"));
+        assert_eq!(dec!("-2").powi(3), dec!("-8"));
+        assert_eq!(dec!("1.5").powi(0), dec!("1"));
+        assert_eq!(dec!("1.5").powi(-2), dec!("0.444444444444444444"));
+        assert_eq!(dec!("0.1").powi(18), dec!("0.000000000000000001"));
+        assert_eq!(dec!("0.1").powi(19), Decimal::zero());
+        assert_eq!(dec!("10").checked_powi(21), None);
+        assert_eq!(dec!("10").checked_powi(-100), Some(Decimal::zero()));
+        assert_eq!(Decimal::zero().checked_powi(-1), None);
+        assert_eq!(
+            dec!("1.01").powi(4000),
+            dec!("192972369947315104.093444294094066122")
+        );
+        assert_eq!(
+            dec!("0.99").powi(-3000),
+            dec!("12428428189819.120695407677861711")
+        );
+        assert_eq!(
+            dec!("1.000000001").powi(1_000_000_000),
+            dec!("2.718281827099904322")
+        );
+    }
+
+    #[test]
+    fn test_sqrt_and_nth_root() {
+        assert_eq!(dec!("4").sqrt(RoundingMode::TowardsZero), Some(dec!("2")));
+        assert_eq!(
+            dec!("2").sqrt(RoundingMode::TowardsZero),
+            Some(dec!("1.414213562373095048"))
+        );
+        assert_eq!(
+            dec!("2").sqrt(RoundingMode::AwayFromZero),
+            Some(dec!("1.414213562373095049"))
+        );
+        assert_eq!(
+            dec!("2").sqrt(RoundingMode::TowardsNearestAndHalfTowardsZero),
+            Some(dec!("1.414213562373095049"))
+        );
+        assert_eq!(dec!("-1").sqrt(RoundingMode::TowardsZero), None);
+        assert_eq!(
+            dec!("27").nth_root(3, RoundingMode::TowardsZero),
+            Some(dec!("3"))
+        );
+        assert_eq!(
+            dec!("-8").nth_root(3, RoundingMode::TowardsZero),
+            Some(dec!("-2"))
+        );
+        assert_eq!(
+            dec!("-2").nth_root(3, RoundingMode::TowardsNegativeInfinity),
+            Some(dec!("-1.259921049894873165"))
+        );
+        assert_eq!(dec!("2").nth_root(0, RoundingMode::TowardsZero), None);
+    }
+
+    #[test]
+    fn test_ln() {
+        assert_eq!(
+            dec!("2").ln(RoundingMode::TowardsZero),
+            Some(dec!("0.693147180559945309"))
+        );
+        assert_eq!(Decimal::ONE.ln(RoundingMode::TowardsZero), Some(dec!("0")));
+        assert_eq!(
+            dec!("0.5").ln(RoundingMode::TowardsZero),
+            Some(dec!("-0.693147180559945309"))
+        );
+        assert_eq!(
+            dec!("1000").ln(RoundingMode::TowardsNearestAndHalfAwayFromZero),
+            Some(dec!("6.907755278982137052"))
+        );
+        assert_eq!(Decimal::zero().ln(RoundingMode::TowardsZero), None);
+        assert_eq!(dec!("-1").ln(RoundingMode::TowardsZero), None);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(
+            Decimal::ONE.exp(RoundingMode::TowardsZero),
+            Some(dec!("2.718281828459045235"))
+        );
+        assert_eq!(
+            Decimal::zero().exp(RoundingMode::TowardsZero),
+            Some(dec!("1"))
+        );
+        assert_eq!(
+            dec!("-1").exp(RoundingMode::TowardsNearestAndHalfAwayFromZero),
+            Some(dec!("0.367879441171442322"))
+        );
+        assert_eq!(
+            dec!("-50").exp(RoundingMode::TowardsZero),
+            Some(Decimal::zero())
+        );
+        assert_eq!(
+            dec!("-50").exp(RoundingMode::AwayFromZero),
+            Some(dec!("0.000000000000000001"))
+        );
+        assert_eq!(
+            dec!("-40").exp(RoundingMode::TowardsZero),
+            Some(dec!("0.000000000000000004"))
+        );
+        assert_eq!(
+            Decimal::MIN.exp(RoundingMode::AwayFromZero),
+            Some(dec!("0.000000000000000001"))
+        );
+        assert_eq!(dec!("100").exp(RoundingMode::TowardsZero), None);
+    }
+
+    #[test]
+    fn test_exp_large() {
+        assert_eq!(
+            dec!("10.5").exp(RoundingMode::TowardsZero),
+            Some(dec!("36315.502674246637738912"))
+        );
+        assert_eq!(
+            dec!("40").exp(RoundingMode::TowardsZero),
+            Some(dec!("235385266837019985.407899910749034804"))
+        );
+        assert_eq!(
+            dec!("46").exp(RoundingMode::TowardsZero),
+            Some(dec!("94961194206024488745.133649117118323101"))
+        );
+        assert_eq!(
+            dec!("46.5").exp(RoundingMode::AwayFromZero),
+            Some(dec!("156564540778558341656.976215902554456242"))
+        );
+        assert_eq!(dec!("46.6").exp(RoundingMode::TowardsZero), None);
+    }
 }
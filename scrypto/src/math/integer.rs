@@ -0,0 +1,434 @@
+use core::ops::*;
+use num_bigint::{BigInt, Sign};
+use num_traits::{Signed, Zero};
+use sbor::*;
+
+use crate::rust::cmp::Ordering;
+use crate::rust::convert::TryFrom;
+use crate::rust::fmt;
+use crate::rust::str::FromStr;
+use crate::rust::string::String;
+use crate::rust::string::ToString;
+use crate::rust::vec::Vec;
+use crate::types::*;
+
+/// `I256` represents a 256 bit signed integer, in two's complement.
+///
+/// Unless otherwise specified, all operations will panic if underflow/overflow.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct I256(pub [u8; 32]);
+
+/// `U256` represents a 256 bit unsigned integer.
+///
+/// Unless otherwise specified, all operations will panic if underflow/overflow.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct U256(pub [u8; 32]);
+
+impl I256 {
+    /// The min value of `I256`, `-2^255`.
+    pub const MIN: Self = {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 0x80;
+        Self(bytes)
+    };
+
+    /// The max value of `I256`, `2^255 - 1`.
+    pub const MAX: Self = {
+        let mut bytes = [0xffu8; 32];
+        bytes[31] = 0x7f;
+        Self(bytes)
+    };
+
+    pub const ZERO: Self = Self::from_i128(0);
+
+    pub const ONE: Self = Self::from_i128(1);
+
+    /// Converts an `i128` into `I256`, in a const context.
+    pub const fn from_i128(value: i128) -> Self {
+        let mut bytes = if value < 0 { [0xffu8; 32] } else { [0u8; 32] };
+        let low = value.to_le_bytes();
+        let mut i = 0;
+        while i < 16 {
+            bytes[i] = low[i];
+            i += 1;
+        }
+        Self(bytes)
+    }
+
+    /// Whether this number is negative.
+    pub fn is_negative(&self) -> bool {
+        self.0[31] & 0x80 != 0
+    }
+
+    /// Whether this number is positive.
+    pub fn is_positive(&self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> Self {
+        Self::from_big_int(self.to_big_int().abs())
+    }
+
+    pub(crate) fn to_big_int(self) -> BigInt {
+        BigInt::from_signed_bytes_le(&self.0)
+    }
+
+    /// Converts a `BigInt` into `I256`, or returns `None` if it's out of range.
+    pub(crate) fn try_from_big_int(value: &BigInt) -> Option<Self> {
+        let bytes = value.to_signed_bytes_le();
+        if bytes.len() > 32 {
+            None
+        } else {
+            let mut buf = if value.is_negative() {
+                [0xffu8; 32]
+            } else {
+                [0u8; 32]
+            };
+            buf[..bytes.len()].copy_from_slice(&bytes);
+            Some(Self(buf))
+        }
+    }
+}
+
+impl U256 {
+    /// The min value of `U256`, `0`.
+    pub const MIN: Self = Self([0u8; 32]);
+
+    /// The max value of `U256`, `2^256 - 1`.
+    pub const MAX: Self = Self([0xffu8; 32]);
+
+    pub const ZERO: Self = Self::from_u128(0);
+
+    pub const ONE: Self = Self::from_u128(1);
+
+    /// Converts a `u128` into `U256`, in a const context.
+    pub const fn from_u128(value: u128) -> Self {
+        let mut bytes = [0u8; 32];
+        let low = value.to_le_bytes();
+        let mut i = 0;
+        while i < 16 {
+            bytes[i] = low[i];
+            i += 1;
+        }
+        Self(bytes)
+    }
+
+    pub(crate) fn to_big_int(self) -> BigInt {
+        BigInt::from_bytes_le(Sign::Plus, &self.0)
+    }
+
+    /// Converts a `BigInt` into `U256`, or returns `None` if it's out of range.
+    pub(crate) fn try_from_big_int(value: &BigInt) -> Option<Self> {
+        if value.is_negative() {
+            return None;
+        }
+        let (_, bytes) = value.to_bytes_le();
+        if bytes.len() > 32 {
+            None
+        } else {
+            let mut buf = [0u8; 32];
+            buf[..bytes.len()].copy_from_slice(&bytes);
+            Some(Self(buf))
+        }
+    }
+}
+
+macro_rules! integer_type {
+    ($t:ident, $scrypto_type:expr) => {
+        impl $t {
+            /// Whether this number is zero.
+            pub fn is_zero(&self) -> bool {
+                self.0.iter().all(|b| *b == 0)
+            }
+
+            /// Raises this number to the power of `exp`.
+            pub fn pow(&self, exp: u32) -> Self {
+                Self::from_big_int(num_traits::pow(self.to_big_int(), exp as usize))
+            }
+
+            pub(crate) fn from_big_int(value: BigInt) -> Self {
+                Self::try_from_big_int(&value).expect("Overflow")
+            }
+
+            pub fn to_vec(&self) -> Vec<u8> {
+                self.0.to_vec()
+            }
+        }
+
+        impl Default for $t {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl PartialOrd for $t {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $t {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.to_big_int().cmp(&other.to_big_int())
+            }
+        }
+
+        impl<T: Into<$t>> Add<T> for $t {
+            type Output = $t;
+
+            fn add(self, other: T) -> Self::Output {
+                Self::from_big_int(self.to_big_int() + other.into().to_big_int())
+            }
+        }
+
+        impl<T: Into<$t>> Sub<T> for $t {
+            type Output = $t;
+
+            fn sub(self, other: T) -> Self::Output {
+                Self::from_big_int(self.to_big_int() - other.into().to_big_int())
+            }
+        }
+
+        impl<T: Into<$t>> Mul<T> for $t {
+            type Output = $t;
+
+            fn mul(self, other: T) -> Self::Output {
+                Self::from_big_int(self.to_big_int() * other.into().to_big_int())
+            }
+        }
+
+        impl<T: Into<$t>> Div<T> for $t {
+            type Output = $t;
+
+            fn div(self, other: T) -> Self::Output {
+                let divisor = other.into().to_big_int();
+                if divisor.is_zero() {
+                    panic!("Division by zero");
+                }
+                Self::from_big_int(self.to_big_int() / divisor)
+            }
+        }
+
+        impl<T: Into<$t>> Rem<T> for $t {
+            type Output = $t;
+
+            fn rem(self, other: T) -> Self::Output {
+                let divisor = other.into().to_big_int();
+                if divisor.is_zero() {
+                    panic!("Division by zero");
+                }
+                Self::from_big_int(self.to_big_int() % divisor)
+            }
+        }
+
+        impl<T: Into<$t>> AddAssign<T> for $t {
+            fn add_assign(&mut self, other: T) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Into<$t>> SubAssign<T> for $t {
+            fn sub_assign(&mut self, other: T) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Into<$t>> MulAssign<T> for $t {
+            fn mul_assign(&mut self, other: T) {
+                *self = *self * other;
+            }
+        }
+
+        impl<T: Into<$t>> DivAssign<T> for $t {
+            fn div_assign(&mut self, other: T) {
+                *self = *self / other;
+            }
+        }
+
+        impl<T: Into<$t>> RemAssign<T> for $t {
+            fn rem_assign(&mut self, other: T) {
+                *self = *self % other;
+            }
+        }
+
+        impl TryFrom<&[u8]> for $t {
+            type Error = ParseIntegerError;
+
+            fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+                if slice.len() == 32 {
+                    let mut bytes = [0u8; 32];
+                    bytes.copy_from_slice(slice);
+                    Ok(Self(bytes))
+                } else {
+                    Err(ParseIntegerError::InvalidLength(slice.len()))
+                }
+            }
+        }
+
+        scrypto_type!($t, $scrypto_type, Vec::new());
+
+        impl FromStr for $t {
+            type Err = ParseIntegerError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let value = BigInt::from_str(s)
+                    .map_err(|_| ParseIntegerError::InvalidInteger(s.to_string()))?;
+                Self::try_from_big_int(&value).ok_or(ParseIntegerError::Overflow)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.to_big_int())
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self)
+            }
+        }
+    };
+}
+
+integer_type!(I256, ScryptoType::I256);
+integer_type!(U256, ScryptoType::U256);
+
+impl Neg for I256 {
+    type Output = I256;
+
+    fn neg(self) -> Self::Output {
+        Self::from_big_int(-self.to_big_int())
+    }
+}
+
+macro_rules! from_int {
+    ($t:ident, $($type:ident),*) => {
+        $(
+            impl From<$type> for $t {
+                fn from(val: $type) -> Self {
+                    Self::from_big_int(BigInt::from(val))
+                }
+            }
+        )*
+    };
+}
+from_int!(I256, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+from_int!(U256, u8, u16, u32, u64, u128, usize);
+
+impl TryFrom<U256> for I256 {
+    type Error = ParseIntegerError;
+
+    fn try_from(val: U256) -> Result<Self, Self::Error> {
+        Self::try_from_big_int(&val.to_big_int()).ok_or(ParseIntegerError::Overflow)
+    }
+}
+
+impl TryFrom<I256> for U256 {
+    type Error = ParseIntegerError;
+
+    fn try_from(val: I256) -> Result<Self, Self::Error> {
+        Self::try_from_big_int(&val.to_big_int()).ok_or(ParseIntegerError::Overflow)
+    }
+}
+
+//========
+// error
+//========
+
+/// Represents an error when parsing a 256 bit integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseIntegerError {
+    InvalidInteger(String),
+    InvalidLength(usize),
+    Overflow,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl std::error::Error for ParseIntegerError {}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Display for ParseIntegerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::*;
+
+    #[test]
+    fn test_format_and_parse() {
+        assert_eq!(
+            I256::MAX.to_string(),
+            "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+        );
+        assert_eq!(
+            I256::MIN.to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+        assert_eq!(
+            U256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(I256::from_str("-42").unwrap(), I256::from(-42));
+        assert_eq!(I256::from_str(&I256::MIN.to_string()).unwrap(), I256::MIN);
+        assert_eq!(U256::from_str(&U256::MAX.to_string()).unwrap(), U256::MAX);
+        assert_eq!(U256::from_str("-1"), Err(ParseIntegerError::Overflow));
+        assert_eq!(
+            I256::from_str("1x"),
+            Err(ParseIntegerError::InvalidInteger("1x".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = U256::from(u128::MAX);
+        assert_eq!(
+            (a * a).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!(I256::from(7) / 2, I256::from(3));
+        assert_eq!(I256::from(-7) / 2, I256::from(-3));
+        assert_eq!(I256::from(-7) % 2, I256::from(-1));
+        assert_eq!(-I256::from(5) + 3, I256::from(-2));
+        assert_eq!(
+            U256::from(2u32).pow(255) - 1u32,
+            U256::try_from(I256::MAX).unwrap()
+        );
+        assert!(I256::from(-1) < I256::ZERO);
+        assert!(U256::MAX > U256::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_add_overflow() {
+        let _ = I256::MAX + 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_sub_overflow() {
+        let _ = U256::ZERO - 1u32;
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_neg_overflow() {
+        let _ = -I256::MIN;
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let value = I256::from(-12345);
+        let bytes = scrypto_encode(&value);
+        assert_eq!(scrypto_decode::<I256>(&bytes).unwrap(), value);
+        assert_eq!(
+            U256::try_from(I256::from(-1)),
+            Err(ParseIntegerError::Overflow)
+        );
+    }
+}
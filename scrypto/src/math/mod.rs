@@ -1,3 +1,7 @@
 mod decimal;
+mod integer;
+mod precise_decimal;
 
 pub use decimal::*;
+pub use integer::*;
+pub use precise_decimal::*;
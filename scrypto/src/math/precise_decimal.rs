@@ -0,0 +1,543 @@
+use core::ops::*;
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+use sbor::rust::iter;
+use sbor::*;
+
+use crate::math::*;
+use crate::rust::convert::TryFrom;
+use crate::rust::fmt;
+use crate::rust::format;
+use crate::rust::str::FromStr;
+use crate::rust::string::String;
+use crate::rust::string::ToString;
+use crate::rust::vec::Vec;
+use crate::types::*;
+
+/// `PreciseDecimal` represents a 256 bit representation of a fixed-scale decimal number.
+///
+/// The finite set of values are of the form `m / 10^36`, where `m` is
+/// an integer such that `-2^255 <= m < 2^255`.
+///
+/// Unless otherwise specified, all operations will panic if underflow/overflow.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PreciseDecimal(pub I256);
+
+impl Default for PreciseDecimal {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl iter::Sum for PreciseDecimal {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut sum = PreciseDecimal::zero();
+        iter.for_each(|d| sum += d);
+        sum
+    }
+}
+
+impl PreciseDecimal {
+    /// The min value of `PreciseDecimal`.
+    pub const MIN: Self = Self(I256::MIN);
+
+    /// The max value of `PreciseDecimal`.
+    pub const MAX: Self = Self(I256::MAX);
+
+    /// The fixed scale used by `PreciseDecimal`.
+    pub const SCALE: u32 = 36;
+
+    pub const ZERO: Self = Self(I256::ZERO);
+
+    pub const ONE: Self = Self(I256::from_i128(10i128.pow(Self::SCALE)));
+
+    /// Returns `PreciseDecimal` of 0.
+    pub fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Returns `PreciseDecimal` of 1.
+    pub fn one() -> Self {
+        Self::ONE
+    }
+
+    /// Whether this decimal is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Whether this decimal is positive.
+    pub fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+
+    /// Whether this decimal is negative.
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns the largest integer that is equal to or less than this number.
+    pub fn floor(&self) -> Self {
+        self.round(0, RoundingMode::TowardsNegativeInfinity)
+    }
+
+    /// Returns the smallest integer that is equal to or greater than this number.
+    pub fn ceiling(&self) -> Self {
+        self.round(0, RoundingMode::TowardsPositiveInfinity)
+    }
+
+    pub fn round(&self, decimal_places: u8, mode: RoundingMode) -> Self {
+        assert!(decimal_places as u32 <= Self::SCALE);
+
+        let divisor = BigInt::from(10).pow(Self::SCALE - decimal_places as u32);
+        let rounded = div_rounded(&self.0.to_big_int(), &divisor, mode) * divisor;
+        Self(I256::from_big_int(rounded))
+    }
+
+    /// Converts this number into a `Decimal`, rounding the digits beyond the scale of
+    /// `Decimal` with the given mode.
+    ///
+    /// Panics if the result is out of the range of `Decimal`.
+    pub fn to_decimal(&self, mode: RoundingMode) -> Decimal {
+        let divisor = BigInt::from(10).pow(Self::SCALE - Decimal::SCALE);
+        let value = div_rounded(&self.0.to_big_int(), &divisor, mode);
+        i128::try_from(value).map(Decimal).expect("Overflow")
+    }
+
+    fn from_big_int(value: BigInt) -> Self {
+        Self(I256::from_big_int(value))
+    }
+}
+
+/// Divides `value` by a positive `divisor`, rounding the quotient with the given mode.
+pub(crate) fn div_rounded(value: &BigInt, divisor: &BigInt, mode: RoundingMode) -> BigInt {
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    // The quotient is truncated, so the remainder has the same sign as the value.
+    let away_from_zero = if value.is_negative() {
+        quotient.clone() - 1
+    } else {
+        quotient.clone() + 1
+    };
+    let half = (remainder.abs() * BigInt::from(2)).cmp(divisor);
+    let round_away = match mode {
+        RoundingMode::TowardsPositiveInfinity => value.is_positive(),
+        RoundingMode::TowardsNegativeInfinity => value.is_negative(),
+        RoundingMode::TowardsZero => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::TowardsNearestAndHalfTowardsZero => half.is_gt(),
+        RoundingMode::TowardsNearestAndHalfAwayFromZero => half.is_ge(),
+    };
+    if round_away {
+        away_from_zero
+    } else {
+        quotient
+    }
+}
+
+macro_rules! from_int {
+    ($type:ident) => {
+        impl From<$type> for PreciseDecimal {
+            fn from(val: $type) -> Self {
+                Self::from_big_int(BigInt::from(val) * Self::ONE.0.to_big_int())
+            }
+        }
+    };
+}
+from_int!(u8);
+from_int!(u16);
+from_int!(u32);
+from_int!(u64);
+from_int!(u128);
+from_int!(usize);
+from_int!(i8);
+from_int!(i16);
+from_int!(i32);
+from_int!(i64);
+from_int!(i128);
+from_int!(isize);
+
+impl From<Decimal> for PreciseDecimal {
+    fn from(val: Decimal) -> Self {
+        Self::from_big_int(BigInt::from(val.0) * BigInt::from(10).pow(Self::SCALE - Decimal::SCALE))
+    }
+}
+
+impl From<&str> for PreciseDecimal {
+    fn from(val: &str) -> Self {
+        Self::from_str(val).unwrap()
+    }
+}
+
+impl From<String> for PreciseDecimal {
+    fn from(val: String) -> Self {
+        Self::from_str(&val).unwrap()
+    }
+}
+
+impl From<bool> for PreciseDecimal {
+    fn from(val: bool) -> Self {
+        if val {
+            Self::from(1)
+        } else {
+            Self::from(0)
+        }
+    }
+}
+
+/// Creates a `PreciseDecimal` from literals.
+///
+/// # Example
+/// ```ignore
+/// use scrypto::prelude::*;
+///
+/// let a = pdec!(1);
+/// let b = pdec!("1.1");
+/// ```
+#[macro_export]
+macro_rules! pdec {
+    ($x:literal) => {
+        ::scrypto::math::PreciseDecimal::from($x)
+    };
+
+    ($base:literal, $shift:literal) => {
+        // Base can be any type that converts into a PreciseDecimal, and shift must support
+        // comparison and `-` unary operation, enforced by rustc.
+        {
+            let base = ::scrypto::math::PreciseDecimal::from($base);
+            if $shift >= 0 {
+                base * 10i128.pow(u32::try_from($shift).expect("Shift overflow"))
+            } else {
+                base / 10i128.pow(u32::try_from(-$shift).expect("Shift overflow"))
+            }
+        }
+    };
+}
+
+impl<T: Into<PreciseDecimal>> Add<T> for PreciseDecimal {
+    type Output = PreciseDecimal;
+
+    fn add(self, other: T) -> Self::Output {
+        Self(self.0 + other.into().0)
+    }
+}
+
+impl<T: Into<PreciseDecimal>> Sub<T> for PreciseDecimal {
+    type Output = PreciseDecimal;
+
+    fn sub(self, other: T) -> Self::Output {
+        Self(self.0 - other.into().0)
+    }
+}
+
+impl<T: Into<PreciseDecimal>> Mul<T> for PreciseDecimal {
+    type Output = PreciseDecimal;
+
+    fn mul(self, other: T) -> Self::Output {
+        let a = self.0.to_big_int();
+        let b = other.into().0.to_big_int();
+        Self::from_big_int(a * b / Self::ONE.0.to_big_int())
+    }
+}
+
+impl<T: Into<PreciseDecimal>> Div<T> for PreciseDecimal {
+    type Output = PreciseDecimal;
+
+    fn div(self, other: T) -> Self::Output {
+        let a = self.0.to_big_int();
+        let b = other.into().0.to_big_int();
+        if b.is_zero() {
+            panic!("Division by zero");
+        }
+        Self::from_big_int(a * Self::ONE.0.to_big_int() / b)
+    }
+}
+
+impl Neg for PreciseDecimal {
+    type Output = PreciseDecimal;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<T: Into<PreciseDecimal>> AddAssign<T> for PreciseDecimal {
+    fn add_assign(&mut self, other: T) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Into<PreciseDecimal>> SubAssign<T> for PreciseDecimal {
+    fn sub_assign(&mut self, other: T) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Into<PreciseDecimal>> MulAssign<T> for PreciseDecimal {
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Into<PreciseDecimal>> DivAssign<T> for PreciseDecimal {
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+//========
+// error
+//========
+
+/// Represents an error when parsing PreciseDecimal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePreciseDecimalError {
+    InvalidChar(char),
+    UnsupportedDecimalPlace,
+    InvalidLength(usize),
+    Overflow,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl std::error::Error for ParsePreciseDecimalError {}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Display for ParsePreciseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+//========
+// binary
+//========
+
+impl TryFrom<&[u8]> for PreciseDecimal {
+    type Error = ParsePreciseDecimalError;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        I256::try_from(slice)
+            .map(Self)
+            .map_err(|_| ParsePreciseDecimalError::InvalidLength(slice.len()))
+    }
+}
+
+impl PreciseDecimal {
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+scrypto_type!(PreciseDecimal, ScryptoType::PreciseDecimal, Vec::new());
+
+//======
+// text
+//======
+
+impl FromStr for PreciseDecimal {
+    type Err = ParsePreciseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (integral, fraction) = s.split_once('.').unwrap_or((s, ""));
+        if fraction.len() > Self::SCALE as usize {
+            return Err(ParsePreciseDecimalError::UnsupportedDecimalPlace);
+        }
+
+        let mut value = BigInt::zero();
+        for c in integral.chars().chain(fraction.chars()) {
+            let digit = c
+                .to_digit(10)
+                .ok_or(ParsePreciseDecimalError::InvalidChar(c))?;
+            value = value * 10 + digit;
+        }
+        value *= BigInt::from(10).pow(Self::SCALE - fraction.len() as u32);
+        if negative {
+            value = -value;
+        }
+
+        I256::try_from_big_int(&value)
+            .map(Self)
+            .ok_or(ParsePreciseDecimalError::Overflow)
+    }
+}
+
+impl fmt::Display for PreciseDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let digits = self.0.to_big_int().abs().to_string();
+        let scale = Self::SCALE as usize;
+        let (integral, fraction) = if digits.len() > scale {
+            digits.split_at(digits.len() - scale)
+        } else {
+            ("0", digits.as_str())
+        };
+        let fraction = format!("{:0>width$}", fraction, width = scale);
+        let fraction = fraction.trim_end_matches('0');
+
+        write!(
+            f,
+            "{}{}{}{}",
+            if self.is_negative() { "-" } else { "" },
+            integral,
+            if fraction.is_empty() { "" } else { "." },
+            fraction
+        )
+    }
+}
+
+impl fmt::Debug for PreciseDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::*;
+    use crate::dec;
+    use sbor::rust::vec;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            PreciseDecimal(I256::ONE).to_string(),
+            "0.000000000000000000000000000000000001"
+        );
+        assert_eq!(PreciseDecimal::ONE.to_string(), "1");
+        assert_eq!(pdec!("-123.45").to_string(), "-123.45");
+        assert_eq!(pdec!("0.5").to_string(), "0.5");
+        assert_eq!(PreciseDecimal::zero().to_string(), "0");
+        assert_eq!(
+            PreciseDecimal::MAX.to_string(),
+            "57896044618658097711785492504343953926634.992332820282019728792003956564819967"
+        );
+        assert_eq!(
+            PreciseDecimal::MIN.to_string(),
+            "-57896044618658097711785492504343953926634.992332820282019728792003956564819968"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            PreciseDecimal::from_str("0.000000000000000000000000000000000001").unwrap(),
+            PreciseDecimal(I256::ONE),
+        );
+        assert_eq!(PreciseDecimal::from_str("1").unwrap(), PreciseDecimal::ONE);
+        assert_eq!(
+            PreciseDecimal::from_str(&PreciseDecimal::MIN.to_string()).unwrap(),
+            PreciseDecimal::MIN,
+        );
+        assert_eq!(
+            PreciseDecimal::from_str("1.0000000000000000000000000000000000001"),
+            Err(ParsePreciseDecimalError::UnsupportedDecimalPlace),
+        );
+        assert_eq!(
+            PreciseDecimal::from_str("1x"),
+            Err(ParsePreciseDecimalError::InvalidChar('x')),
+        );
+        assert_eq!(
+            PreciseDecimal::from_str("100000000000000000000000000000000000000000000"),
+            Err(ParsePreciseDecimalError::Overflow),
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(pdec!(5) + pdec!(7), pdec!(12));
+        assert_eq!(pdec!(5) - pdec!(7), pdec!(-2));
+        assert_eq!(pdec!("1.5") * pdec!(-2), pdec!(-3));
+        assert_eq!(
+            (pdec!(5) / pdec!(7)).to_string(),
+            "0.714285714285714285714285714285714285"
+        );
+        assert_eq!(pdec!(11235, -2), pdec!("112.35"));
+        let sum: PreciseDecimal = vec![pdec!(1), pdec!(2), pdec!(3)].into_iter().sum();
+        assert_eq!(sum, pdec!(6));
+    }
+
+    #[test]
+    fn test_mul_does_not_overflow_on_decimal_max() {
+        let a = PreciseDecimal::from(Decimal::MAX);
+        assert_eq!(
+            (a * a).to_string(),
+            "28948022309329048855892746252171976962977.213799489202546401021394546514198529"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_mul_overflow() {
+        let _ = PreciseDecimal::MAX * pdec!(2);
+    }
+
+    #[test]
+    fn test_round() {
+        let mode = RoundingMode::TowardsNearestAndHalfAwayFromZero;
+        assert_eq!(pdec!("2.5").round(0, mode), pdec!(3));
+        assert_eq!(pdec!("-2.5").round(0, mode), pdec!(-3));
+        let mode = RoundingMode::TowardsNearestAndHalfTowardsZero;
+        assert_eq!(pdec!("2.5").round(0, mode), pdec!(2));
+        assert_eq!(pdec!("2.51").round(0, mode), pdec!(3));
+        assert_eq!(pdec!("-5.2").floor(), pdec!(-6));
+        assert_eq!(pdec!("-5.2").ceiling(), pdec!(-5));
+        assert_eq!(pdec!("-5.2").round(0, RoundingMode::TowardsZero), pdec!(-5));
+        assert_eq!(
+            pdec!("-5.2").round(0, RoundingMode::AwayFromZero),
+            pdec!(-6)
+        );
+        assert_eq!(
+            pdec!("-2.555555").round(2, RoundingMode::TowardsNearestAndHalfAwayFromZero),
+            pdec!("-2.56")
+        );
+    }
+
+    #[test]
+    fn test_decimal_conversion() {
+        assert_eq!(PreciseDecimal::from(dec!("1.5")), pdec!("1.5"));
+        assert_eq!(
+            PreciseDecimal::from(Decimal::MIN).to_decimal(RoundingMode::TowardsZero),
+            Decimal::MIN
+        );
+
+        let third = pdec!(1) / 3;
+        assert_eq!(
+            third.to_decimal(RoundingMode::TowardsZero),
+            dec!("0.333333333333333333")
+        );
+        assert_eq!(
+            third.to_decimal(RoundingMode::AwayFromZero),
+            dec!("0.333333333333333334")
+        );
+        assert_eq!(
+            (-third).to_decimal(RoundingMode::TowardsNegativeInfinity),
+            dec!("-0.333333333333333334")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_to_decimal_overflow() {
+        let _ = (PreciseDecimal::from(Decimal::MAX) + 1).to_decimal(RoundingMode::TowardsZero);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let value = pdec!("-1.000000000000000000000000000000000001");
+        let bytes = scrypto_encode(&value);
+        assert_eq!(scrypto_decode::<PreciseDecimal>(&bytes).unwrap(), value);
+    }
+}
@@ -15,6 +15,9 @@ pub enum MintParams {
     NonFungible {
         entries: HashMap<NonFungibleId, (Vec<u8>, Vec<u8>)>,
     },
+
+    /// To mint non-fungible resource with engine-generated ids, represented by data only
+    NonFungibleAutoId { entries: Vec<(Vec<u8>, Vec<u8>)> },
 }
 
 impl MintParams {
@@ -37,12 +40,27 @@ impl MintParams {
         Self::NonFungible { entries: encoded }
     }
 
+    pub fn non_fungible_auto_id<T, V>(entries: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: NonFungibleData,
+    {
+        let encoded = entries
+            .into_iter()
+            .map(|e| (e.immutable_data(), e.mutable_data()))
+            .collect();
+
+        Self::NonFungibleAutoId { entries: encoded }
+    }
+
     pub fn matches_type(&self, resource_type: &ResourceType) -> bool {
         match self {
             Self::Fungible { .. } => {
                 matches!(resource_type, ResourceType::Fungible { .. })
             }
-            Self::NonFungible { .. } => matches!(resource_type, ResourceType::NonFungible),
+            Self::NonFungible { .. } | Self::NonFungibleAutoId { .. } => {
+                matches!(resource_type, ResourceType::NonFungible { .. })
+            }
         }
     }
 
@@ -50,6 +68,7 @@ impl MintParams {
         match self {
             Self::Fungible { amount } => amount.clone(),
             Self::NonFungible { entries } => entries.len().into(),
+            Self::NonFungibleAutoId { entries } => entries.len().into(),
         }
     }
 }
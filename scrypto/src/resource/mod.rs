@@ -10,6 +10,7 @@ mod proof;
 mod proof_rule;
 mod resource_builder;
 mod resource_manager;
+mod resource_metadata;
 mod resource_type;
 mod schema_path;
 mod system;
@@ -21,13 +22,14 @@ pub use bucket::{Bucket, ParseBucketError};
 pub use mint_params::MintParams;
 pub use non_fungible::NonFungible;
 pub use non_fungible_address::{NonFungibleAddress, ParseNonFungibleAddressError};
-pub use non_fungible_data::NonFungibleData;
-pub use non_fungible_id::{NonFungibleId, ParseNonFungibleIdError};
+pub use non_fungible_data::{NonFungibleData, NonFungibleDataSchema};
+pub use non_fungible_id::{NonFungibleId, NonFungibleIdType, ParseNonFungibleIdError};
 pub use proof::{ParseProofError, Proof};
 pub use proof_rule::{
-    require, require_all_of, require_amount, require_any_of, require_n_of, AccessRuleNode,
-    AccessRule, ProofRule, SoftCount, SoftDecimal, SoftResource, SoftResourceOrNonFungible,
-    SoftResourceOrNonFungibleList,
+    require, require_all_of, require_amount, require_any_of, require_epoch_at_least,
+    require_epoch_before, require_n_of, require_n_of_signatures, require_signature,
+    require_time_at_least, require_time_before, AccessRuleNode, AccessRule, ProofRule, SoftCount,
+    SoftDecimal, SoftResource, SoftResourceOrNonFungible, SoftResourceOrNonFungibleList, SoftU64,
 };
 pub use resource_builder::{ResourceBuilder, DIVISIBILITY_MAXIMUM, DIVISIBILITY_NONE};
 pub use resource_manager::Mutability::*;
@@ -35,6 +37,7 @@ pub use resource_manager::ResourceMethod::*;
 pub use resource_manager::{
     Mutability, ParseResourceAddressError, ResourceAddress, ResourceManager, ResourceMethod,
 };
+pub use resource_metadata::{validate_metadata, MetadataError, MetadataKey, MAX_SYMBOL_LENGTH};
 pub use resource_type::ResourceType;
 pub use schema_path::SchemaPath;
 pub use system::{init_resource_system, resource_system, ResourceSystem};
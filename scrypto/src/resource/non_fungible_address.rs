@@ -1,5 +1,7 @@
 use sbor::*;
 
+use crate::constants::ECDSA_TOKEN;
+use crate::crypto::EcdsaPublicKey;
 use crate::misc::*;
 use crate::resource::*;
 use crate::rust::borrow::ToOwned;
@@ -24,6 +26,11 @@ impl NonFungibleAddress {
         }
     }
 
+    /// Returns the address of the virtual badge which proves a signature by the given key.
+    pub fn from_public_key(public_key: &EcdsaPublicKey) -> Self {
+        Self::new(ECDSA_TOKEN, NonFungibleId::from_bytes(public_key.to_vec()))
+    }
+
     /// Returns the resource address.
     pub fn resource_address(&self) -> ResourceAddress {
         self.resource_address
@@ -122,11 +129,10 @@ mod tests {
 
     #[test]
     fn test_non_fungible_address_codec() {
-        let expected = "030000000000000000000000000000000000000000000000000005046ff03b949241ce1dadd43519e6960e0a85b41a69a05c328103aa2bce1594ca163c4f753a55bf01dc53f6c0b0c7eee78b40c6ff7d25a96e2282b989cef71c144a";
+        let expected = "03000000000000000000000000000000000000000000000000000503046ff03b949241ce1dadd43519e6960e0a85b41a69a05c328103aa2bce1594ca163c4f753a55bf01dc53f6c0b0c7eee78b40c6ff7d25a96e2282b989cef71c144a";
         let private_key = EcdsaPrivateKey::from_bytes(&[1u8; 32]).unwrap();
         let public_key = private_key.public_key();
-        let auth_address =
-            NonFungibleAddress::new(ECDSA_TOKEN, NonFungibleId::from_bytes(public_key.to_vec()));
+        let auth_address = NonFungibleAddress::from_public_key(&public_key);
         let s1 = auth_address.to_string();
         let auth_address2 = NonFungibleAddress::from_str(&s1).unwrap();
         let s2 = auth_address2.to_string();
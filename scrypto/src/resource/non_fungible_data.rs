@@ -21,3 +21,35 @@ pub trait NonFungibleData {
     /// Returns the schema of the mutable data.
     fn mutable_data_schema() -> Type;
 }
+
+/// Represents the schema of the immutable and mutable parts of a non-fungible data.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub struct NonFungibleDataSchema {
+    pub immutable: Type,
+    pub mutable: Type,
+}
+
+impl NonFungibleDataSchema {
+    /// Returns the schema of the given non-fungible data type.
+    pub fn of<T: NonFungibleData>() -> Self {
+        Self {
+            immutable: T::immutable_data_schema(),
+            mutable: T::mutable_data_schema(),
+        }
+    }
+
+    /// Returns the schema of a mutable field, if any.
+    pub fn mutable_field(&self, name: &str) -> Option<(usize, &Type)> {
+        match &self.mutable {
+            Type::Struct {
+                fields: Fields::Named { named },
+                ..
+            } => named
+                .iter()
+                .enumerate()
+                .find(|(_, (field_name, _))| field_name == name)
+                .map(|(index, (_, ty))| (index, ty)),
+            _ => None,
+        }
+    }
+}
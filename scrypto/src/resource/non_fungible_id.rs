@@ -8,29 +8,89 @@ use crate::rust::string::ToString;
 use crate::rust::vec::Vec;
 use crate::types::*;
 
+/// Represents the type of the ids of a non-fungible resource.
+#[derive(Debug, Clone, Copy, TypeId, Encode, Decode, Describe, PartialEq, Eq, Hash)]
+pub enum NonFungibleIdType {
+    U32,
+    U64,
+    String,
+    Bytes,
+    UUID,
+}
+
+impl NonFungibleIdType {
+    pub fn id(&self) -> u8 {
+        match self {
+            Self::U32 => 0,
+            Self::U64 => 1,
+            Self::String => 2,
+            Self::Bytes => 3,
+            Self::UUID => 4,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::U32),
+            1 => Some(Self::U64),
+            2 => Some(Self::String),
+            3 => Some(Self::Bytes),
+            4 => Some(Self::UUID),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a key for a non-fungible resource
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct NonFungibleId(Vec<u8>);
+pub enum NonFungibleId {
+    U32(u32),
+    U64(u64),
+    String(String),
+    Bytes(Vec<u8>),
+    UUID(u128),
+}
 
 impl NonFungibleId {
     /// Creates a non-fungible ID from some uuid.
     pub fn random() -> Self {
-        Self(crate::core::Runtime::generate_uuid().to_be_bytes().to_vec())
+        Self::UUID(crate::core::Runtime::generate_uuid())
     }
 
     /// Creates a non-fungible ID from an arbitrary byte array.
     pub fn from_bytes(v: Vec<u8>) -> Self {
-        Self(v)
+        Self::Bytes(v)
     }
 
     /// Creates a non-fungible ID from a `u32` number.
     pub fn from_u32(u: u32) -> Self {
-        Self(u.to_be_bytes().to_vec())
+        Self::U32(u)
     }
 
     /// Creates a non-fungible ID from a `u64` number.
     pub fn from_u64(u: u64) -> Self {
-        Self(u.to_be_bytes().to_vec())
+        Self::U64(u)
+    }
+
+    /// Creates a non-fungible ID from a string.
+    pub fn from_string<S: Into<String>>(s: S) -> Self {
+        Self::String(s.into())
+    }
+
+    /// Creates a non-fungible ID from a `u128` uuid.
+    pub fn from_uuid(u: u128) -> Self {
+        Self::UUID(u)
+    }
+
+    /// Returns the type of this non-fungible ID.
+    pub fn id_type(&self) -> NonFungibleIdType {
+        match self {
+            Self::U32(_) => NonFungibleIdType::U32,
+            Self::U64(_) => NonFungibleIdType::U64,
+            Self::String(_) => NonFungibleIdType::String,
+            Self::Bytes(_) => NonFungibleIdType::Bytes,
+            Self::UUID(_) => NonFungibleIdType::UUID,
+        }
     }
 }
 
@@ -42,6 +102,12 @@ impl NonFungibleId {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParseNonFungibleIdError {
     InvalidHex(String),
+    InvalidLength(usize),
+    InvalidIdType(u8),
+    InvalidString,
+    InvalidU32(String),
+    InvalidU64(String),
+    InvalidUUID(String),
 }
 
 #[cfg(not(feature = "alloc"))]
@@ -62,13 +128,43 @@ impl TryFrom<&[u8]> for NonFungibleId {
     type Error = ParseNonFungibleIdError;
 
     fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self(slice.to_vec()))
+        let (id_type, data) = slice
+            .split_first()
+            .ok_or(ParseNonFungibleIdError::InvalidLength(0))?;
+        match NonFungibleIdType::from_id(*id_type) {
+            Some(NonFungibleIdType::U32) => data
+                .try_into()
+                .map(|b| Self::U32(u32::from_be_bytes(b)))
+                .map_err(|_| ParseNonFungibleIdError::InvalidLength(slice.len())),
+            Some(NonFungibleIdType::U64) => data
+                .try_into()
+                .map(|b| Self::U64(u64::from_be_bytes(b)))
+                .map_err(|_| ParseNonFungibleIdError::InvalidLength(slice.len())),
+            Some(NonFungibleIdType::String) => String::from_utf8(data.to_vec())
+                .map(Self::String)
+                .map_err(|_| ParseNonFungibleIdError::InvalidString),
+            Some(NonFungibleIdType::Bytes) => Ok(Self::Bytes(data.to_vec())),
+            Some(NonFungibleIdType::UUID) => data
+                .try_into()
+                .map(|b| Self::UUID(u128::from_be_bytes(b)))
+                .map_err(|_| ParseNonFungibleIdError::InvalidLength(slice.len())),
+            None => Err(ParseNonFungibleIdError::InvalidIdType(*id_type)),
+        }
     }
 }
 
 impl NonFungibleId {
     pub fn to_vec(&self) -> Vec<u8> {
-        self.0.clone()
+        let mut vec = Vec::new();
+        vec.push(self.id_type().id());
+        match self {
+            Self::U32(u) => vec.extend(u.to_be_bytes()),
+            Self::U64(u) => vec.extend(u.to_be_bytes()),
+            Self::String(s) => vec.extend(s.as_bytes()),
+            Self::Bytes(b) => vec.extend(b),
+            Self::UUID(u) => vec.extend(u.to_be_bytes()),
+        }
+        vec
     }
 }
 
@@ -78,19 +174,69 @@ scrypto_type!(NonFungibleId, ScryptoType::NonFungibleId, Vec::new());
 // text
 //======
 
+/// Parses the human-readable form of a non-fungible id:
+///
+/// * `U32`: `5u32`
+/// * `U64`: `5u64`
+/// * `String`: `"name"`, in double quotes
+/// * `Bytes`: `0a0b`, in hex
+/// * `UUID`: `f81d4fae-7dec-11d0-a765-00a0c91e6bf6`
 impl FromStr for NonFungibleId {
     type Err = ParseNonFungibleIdError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytes =
-            hex::decode(s).map_err(|_| ParseNonFungibleIdError::InvalidHex(s.to_owned()))?;
-        Self::try_from(bytes.as_slice())
+        if let Some(u) = s.strip_suffix("u32") {
+            u.parse::<u32>()
+                .map(Self::U32)
+                .map_err(|_| ParseNonFungibleIdError::InvalidU32(s.to_owned()))
+        } else if let Some(u) = s.strip_suffix("u64") {
+            u.parse::<u64>()
+                .map(Self::U64)
+                .map_err(|_| ParseNonFungibleIdError::InvalidU64(s.to_owned()))
+        } else if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+            Ok(Self::String(s[1..s.len() - 1].to_owned()))
+        } else if s.contains('-') {
+            parse_uuid(s)
+                .map(Self::UUID)
+                .ok_or(ParseNonFungibleIdError::InvalidUUID(s.to_owned()))
+        } else {
+            hex::decode(s)
+                .map(Self::Bytes)
+                .map_err(|_| ParseNonFungibleIdError::InvalidHex(s.to_owned()))
+        }
+    }
+}
+
+fn parse_uuid(s: &str) -> Option<u128> {
+    let groups: Vec<&str> = s.split('-').collect();
+    let lengths: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+    if lengths != [8, 4, 4, 4, 12] {
+        return None;
     }
+    let bytes: [u8; 16] = hex::decode(groups.concat()).ok()?.try_into().ok()?;
+    Some(u128::from_be_bytes(bytes))
 }
 
 impl fmt::Display for NonFungibleId {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "{}", hex::encode(&self.0))
+        match self {
+            Self::U32(u) => write!(f, "{}u32", u),
+            Self::U64(u) => write!(f, "{}u64", u),
+            Self::String(s) => write!(f, "\"{}\"", s),
+            Self::Bytes(b) => write!(f, "{}", hex::encode(b)),
+            Self::UUID(u) => {
+                let s = hex::encode(u.to_be_bytes());
+                write!(
+                    f,
+                    "{}-{}-{}-{}-{}",
+                    &s[0..8],
+                    &s[8..12],
+                    &s[12..16],
+                    &s[16..20],
+                    &s[20..32]
+                )
+            }
+        }
     }
 }
 
@@ -112,12 +258,78 @@ mod tests {
             NonFungibleId::from_bytes(vec![53u8, 117u8])
         );
         assert_eq!(
-            NonFungibleId::from_str("00000005").unwrap(),
+            NonFungibleId::from_str("5u32").unwrap(),
             NonFungibleId::from_u32(5)
         );
         assert_eq!(
-            NonFungibleId::from_str("0000000000000005").unwrap(),
+            NonFungibleId::from_str("5u64").unwrap(),
             NonFungibleId::from_u64(5)
         );
+        assert_eq!(
+            NonFungibleId::from_str("\"car-1\"").unwrap(),
+            NonFungibleId::from_string("car-1")
+        );
+        assert_eq!(
+            NonFungibleId::from_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap(),
+            NonFungibleId::from_uuid(0xf81d4fae7dec11d0a76500a0c91e6bf6)
+        );
+    }
+
+    #[test]
+    fn test_non_fungible_id_display_round_trip() {
+        for id in [
+            NonFungibleId::from_u32(5),
+            NonFungibleId::from_u64(u64::MAX),
+            NonFungibleId::from_string("car-1"),
+            NonFungibleId::from_bytes(vec![1, 2, 3]),
+            NonFungibleId::from_uuid(1),
+        ] {
+            assert_eq!(NonFungibleId::from_str(&id.to_string()).unwrap(), id);
+        }
+        assert_eq!(NonFungibleId::from_u32(5).to_string(), "5u32");
+        assert_eq!(
+            NonFungibleId::from_uuid(1).to_string(),
+            "00000000-0000-0000-0000-000000000001"
+        );
+    }
+
+    #[test]
+    fn test_non_fungible_id_binary_round_trip() {
+        for id in [
+            NonFungibleId::from_u32(5),
+            NonFungibleId::from_u64(5),
+            NonFungibleId::from_string("car-1"),
+            NonFungibleId::from_bytes(vec![]),
+            NonFungibleId::from_uuid(5),
+        ] {
+            assert_eq!(NonFungibleId::try_from(id.to_vec().as_slice()).unwrap(), id);
+        }
+        assert_eq!(NonFungibleId::from_u32(5).to_vec(), vec![0, 0, 0, 0, 5]);
+        assert_eq!(
+            NonFungibleId::try_from([0u8, 1].as_ref()),
+            Err(ParseNonFungibleIdError::InvalidLength(2))
+        );
+        assert_eq!(
+            NonFungibleId::try_from([9u8].as_ref()),
+            Err(ParseNonFungibleIdError::InvalidIdType(9))
+        );
+    }
+
+    #[test]
+    fn test_invalid_non_fungible_id_string_rep() {
+        assert_eq!(
+            NonFungibleId::from_str("-1u32"),
+            Err(ParseNonFungibleIdError::InvalidU32("-1u32".to_owned()))
+        );
+        assert_eq!(
+            NonFungibleId::from_str("f81d4fae-7dec"),
+            Err(ParseNonFungibleIdError::InvalidUUID(
+                "f81d4fae-7dec".to_owned()
+            ))
+        );
+        assert_eq!(
+            NonFungibleId::from_str("xyz"),
+            Err(ParseNonFungibleIdError::InvalidHex("xyz".to_owned()))
+        );
     }
 }
@@ -1,8 +1,11 @@
+use crate::constants::ECDSA_TOKEN;
+use crate::crypto::EcdsaPublicKey;
 use crate::engine::api::{CheckAccessRuleInput, CheckAccessRuleOutput, CHECK_ACCESS_RULE};
 use crate::engine::call_engine;
 use crate::resource::AccessRuleNode::{AllOf, AnyOf};
 use crate::resource::*;
 use crate::rust::borrow::ToOwned;
+use crate::rust::fmt;
 use crate::rust::vec;
 use crate::rust::vec::Vec;
 use crate::rust::string::ToString;
@@ -61,6 +64,31 @@ impl From<&str> for SoftCount {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Describe, TypeId, Encode, Decode)]
+pub enum SoftU64 {
+    Static(u64),
+    Dynamic(SchemaPath),
+}
+
+impl From<u64> for SoftU64 {
+    fn from(value: u64) -> Self {
+        SoftU64::Static(value)
+    }
+}
+
+impl From<SchemaPath> for SoftU64 {
+    fn from(path: SchemaPath) -> Self {
+        SoftU64::Dynamic(path)
+    }
+}
+
+impl From<&str> for SoftU64 {
+    fn from(path: &str) -> Self {
+        let schema_path: SchemaPath = path.parse().expect("Could not decode path");
+        SoftU64::Dynamic(schema_path)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Describe, TypeId, Encode, Decode)]
 pub enum SoftResource {
     Static(ResourceAddress),
@@ -154,6 +182,14 @@ pub enum ProofRule {
     CountOf(SoftCount, SoftResourceOrNonFungibleList),
     AllOf(SoftResourceOrNonFungibleList),
     AnyOf(SoftResourceOrNonFungibleList),
+    /// Satisfied in or after the given epoch
+    EpochAtLeast(SoftU64),
+    /// Satisfied strictly before the given epoch
+    EpochBefore(SoftU64),
+    /// Satisfied at or after the given time, in milliseconds since the Unix epoch
+    TimeAtLeast(SoftU64),
+    /// Satisfied strictly before the given time, in milliseconds since the Unix epoch
+    TimeBefore(SoftU64),
 }
 
 // FIXME: describe types with cycles
@@ -265,6 +301,52 @@ where
     ProofRule::AmountOf(amount.into(), resource.into())
 }
 
+/// Requires a signature by the given key.
+pub fn require_signature(public_key: EcdsaPublicKey) -> ProofRule {
+    ProofRule::Require(NonFungibleAddress::from_public_key(&public_key).into())
+}
+
+/// Requires signatures by at least `count` of the given keys.
+pub fn require_n_of_signatures<C, P>(count: C, public_keys: P) -> ProofRule
+where
+    C: Into<SoftCount>,
+    P: IntoIterator<Item = EcdsaPublicKey>,
+{
+    let badges: Vec<NonFungibleAddress> = public_keys
+        .into_iter()
+        .map(|public_key| NonFungibleAddress::from_public_key(&public_key))
+        .collect();
+    ProofRule::CountOf(count.into(), badges.into())
+}
+
+pub fn require_epoch_at_least<E>(epoch: E) -> ProofRule
+where
+    E: Into<SoftU64>,
+{
+    ProofRule::EpochAtLeast(epoch.into())
+}
+
+pub fn require_epoch_before<E>(epoch: E) -> ProofRule
+where
+    E: Into<SoftU64>,
+{
+    ProofRule::EpochBefore(epoch.into())
+}
+
+pub fn require_time_at_least<T>(time: T) -> ProofRule
+where
+    T: Into<SoftU64>,
+{
+    ProofRule::TimeAtLeast(time.into())
+}
+
+pub fn require_time_before<T>(time: T) -> ProofRule
+where
+    T: Into<SoftU64>,
+{
+    ProofRule::TimeBefore(time.into())
+}
+
 // TODO: Move this logic into preprocessor. It probably needs to be implemented as a procedural macro.
 #[macro_export]
 macro_rules! access_and_or {
@@ -377,3 +459,205 @@ macro_rules! rule {
         ::scrypto::resource::AccessRule::Protected(access_rule_node!($($tt)+))
     }};
 }
+
+//======
+// text
+//======
+
+/// Returns the signer of a signature virtual badge.
+fn signer_of(resource: &SoftResourceOrNonFungible) -> Option<NonFungibleId> {
+    match resource {
+        SoftResourceOrNonFungible::StaticNonFungible(non_fungible_address)
+            if non_fungible_address.resource_address() == ECDSA_TOKEN =>
+        {
+            Some(non_fungible_address.non_fungible_id())
+        }
+        _ => None,
+    }
+}
+
+fn signers_of(list: &SoftResourceOrNonFungibleList) -> Option<Vec<NonFungibleId>> {
+    match list {
+        SoftResourceOrNonFungibleList::Static(resources) => {
+            resources.iter().map(signer_of).collect()
+        }
+        SoftResourceOrNonFungibleList::Dynamic(_) => None,
+    }
+}
+
+fn write_list<T: fmt::Display>(f: &mut fmt::Formatter, items: &[T]) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, item) in items.iter().enumerate() {
+        if i != 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", item)?;
+    }
+    write!(f, "]")
+}
+
+impl fmt::Display for SoftU64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SoftU64::Static(value) => write!(f, "{}", value),
+            SoftU64::Dynamic(path) => write!(f, "\"{}\"", path),
+        }
+    }
+}
+
+impl fmt::Display for SoftDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SoftDecimal::Static(amount) => write!(f, "dec!(\"{}\")", amount),
+            SoftDecimal::Dynamic(path) => write!(f, "\"{}\"", path),
+        }
+    }
+}
+
+impl fmt::Display for SoftCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SoftCount::Static(count) => write!(f, "{}", count),
+            SoftCount::Dynamic(path) => write!(f, "\"{}\"", path),
+        }
+    }
+}
+
+impl fmt::Display for SoftResource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SoftResource::Static(resource_address) => write!(f, "{}", resource_address),
+            SoftResource::Dynamic(path) => write!(f, "\"{}\"", path),
+        }
+    }
+}
+
+impl fmt::Display for SoftResourceOrNonFungible {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SoftResourceOrNonFungible::StaticNonFungible(non_fungible_address) => {
+                write!(f, "{}", non_fungible_address)
+            }
+            SoftResourceOrNonFungible::StaticResource(resource_address) => {
+                write!(f, "{}", resource_address)
+            }
+            SoftResourceOrNonFungible::Dynamic(path) => write!(f, "\"{}\"", path),
+        }
+    }
+}
+
+impl fmt::Display for SoftResourceOrNonFungibleList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SoftResourceOrNonFungibleList::Static(resources) => write_list(f, resources),
+            SoftResourceOrNonFungibleList::Dynamic(path) => write!(f, "\"{}\"", path),
+        }
+    }
+}
+
+/// Formats a proof rule the way it's written with the `rule!` macro, with signature virtual
+/// badges shown as the public keys they stand for.
+impl fmt::Display for ProofRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProofRule::Require(resource) => match signer_of(resource) {
+                Some(signer) => write!(f, "require_signature({})", signer),
+                None => write!(f, "require({})", resource),
+            },
+            ProofRule::AmountOf(amount, resource) => {
+                write!(f, "require_amount({}, {})", amount, resource)
+            }
+            ProofRule::CountOf(count, resources) => match signers_of(resources) {
+                Some(signers) => {
+                    write!(f, "require_n_of_signatures({}, ", count)?;
+                    write_list(f, &signers)?;
+                    write!(f, ")")
+                }
+                None => write!(f, "require_n_of({}, {})", count, resources),
+            },
+            ProofRule::AllOf(resources) => write!(f, "require_all_of({})", resources),
+            ProofRule::AnyOf(resources) => write!(f, "require_any_of({})", resources),
+            ProofRule::EpochAtLeast(epoch) => write!(f, "require_epoch_at_least({})", epoch),
+            ProofRule::EpochBefore(epoch) => write!(f, "require_epoch_before({})", epoch),
+            ProofRule::TimeAtLeast(time) => write!(f, "require_time_at_least({})", time),
+            ProofRule::TimeBefore(time) => write!(f, "require_time_before({})", time),
+        }
+    }
+}
+
+impl fmt::Display for AccessRuleNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (rules, operator) = match self {
+            AccessRuleNode::ProofRule(proof_rule) => return write!(f, "{}", proof_rule),
+            AccessRuleNode::AnyOf(rules) => (rules, " || "),
+            AccessRuleNode::AllOf(rules) => (rules, " && "),
+        };
+        for (i, rule) in rules.iter().enumerate() {
+            if i != 0 {
+                write!(f, "{}", operator)?;
+            }
+            match rule {
+                AccessRuleNode::ProofRule(_) => write!(f, "{}", rule)?,
+                _ => write!(f, "({})", rule)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AccessRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccessRule::AllowAll => write!(f, "rule!(allow_all)"),
+            AccessRule::DenyAll => write!(f, "rule!(deny_all)"),
+            AccessRule::Protected(node) => write!(f, "rule!({})", node),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::RADIX_TOKEN;
+    use crate::crypto::EcdsaPrivateKey;
+    use crate::rust::format;
+
+    #[test]
+    fn test_display_signature_rules() {
+        let pk1 = EcdsaPrivateKey::from_bytes(&[1u8; 32])
+            .unwrap()
+            .public_key();
+        let pk2 = EcdsaPrivateKey::from_bytes(&[2u8; 32])
+            .unwrap()
+            .public_key();
+
+        let rule = rule!(require_signature(pk1) || require_n_of_signatures(2, [pk1, pk2]));
+        assert_eq!(
+            rule.to_string(),
+            format!(
+                "rule!(require_signature({0}) || require_n_of_signatures(2, [{0}, {1}]))",
+                pk1, pk2
+            )
+        );
+        assert_eq!(
+            rule!(require(NonFungibleAddress::from_public_key(&pk1))),
+            rule!(require_signature(pk1))
+        );
+    }
+
+    #[test]
+    fn test_display_nested_rules() {
+        let rule = rule!(
+            require_amount(Decimal::from(5), RADIX_TOKEN)
+                && (require_epoch_at_least("unlock_epoch") || require_time_before(100))
+        );
+        assert_eq!(
+            rule.to_string(),
+            format!(
+                "rule!(require_amount(dec!(\"5\"), {}) && (require_epoch_at_least(\"unlock_epoch\") || require_time_before(100)))",
+                RADIX_TOKEN
+            )
+        );
+        assert_eq!(rule!(allow_all).to_string(), "rule!(allow_all)");
+    }
+}
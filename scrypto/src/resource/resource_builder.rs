@@ -2,7 +2,7 @@ use crate::rule;
 use crate::math::*;
 use crate::resource::*;
 use crate::rust::borrow::ToOwned;
-use crate::rust::collections::HashMap;
+use crate::rust::collections::{BTreeSet, HashMap};
 use crate::rust::string::String;
 
 /// Not divisible.
@@ -17,11 +17,16 @@ pub struct FungibleResourceBuilder {
     divisibility: u8,
     metadata: HashMap<String, String>,
     authorization: HashMap<ResourceMethod, (AccessRule, Mutability)>,
+    max_supply: Option<Decimal>,
+    locked_metadata: BTreeSet<String>,
 }
 
 pub struct NonFungibleResourceBuilder {
+    id_type: NonFungibleIdType,
     metadata: HashMap<String, String>,
     authorization: HashMap<ResourceMethod, (AccessRule, Mutability)>,
+    max_supply: Option<u64>,
+    locked_metadata: BTreeSet<String>,
 }
 
 impl ResourceBuilder {
@@ -30,9 +35,9 @@ impl ResourceBuilder {
         FungibleResourceBuilder::new()
     }
 
-    /// Starts a new builder to create non-fungible resource.
-    pub fn new_non_fungible() -> NonFungibleResourceBuilder {
-        NonFungibleResourceBuilder::new()
+    /// Starts a new builder to create non-fungible resource, whose ids are of the given type.
+    pub fn new_non_fungible(id_type: NonFungibleIdType) -> NonFungibleResourceBuilder {
+        NonFungibleResourceBuilder::new(id_type)
     }
 }
 
@@ -42,6 +47,8 @@ impl FungibleResourceBuilder {
             divisibility: DIVISIBILITY_MAXIMUM,
             metadata: HashMap::new(),
             authorization: HashMap::new(),
+            max_supply: None,
+            locked_metadata: BTreeSet::new(),
         }
     }
 
@@ -54,15 +61,30 @@ impl FungibleResourceBuilder {
         self
     }
 
+    /// Caps the total supply, including the initial supply.
+    ///
+    /// The cap can be lowered later via `ResourceManager::set_max_supply` but never raised.
+    pub fn max_supply<T: Into<Decimal>>(&mut self, amount: T) -> &mut Self {
+        self.max_supply = Some(amount.into());
+        self
+    }
+
     /// Adds a resource metadata.
     ///
     /// If a previous attribute with the same name has been set, it will be overwritten.
+    /// Well-known keys, see `MetadataKey`, are validated when the resource is created.
     pub fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
         self.metadata
             .insert(name.as_ref().to_owned(), value.as_ref().to_owned());
         self
     }
 
+    /// Locks a resource metadata, so that it can't be updated even by the metadata updater.
+    pub fn lock_metadata<K: AsRef<str>>(&mut self, name: K) -> &mut Self {
+        self.locked_metadata.insert(name.as_ref().to_owned());
+        self
+    }
+
     pub fn mintable(&mut self, method_auth: AccessRule, mutability: Mutability) -> &mut Self {
         self.authorization.insert(Mint, (method_auth, mutability));
         self
@@ -103,6 +125,16 @@ impl FungibleResourceBuilder {
         self
     }
 
+    pub fn recallable(&mut self, method_auth: AccessRule, mutability: Mutability) -> &mut Self {
+        self.authorization.insert(Recall, (method_auth, mutability));
+        self
+    }
+
+    pub fn freezable(&mut self, method_auth: AccessRule, mutability: Mutability) -> &mut Self {
+        self.authorization.insert(Freeze, (method_auth, mutability));
+        self
+    }
+
     /// Creates resource with the given initial supply.
     ///
     /// # Example
@@ -133,27 +165,48 @@ impl FungibleResourceBuilder {
             self.metadata.clone(),
             authorization,
             mint_params,
+            self.max_supply,
+            None,
+            self.locked_metadata.clone(),
         )
     }
 }
 
 impl NonFungibleResourceBuilder {
-    pub fn new() -> Self {
+    pub fn new(id_type: NonFungibleIdType) -> Self {
         Self {
+            id_type,
             metadata: HashMap::new(),
             authorization: HashMap::new(),
+            max_supply: None,
+            locked_metadata: BTreeSet::new(),
         }
     }
 
+    /// Caps the number of non-fungible units, including the initial supply.
+    ///
+    /// The cap can be lowered later via `ResourceManager::set_max_supply` but never raised.
+    pub fn max_supply(&mut self, count: u64) -> &mut Self {
+        self.max_supply = Some(count);
+        self
+    }
+
     /// Adds a resource metadata.
     ///
     /// If a previous attribute with the same name has been set, it will be overwritten.
+    /// Well-known keys, see `MetadataKey`, are validated when the resource is created.
     pub fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
         self.metadata
             .insert(name.as_ref().to_owned(), value.as_ref().to_owned());
         self
     }
 
+    /// Locks a resource metadata, so that it can't be updated even by the metadata updater.
+    pub fn lock_metadata<K: AsRef<str>>(&mut self, name: K) -> &mut Self {
+        self.locked_metadata.insert(name.as_ref().to_owned());
+        self
+    }
+
     pub fn mintable(&mut self, method_auth: AccessRule, mutability: Mutability) -> &mut Self {
         self.authorization.insert(Mint, (method_auth, mutability));
         self
@@ -194,6 +247,16 @@ impl NonFungibleResourceBuilder {
         self
     }
 
+    pub fn recallable(&mut self, method_auth: AccessRule, mutability: Mutability) -> &mut Self {
+        self.authorization.insert(Recall, (method_auth, mutability));
+        self
+    }
+
+    pub fn freezable(&mut self, method_auth: AccessRule, mutability: Mutability) -> &mut Self {
+        self.authorization.insert(Freeze, (method_auth, mutability));
+        self
+    }
+
     pub fn updateable_non_fungible_data(
         &mut self,
         method_auth: AccessRule,
@@ -208,11 +271,11 @@ impl NonFungibleResourceBuilder {
     ///
     /// # Example
     /// ```ignore
-    /// let bucket = ResourceBuilder::new_non_fungible()
+    /// let bucket = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
     ///     .metadata("name", "TestNonFungible")
     ///     .initial_supply([
-    ///         (NftKey::from(1u128), "immutable_part", "mutable_part"),
-    ///         (NftKey::from(2u128), "another_immutable_part", "another_mutable_part"),
+    ///         (NonFungibleId::from_u32(1), "immutable_part", "mutable_part"),
+    ///         (NonFungibleId::from_u32(2), "another_immutable_part", "another_mutable_part"),
     ///     ]);
     /// ```
     pub fn initial_supply<T, V>(&self, entries: T) -> Bucket
@@ -220,27 +283,46 @@ impl NonFungibleResourceBuilder {
         T: IntoIterator<Item = (NonFungibleId, V)>,
         V: NonFungibleData,
     {
-        self.build(Some(MintParams::non_fungible(entries)))
-            .1
-            .unwrap()
+        self.build(
+            Some(MintParams::non_fungible(entries)),
+            NonFungibleDataSchema::of::<V>(),
+        )
+        .1
+        .unwrap()
     }
 
-    /// Creates resource with no initial supply.
-    pub fn no_initial_supply(&self) -> ResourceAddress {
-        self.build(None).0
+    /// Creates resource with no initial supply, whose non-fungibles hold data of type `V`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let resource_address = ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
+    ///     .metadata("name", "TestNonFungible")
+    ///     .no_initial_supply::<Ticket>();
+    /// ```
+    pub fn no_initial_supply<V: NonFungibleData>(&self) -> ResourceAddress {
+        self.build(None, NonFungibleDataSchema::of::<V>()).0
     }
 
-    fn build(&self, mint_params: Option<MintParams>) -> (ResourceAddress, Option<Bucket>) {
+    fn build(
+        &self,
+        mint_params: Option<MintParams>,
+        data_schema: NonFungibleDataSchema,
+    ) -> (ResourceAddress, Option<Bucket>) {
         let mut authorization = self.authorization.clone();
         if !authorization.contains_key(&Withdraw) {
             authorization.insert(Withdraw, (rule!(allow_all), LOCKED));
         }
 
         resource_system().new_resource(
-            ResourceType::NonFungible,
+            ResourceType::NonFungible {
+                id_type: self.id_type,
+            },
             self.metadata.clone(),
             authorization,
             mint_params,
+            self.max_supply.map(Decimal::from),
+            Some(data_schema),
+            self.locked_metadata.clone(),
         )
     }
 }
@@ -1,14 +1,14 @@
 use sbor::*;
 
 use crate::args;
-use crate::buffer::scrypto_decode;
+use crate::buffer::{scrypto_decode, scrypto_encode};
 use crate::core::SNodeRef;
 use crate::engine::{api::*, call_engine};
 use crate::math::*;
 use crate::misc::*;
 use crate::resource::*;
 use crate::rust::borrow::ToOwned;
-use crate::rust::collections::HashMap;
+use crate::rust::collections::{BTreeMap, BTreeSet, HashMap};
 use crate::rust::fmt;
 use crate::rust::str::FromStr;
 use crate::rust::string::String;
@@ -24,6 +24,8 @@ pub enum ResourceMethod {
     Deposit,
     UpdateMetadata,
     UpdateNonFungibleData,
+    Recall,
+    Freeze,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode, Describe)]
@@ -90,6 +92,19 @@ impl ResourceManager {
         scrypto_decode(&output.rtn).unwrap()
     }
 
+    /// Mints a non-fungible resource, whose id is generated by the engine.
+    ///
+    /// Only supported by resources with `U64` or `UUID` ids.
+    pub fn mint_non_fungible_auto_id<T: NonFungibleData>(&self, data: T) -> Bucket {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "mint".to_string(),
+            args: args![MintParams::non_fungible_auto_id([data])],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
     /// Burns a bucket of resources.
     pub fn burn(&self, bucket: Bucket) {
         let input = InvokeSNodeInput {
@@ -211,8 +226,48 @@ impl ResourceManager {
         scrypto_decode(&output.rtn).unwrap()
     }
 
+    pub fn set_recallable(&self, recall_auth: AccessRule) -> () {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "method_auth".to_string(),
+            args: args![Recall, "update", recall_auth],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
+    pub fn lock_recallable(&self) -> () {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "method_auth".to_string(),
+            args: args![Recall, "lock"],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
+    pub fn set_freezable(&self, freeze_auth: AccessRule) -> () {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "method_auth".to_string(),
+            args: args![Freeze, "update", freeze_auth],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
+    pub fn lock_freezable(&self) -> () {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "method_auth".to_string(),
+            args: args![Freeze, "lock"],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
     /// Returns the metadata associated with this resource.
-    pub fn metadata(&self) -> HashMap<String, String> {
+    pub fn metadata(&self) -> BTreeMap<String, String> {
         let input = InvokeSNodeInput {
             snode_ref: SNodeRef::ResourceRef(self.0),
             function: "get_metadata".to_string(),
@@ -233,6 +288,30 @@ impl ResourceManager {
         scrypto_decode(&output.rtn).unwrap()
     }
 
+    /// Returns the max supply of this resource, if capped.
+    pub fn max_supply(&self) -> Option<Decimal> {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "get_max_supply".to_string(),
+            args: args![],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
+    /// Lowers the max supply of this resource.
+    ///
+    /// # Panics
+    /// Panics if the new max supply is above the current one or below the total supply.
+    pub fn set_max_supply<T: Into<Decimal>>(&self, max_supply: T) {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "set_max_supply".to_string(),
+            args: args![max_supply.into()],
+        };
+        let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+    }
+
     /// Returns the data of a non-fungible unit, both the immutable and mutable parts.
     ///
     /// # Panics
@@ -261,6 +340,36 @@ impl ResourceManager {
         let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
     }
 
+    /// Updates a single field of the mutable part of a non-fungible unit.
+    ///
+    /// # Panics
+    /// Panics if this resource has no data schema, the field is not a mutable field, the value
+    /// does not match the field type or the specified non-fungible is not found.
+    pub fn update_non_fungible_data_field<V: Encode>(
+        &self,
+        id: &NonFungibleId,
+        field: &str,
+        value: V,
+    ) {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "update_non_fungible_mutable_data_field".to_string(),
+            args: args![id.clone(), field.to_owned(), scrypto_encode(&value)],
+        };
+        let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+    }
+
+    /// Returns the schema of the non-fungible data, or `None` if this resource is fungible.
+    pub fn non_fungible_data_schema(&self) -> Option<NonFungibleDataSchema> {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "get_non_fungible_data_schema".to_string(),
+            args: args![],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
     /// Checks if non-fungible unit, with certain key exists or not.
     ///
     pub fn non_fungible_exists(&self, id: &NonFungibleId) -> bool {
@@ -273,8 +382,35 @@ impl ResourceManager {
         scrypto_decode(&output.rtn).unwrap()
     }
 
+    /// Returns the number of non-fungible units ever minted, including the burned ones.
+    pub fn minted_non_fungible_count(&self) -> u64 {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "get_minted_non_fungible_count".to_string(),
+            args: args![],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
+    /// Returns up to `limit` minted non-fungible ids in id order, skipping the first `offset`.
+    ///
+    /// Each id is paired with whether the non-fungible has since been burned.
+    pub fn minted_non_fungible_ids(&self, offset: u32, limit: u32) -> Vec<(NonFungibleId, bool)> {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "get_minted_non_fungible_ids".to_string(),
+            args: args![offset, limit],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
     /// Updates the resource metadata
-    pub fn update_metadata(&self, new_metadata: HashMap<String, String>) {
+    ///
+    /// # Panics
+    /// Panics if a well-known entry is invalid or a locked entry is changed.
+    pub fn update_metadata(&self, new_metadata: BTreeMap<String, String>) {
         let input = InvokeSNodeInput {
             snode_ref: SNodeRef::ResourceRef(self.0),
             function: "update_metadata".to_string(),
@@ -282,6 +418,40 @@ impl ResourceManager {
         };
         let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
     }
+
+    /// Sets a single resource metadata, keeping the others.
+    ///
+    /// # Panics
+    /// Panics if the entry is a well-known one with an invalid value, or is locked.
+    pub fn set_metadata<K: AsRef<str>, V: AsRef<str>>(&self, name: K, value: V) {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "set_metadata".to_string(),
+            args: args![name.as_ref().to_owned(), value.as_ref().to_owned()],
+        };
+        let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+    }
+
+    /// Locks a resource metadata, so that it can never be updated again.
+    pub fn lock_metadata<K: AsRef<str>>(&self, name: K) {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "lock_metadata".to_string(),
+            args: args![name.as_ref().to_owned()],
+        };
+        let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+    }
+
+    /// Returns the names of the locked resource metadata.
+    pub fn locked_metadata(&self) -> BTreeSet<String> {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::ResourceRef(self.0),
+            function: "get_locked_metadata".to_string(),
+            args: args![],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
 }
 
 //========
@@ -0,0 +1,168 @@
+use sbor::*;
+
+use crate::rust::borrow::ToOwned;
+use crate::rust::string::String;
+
+/// The maximum length of a resource symbol.
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+
+/// Represents a well-known resource metadata key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, TypeId, Encode, Decode, Describe)]
+pub enum MetadataKey {
+    /// The ticker symbol, e.g. `XRD`.
+    Symbol,
+    /// The human-readable name.
+    Name,
+    /// A free-form description.
+    Description,
+    /// The website URL.
+    Url,
+    /// The icon URL.
+    IconUrl,
+}
+
+/// Represents an error when validating resource metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataError {
+    InvalidSymbol(String),
+    InvalidUrl(String),
+}
+
+impl MetadataKey {
+    /// Returns the key under which this entry is stored.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Symbol => "symbol",
+            Self::Name => "name",
+            Self::Description => "description",
+            Self::Url => "url",
+            Self::IconUrl => "icon_url",
+        }
+    }
+
+    /// Returns the well-known key with the given name, if any.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "symbol" => Some(Self::Symbol),
+            "name" => Some(Self::Name),
+            "description" => Some(Self::Description),
+            "url" => Some(Self::Url),
+            "icon_url" => Some(Self::IconUrl),
+            _ => None,
+        }
+    }
+
+    /// Checks whether a value is valid for this key.
+    pub fn validate(&self, value: &str) -> Result<(), MetadataError> {
+        match self {
+            Self::Symbol => {
+                if value.is_empty()
+                    || value.len() > MAX_SYMBOL_LENGTH
+                    || !value.chars().all(|c| c.is_ascii_alphanumeric())
+                {
+                    return Err(MetadataError::InvalidSymbol(value.to_owned()));
+                }
+            }
+            Self::Url | Self::IconUrl => {
+                if !is_valid_url(value) {
+                    return Err(MetadataError::InvalidUrl(value.to_owned()));
+                }
+            }
+            Self::Name | Self::Description => {}
+        }
+        Ok(())
+    }
+}
+
+impl AsRef<str> for MetadataKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Checks a metadata entry, of which only the well-known keys are constrained.
+pub fn validate_metadata(key: &str, value: &str) -> Result<(), MetadataError> {
+    match MetadataKey::from_key(key) {
+        Some(key) => key.validate(value),
+        None => Ok(()),
+    }
+}
+
+/// Accepts `http` and `https` URLs with a non-empty host and no whitespace.
+fn is_valid_url(url: &str) -> bool {
+    let rest = match url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let host = rest.split(|c| c == '/' || c == '?' || c == '#').next();
+    match host {
+        Some(host) if !host.is_empty() => {
+            host.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == ':')
+                && !rest.chars().any(|c| c.is_whitespace() || c.is_control())
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_symbol() {
+        assert_eq!(validate_metadata("symbol", "XRD"), Ok(()));
+        assert_eq!(validate_metadata("symbol", "xUSDC2"), Ok(()));
+        assert_eq!(
+            validate_metadata("symbol", ""),
+            Err(MetadataError::InvalidSymbol("".to_owned()))
+        );
+        assert_eq!(
+            validate_metadata("symbol", "X RD"),
+            Err(MetadataError::InvalidSymbol("X RD".to_owned()))
+        );
+        assert_eq!(
+            validate_metadata("symbol", "ABCDEFGHIJK"),
+            Err(MetadataError::InvalidSymbol("ABCDEFGHIJK".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_validate_url() {
+        assert_eq!(
+            validate_metadata("url", "https://tokens.radixdlt.com"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_metadata("icon_url", "http://localhost:8080/icon.png?size=64"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_metadata("url", "tokens.radixdlt.com"),
+            Err(MetadataError::InvalidUrl("tokens.radixdlt.com".to_owned()))
+        );
+        assert_eq!(
+            validate_metadata("url", "https:///path"),
+            Err(MetadataError::InvalidUrl("https:///path".to_owned()))
+        );
+        assert_eq!(
+            validate_metadata("icon_url", "https://radixdlt.com/my icon.png"),
+            Err(MetadataError::InvalidUrl(
+                "https://radixdlt.com/my icon.png".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys_are_not_constrained() {
+        assert_eq!(validate_metadata("anything", " goes "), Ok(()));
+        assert_eq!(
+            MetadataKey::from_key("icon_url"),
+            Some(MetadataKey::IconUrl)
+        );
+        assert_eq!(MetadataKey::IconUrl.as_str(), "icon_url");
+    }
+}
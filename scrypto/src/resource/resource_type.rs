@@ -1,5 +1,7 @@
 use sbor::*;
 
+use crate::resource::NonFungibleIdType;
+
 /// Represents the type of a resource.
 #[derive(Debug, Clone, Copy, TypeId, Encode, Decode, Describe, Eq, PartialEq)]
 pub enum ResourceType {
@@ -7,14 +9,14 @@ pub enum ResourceType {
     Fungible { divisibility: u8 },
 
     /// Represents a non-fungible resource
-    NonFungible,
+    NonFungible { id_type: NonFungibleIdType },
 }
 
 impl ResourceType {
     pub fn divisibility(&self) -> u8 {
         match self {
             ResourceType::Fungible { divisibility } => *divisibility,
-            ResourceType::NonFungible => 0,
+            ResourceType::NonFungible { .. } => 0,
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::resource::schema_path::SchemaSubPath::{Field, Index};
+use crate::rust::fmt;
 use crate::rust::str::FromStr;
 use crate::rust::string::String;
 use crate::rust::string::ToString;
@@ -116,3 +117,18 @@ impl FromStr for SchemaPath {
         Ok(schema_path)
     }
 }
+
+impl fmt::Display for SchemaPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, sub_path) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, "/")?;
+            }
+            match sub_path {
+                SchemaSubPath::Index(index) => write!(f, "{}", index)?,
+                SchemaSubPath::Field(field) => write!(f, "{}", field)?,
+            }
+        }
+        Ok(())
+    }
+}
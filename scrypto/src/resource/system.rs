@@ -2,8 +2,9 @@ use crate::args;
 use crate::buffer::scrypto_decode;
 use crate::core::SNodeRef;
 use crate::engine::{api::*, call_engine};
+use crate::math::*;
 use crate::resource::*;
-use crate::rust::collections::HashMap;
+use crate::rust::collections::{BTreeSet, HashMap};
 use crate::rust::string::String;
 use crate::rust::string::ToString;
 
@@ -44,11 +45,22 @@ impl ResourceSystem {
         metadata: HashMap<String, String>,
         authorization: HashMap<ResourceMethod, (AccessRule, Mutability)>,
         mint_params: Option<MintParams>,
+        max_supply: Option<Decimal>,
+        data_schema: Option<NonFungibleDataSchema>,
+        locked_metadata: BTreeSet<String>,
     ) -> (ResourceAddress, Option<Bucket>) {
         let input = InvokeSNodeInput {
             snode_ref: SNodeRef::ResourceStatic,
             function: "create".to_string(),
-            args: args![resource_type, metadata, authorization, mint_params],
+            args: args![
+                resource_type,
+                metadata,
+                authorization,
+                mint_params,
+                max_supply,
+                data_schema,
+                locked_metadata
+            ],
         };
         let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
         scrypto_decode(&output.rtn).unwrap()
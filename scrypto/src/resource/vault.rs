@@ -91,6 +91,55 @@ impl Vault {
         scrypto_decode(&output.rtn).unwrap()
     }
 
+    /// Recalls some amount of resource from this vault into a bucket, even if frozen.
+    ///
+    /// Requires the recall authority of the resource.
+    pub fn recall<A: Into<Decimal>>(&mut self, amount: A) -> Bucket {
+        let amount: Decimal = amount.into();
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::VaultRef(self.0),
+            function: "recall_from_vault".to_string(),
+            args: args![amount],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
+    /// Freezes this vault, so that no resource can be taken from it.
+    ///
+    /// Requires the freeze authority of the resource.
+    pub fn freeze(&mut self) {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::VaultRef(self.0),
+            function: "freeze_vault".to_string(),
+            args: args![],
+        };
+        let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+    }
+
+    /// Unfreezes this vault.
+    ///
+    /// Requires the freeze authority of the resource.
+    pub fn unfreeze(&mut self) {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::VaultRef(self.0),
+            function: "unfreeze_vault".to_string(),
+            args: args![],
+        };
+        let _: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+    }
+
+    /// Checks if this vault is frozen.
+    pub fn is_frozen(&self) -> bool {
+        let input = InvokeSNodeInput {
+            snode_ref: SNodeRef::VaultRef(self.0),
+            function: "is_vault_frozen".to_string(),
+            args: args![],
+        };
+        let output: InvokeSNodeOutput = call_engine(INVOKE_SNODE, input);
+        scrypto_decode(&output.rtn).unwrap()
+    }
+
     /// Creates an ownership proof of this vault.
     pub fn create_proof(&self) -> Proof {
         let input = InvokeSNodeInput {
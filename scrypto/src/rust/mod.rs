@@ -17,6 +17,8 @@ pub use alloc::vec;
 #[cfg(feature = "alloc")]
 pub use core::cell;
 #[cfg(feature = "alloc")]
+pub use core::cmp;
+#[cfg(feature = "alloc")]
 pub use core::convert;
 #[cfg(feature = "alloc")]
 pub use core::marker;
@@ -32,6 +34,8 @@ pub use std::borrow;
 #[cfg(not(feature = "alloc"))]
 pub use std::cell;
 #[cfg(not(feature = "alloc"))]
+pub use std::cmp;
+#[cfg(not(feature = "alloc"))]
 pub use std::convert;
 #[cfg(not(feature = "alloc"))]
 pub use std::fmt;
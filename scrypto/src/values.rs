@@ -1,6 +1,7 @@
 use sbor::type_id::*;
 use sbor::{any::*, *};
 use sbor::path::{MutableSborPath, SborPath};
+use sbor::describe::Fields;
 
 use crate::buffer::*;
 use crate::component::*;
@@ -108,6 +109,174 @@ impl ScryptoValue {
         ScryptoValue::from_slice(&scrypto_encode(value)).unwrap()
     }
 
+    /// Checks whether this value conforms to the given schema.
+    pub fn matches_schema(&self, schema: &Type) -> bool {
+        Self::value_matches_schema(&self.dom, schema)
+    }
+
+    fn value_matches_schema(value: &Value, schema: &Type) -> bool {
+        match (value, schema) {
+            (Value::Unit, Type::Unit)
+            | (Value::Bool { .. }, Type::Bool)
+            | (Value::I8 { .. }, Type::I8)
+            | (Value::I16 { .. }, Type::I16)
+            | (Value::I32 { .. }, Type::I32)
+            | (Value::I64 { .. }, Type::I64)
+            | (Value::I128 { .. }, Type::I128)
+            | (Value::U8 { .. }, Type::U8)
+            | (Value::U16 { .. }, Type::U16)
+            | (Value::U32 { .. }, Type::U32)
+            | (Value::U64 { .. }, Type::U64)
+            | (Value::U128 { .. }, Type::U128)
+            | (Value::String { .. }, Type::String) => true,
+            (Value::Struct { fields }, Type::Struct { fields: schema, .. }) => {
+                Self::fields_match_schema(fields, schema)
+            }
+            (Value::Enum { name, fields }, Type::Enum { variants, .. }) => variants
+                .iter()
+                .find(|v| v.name == *name)
+                .map(|v| Self::fields_match_schema(fields, &v.fields))
+                .unwrap_or(false),
+            (Value::Option { value }, Type::Option { value: schema }) => match value.borrow() {
+                Some(v) => Self::value_matches_schema(v, schema),
+                None => true,
+            },
+            (
+                Value::Array {
+                    element_type_id,
+                    elements,
+                },
+                Type::Array { element, length },
+            ) => {
+                elements.len() == *length as usize
+                    && Self::elements_match_schema(*element_type_id, elements, element)
+            }
+            (Value::Tuple { elements }, Type::Tuple { elements: schema }) => {
+                elements.len() == schema.len()
+                    && elements
+                        .iter()
+                        .zip(schema)
+                        .all(|(v, t)| Self::value_matches_schema(v, t))
+            }
+            (Value::Result { value }, Type::Result { okay, error }) => match value.borrow() {
+                Ok(v) => Self::value_matches_schema(v, okay),
+                Err(v) => Self::value_matches_schema(v, error),
+            },
+            (
+                Value::Vec {
+                    element_type_id,
+                    elements,
+                },
+                Type::Vec { element },
+            )
+            | (
+                Value::TreeSet {
+                    element_type_id,
+                    elements,
+                },
+                Type::TreeSet { element },
+            )
+            | (
+                Value::HashSet {
+                    element_type_id,
+                    elements,
+                },
+                Type::HashSet { element },
+            ) => Self::elements_match_schema(*element_type_id, elements, element),
+            (
+                Value::TreeMap {
+                    key_type_id,
+                    value_type_id,
+                    elements,
+                },
+                Type::TreeMap { key, value },
+            )
+            | (
+                Value::HashMap {
+                    key_type_id,
+                    value_type_id,
+                    elements,
+                },
+                Type::HashMap { key, value },
+            ) => {
+                Self::type_id_matches_schema(*key_type_id, key)
+                    && Self::type_id_matches_schema(*value_type_id, value)
+                    && elements.chunks(2).all(|pair| {
+                        Self::value_matches_schema(&pair[0], key)
+                            && Self::value_matches_schema(&pair[1], value)
+                    })
+            }
+            (Value::Custom { type_id, .. }, Type::Custom { name, .. }) => {
+                ScryptoType::from_id(*type_id)
+                    .map(|t| t.name() == *name)
+                    .unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+
+    fn fields_match_schema(fields: &[Value], schema: &Fields) -> bool {
+        match schema {
+            Fields::Named { named } => {
+                fields.len() == named.len()
+                    && fields
+                        .iter()
+                        .zip(named)
+                        .all(|(v, (_, t))| Self::value_matches_schema(v, t))
+            }
+            Fields::Unnamed { unnamed } => {
+                fields.len() == unnamed.len()
+                    && fields
+                        .iter()
+                        .zip(unnamed)
+                        .all(|(v, t)| Self::value_matches_schema(v, t))
+            }
+            Fields::Unit => fields.is_empty(),
+        }
+    }
+
+    fn elements_match_schema(element_type_id: u8, elements: &[Value], schema: &Type) -> bool {
+        // The element type is checked separately, as collections may be empty
+        Self::type_id_matches_schema(element_type_id, schema)
+            && elements
+                .iter()
+                .all(|e| Self::value_matches_schema(e, schema))
+    }
+
+    fn type_id_matches_schema(type_id: u8, schema: &Type) -> bool {
+        let expected = match schema {
+            Type::Unit => TYPE_UNIT,
+            Type::Bool => TYPE_BOOL,
+            Type::I8 => TYPE_I8,
+            Type::I16 => TYPE_I16,
+            Type::I32 => TYPE_I32,
+            Type::I64 => TYPE_I64,
+            Type::I128 => TYPE_I128,
+            Type::U8 => TYPE_U8,
+            Type::U16 => TYPE_U16,
+            Type::U32 => TYPE_U32,
+            Type::U64 => TYPE_U64,
+            Type::U128 => TYPE_U128,
+            Type::String => TYPE_STRING,
+            Type::Option { .. } => TYPE_OPTION,
+            Type::Array { .. } => TYPE_ARRAY,
+            Type::Tuple { .. } => TYPE_TUPLE,
+            Type::Struct { .. } => TYPE_STRUCT,
+            Type::Enum { .. } => TYPE_ENUM,
+            Type::Result { .. } => TYPE_RESULT,
+            Type::Vec { .. } => TYPE_VEC,
+            Type::TreeSet { .. } => TYPE_TREE_SET,
+            Type::TreeMap { .. } => TYPE_TREE_MAP,
+            Type::HashSet { .. } => TYPE_HASH_SET,
+            Type::HashMap { .. } => TYPE_HASH_MAP,
+            Type::Custom { name, .. } => match ScryptoType::from_name(name) {
+                Some(t) => t.id(),
+                None => return false,
+            },
+        };
+        type_id == expected
+    }
+
     pub fn to_string(&self) -> String {
         ScryptoValueFormatter::format_value(&self.dom, &HashMap::new(), &HashMap::new())
     }
@@ -147,6 +316,9 @@ pub enum ScryptoCustomValueCheckError {
     DecodeError(DecodeError),
     InvalidTypeId(u8),
     InvalidDecimal(ParseDecimalError),
+    InvalidPreciseDecimal(ParsePreciseDecimalError),
+    InvalidI256(ParseIntegerError),
+    InvalidU256(ParseIntegerError),
     InvalidPackageAddress(ParsePackageAddressError),
     InvalidComponentAddress(ParseComponentAddressError),
     InvalidResourceAddress(ParseResourceAddressError),
@@ -207,6 +379,16 @@ impl CustomValueVisitor for ScryptoCustomValueChecker {
             ScryptoType::Decimal => {
                 Decimal::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidDecimal)?;
             }
+            ScryptoType::PreciseDecimal => {
+                PreciseDecimal::try_from(data)
+                    .map_err(ScryptoCustomValueCheckError::InvalidPreciseDecimal)?;
+            }
+            ScryptoType::I256 => {
+                I256::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidI256)?;
+            }
+            ScryptoType::U256 => {
+                U256::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidU256)?;
+            }
             ScryptoType::Bucket => {
                 let bucket = Bucket::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidBucket)?;
                 if self.buckets.insert(bucket, path.clone().into()).is_some() {
@@ -421,6 +603,22 @@ impl ScryptoValueFormatter {
         }
         buf
     }
+
+    /// Formats a non-fungible id as a manifest literal, using number literals for integer ids.
+    pub fn format_non_fungible_id(non_fungible_id: &NonFungibleId) -> String {
+        match non_fungible_id {
+            NonFungibleId::U32(u) => format!("NonFungibleId({}u32)", u),
+            NonFungibleId::U64(u) => format!("NonFungibleId({}u64)", u),
+            _ => format!(
+                "NonFungibleId(\"{}\")",
+                non_fungible_id
+                    .to_string()
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+            ),
+        }
+    }
+
     pub fn from_custom_value(
         type_id: u8,
         data: &[u8],
@@ -429,6 +627,12 @@ impl ScryptoValueFormatter {
     ) -> String {
         match ScryptoType::from_id(type_id).unwrap() {
             ScryptoType::Decimal => format!("Decimal(\"{}\")", Decimal::try_from(data).unwrap()),
+            ScryptoType::PreciseDecimal => format!(
+                "PreciseDecimal(\"{}\")",
+                PreciseDecimal::try_from(data).unwrap()
+            ),
+            ScryptoType::I256 => format!("I256(\"{}\")", I256::try_from(data).unwrap()),
+            ScryptoType::U256 => format!("U256(\"{}\")", U256::try_from(data).unwrap()),
             ScryptoType::PackageAddress => {
                 format!(
                     "PackageAddress(\"{}\")",
@@ -475,10 +679,9 @@ impl ScryptoValueFormatter {
                 }
             }
             ScryptoType::Vault => format!("Vault(\"{}\")", Vault::try_from(data).unwrap()),
-            ScryptoType::NonFungibleId => format!(
-                "NonFungibleId(\"{}\")",
-                NonFungibleId::try_from(data).unwrap()
-            ),
+            ScryptoType::NonFungibleId => {
+                Self::format_non_fungible_id(&NonFungibleId::try_from(data).unwrap())
+            }
             ScryptoType::NonFungibleAddress => format!(
                 "NonFungibleAddress(\"{}\")",
                 NonFungibleAddress::try_from(data).unwrap()
@@ -494,6 +697,8 @@ impl ScryptoValueFormatter {
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::rust::borrow::ToOwned;
+    use crate::rust::str::FromStr;
     use super::rust::vec;
     use super::*;
 
@@ -511,4 +716,33 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn should_format_high_precision_numbers() {
+        let value = ScryptoValue::from_value(&(
+            PreciseDecimal::from_str("-1.5").unwrap(),
+            I256::from(-7),
+            U256::MAX,
+        ));
+        assert_eq!(
+            value.to_string(),
+            "Tuple(PreciseDecimal(\"-1.5\"), I256(\"-7\"), U256(\"115792089237316195423570985008687907853269984665640564039457584007913129639935\"))"
+        );
+    }
+
+    #[test]
+    fn should_match_schema() {
+        let value = ScryptoValue::from_value(&(
+            1u32,
+            Some("name".to_owned()),
+            vec![Decimal::one()],
+        ));
+        let schema = <(u32, Option<String>, Vec<Decimal>)>::describe();
+        assert!(value.matches_schema(&schema));
+
+        let schema = <(u32, Option<String>, Vec<u8>)>::describe();
+        assert!(!value.matches_schema(&schema));
+        let schema = <(u32, Option<String>)>::describe();
+        assert!(!value.matches_schema(&schema));
+    }
 }
@@ -0,0 +1,535 @@
+use radix_engine::ledger::*;
+use radix_engine::model::*;
+use sbor::describe::Fields;
+use sbor::type_id::*;
+use sbor::{Type, Value};
+use scrypto::component::LazyMap;
+use scrypto::engine::types::*;
+use scrypto::rust::borrow::Borrow;
+use scrypto::rust::collections::HashMap;
+use scrypto::types::*;
+use scrypto::values::*;
+use serde_json::json;
+
+/// A value in component state, decoded with the blueprint schema when available.
+#[derive(Debug, Clone)]
+pub enum DecodedValue {
+    /// A value without inner structure
+    Plain(Value),
+
+    /// A struct, enum variant, tuple, option or result
+    Composite {
+        name: String,
+        /// The fields, with names when known
+        fields: Vec<(Option<String>, DecodedValue)>,
+    },
+
+    /// An array, vec or set
+    Collection {
+        name: String,
+        elements: Vec<DecodedValue>,
+    },
+
+    /// A tree map or hash map
+    Map {
+        name: String,
+        entries: Vec<(DecodedValue, DecodedValue)>,
+    },
+
+    /// A vault, with its resource and balance
+    Vault {
+        id: String,
+        resource_address: ResourceAddress,
+        symbol: Option<String>,
+        amount: Decimal,
+    },
+
+    /// A lazy map; entries are not loaded beyond the expansion depth
+    LazyMap {
+        id: String,
+        entries: Option<Vec<(DecodedValue, DecodedValue)>>,
+    },
+}
+
+/// Decodes component state, expanding lazy maps nested at most `depth` levels deep.
+pub fn decode_state<T: SubstateStore + QueryableSubstateStore>(
+    component_address: ComponentAddress,
+    state: &[u8],
+    schema: Option<&Type>,
+    depth: Option<usize>,
+    substate_store: &T,
+) -> DecodedValue {
+    let decoder = StateDecoder {
+        component_address,
+        depth,
+        substate_store,
+    };
+    let value = ScryptoValue::from_slice(state).unwrap();
+    decoder.decode(&value.dom, schema, 0)
+}
+
+struct StateDecoder<'s, T: SubstateStore + QueryableSubstateStore> {
+    component_address: ComponentAddress,
+    depth: Option<usize>,
+    substate_store: &'s T,
+}
+
+impl<'s, T: SubstateStore + QueryableSubstateStore> StateDecoder<'s, T> {
+    /// Decodes a value; `level` is the number of lazy maps it's nested in.
+    fn decode(&self, value: &Value, ty: Option<&Type>, level: usize) -> DecodedValue {
+        match value {
+            Value::Struct { fields } => {
+                let (name, field_types) = match ty {
+                    Some(Type::Struct { name, fields }) => (name.clone(), Some(fields)),
+                    _ => ("Struct".to_owned(), None),
+                };
+                DecodedValue::Composite {
+                    name,
+                    fields: self.decode_fields(fields, field_types, level),
+                }
+            }
+            Value::Enum { name, fields } => {
+                let (name, field_types) = match ty {
+                    Some(Type::Enum {
+                        name: enum_name,
+                        variants,
+                    }) => (
+                        format!("{}::{}", enum_name, name),
+                        variants.iter().find(|v| &v.name == name).map(|v| &v.fields),
+                    ),
+                    _ => (name.clone(), None),
+                };
+                DecodedValue::Composite {
+                    name,
+                    fields: self.decode_fields(fields, field_types, level),
+                }
+            }
+            Value::Option { value } => match value.borrow() {
+                Some(x) => {
+                    let ty = match ty {
+                        Some(Type::Option { value }) => Some(value.as_ref()),
+                        _ => None,
+                    };
+                    DecodedValue::Composite {
+                        name: "Some".to_owned(),
+                        fields: vec![(None, self.decode(x, ty, level))],
+                    }
+                }
+                None => DecodedValue::Plain(Value::Option {
+                    value: Box::new(None),
+                }),
+            },
+            Value::Result { value } => {
+                let (name, x, ty) = match (value.borrow(), ty) {
+                    (Ok(x), Some(Type::Result { okay, .. })) => ("Ok", x, Some(okay.as_ref())),
+                    (Ok(x), _) => ("Ok", x, None),
+                    (Err(x), Some(Type::Result { error, .. })) => ("Err", x, Some(error.as_ref())),
+                    (Err(x), _) => ("Err", x, None),
+                };
+                DecodedValue::Composite {
+                    name: name.to_owned(),
+                    fields: vec![(None, self.decode(x, ty, level))],
+                }
+            }
+            Value::Tuple { elements } => {
+                let types = match ty {
+                    Some(Type::Tuple { elements }) => Some(elements),
+                    _ => None,
+                };
+                DecodedValue::Composite {
+                    name: "Tuple".to_owned(),
+                    fields: elements
+                        .iter()
+                        .enumerate()
+                        .map(|(i, e)| (None, self.decode(e, types.and_then(|t| t.get(i)), level)))
+                        .collect(),
+                }
+            }
+            Value::Vec {
+                element_type_id, ..
+            } if *element_type_id == TYPE_U8 => DecodedValue::Plain(value.clone()),
+            Value::Array {
+                element_type_id,
+                elements,
+            }
+            | Value::Vec {
+                element_type_id,
+                elements,
+            }
+            | Value::TreeSet {
+                element_type_id,
+                elements,
+            }
+            | Value::HashSet {
+                element_type_id,
+                elements,
+            } => {
+                let element_type = match ty {
+                    Some(Type::Array { element, .. })
+                    | Some(Type::Vec { element })
+                    | Some(Type::TreeSet { element })
+                    | Some(Type::HashSet { element }) => Some(element.as_ref()),
+                    _ => None,
+                };
+                DecodedValue::Collection {
+                    name: format!(
+                        "{}<{}>",
+                        collection_name(value),
+                        ScryptoValueFormatter::format_type_id(*element_type_id)
+                    ),
+                    elements: elements
+                        .iter()
+                        .map(|e| self.decode(e, element_type, level))
+                        .collect(),
+                }
+            }
+            Value::TreeMap {
+                key_type_id,
+                value_type_id,
+                elements,
+            }
+            | Value::HashMap {
+                key_type_id,
+                value_type_id,
+                elements,
+            } => {
+                let (key_type, value_type) = match ty {
+                    Some(Type::TreeMap { key, value }) | Some(Type::HashMap { key, value }) => {
+                        (Some(key.as_ref()), Some(value.as_ref()))
+                    }
+                    _ => (None, None),
+                };
+                DecodedValue::Map {
+                    name: format!(
+                        "{}<{}, {}>",
+                        collection_name(value),
+                        ScryptoValueFormatter::format_type_id(*key_type_id),
+                        ScryptoValueFormatter::format_type_id(*value_type_id)
+                    ),
+                    entries: elements
+                        .chunks(2)
+                        .map(|pair| {
+                            (
+                                self.decode(&pair[0], key_type, level),
+                                self.decode(&pair[1], value_type, level),
+                            )
+                        })
+                        .collect(),
+                }
+            }
+            Value::Custom { type_id, bytes } => match ScryptoType::from_id(*type_id) {
+                Some(ScryptoType::Vault) => self.decode_vault(bytes),
+                Some(ScryptoType::LazyMap) => self.decode_lazy_map(bytes, ty, level + 1),
+                _ => DecodedValue::Plain(value.clone()),
+            },
+            _ => DecodedValue::Plain(value.clone()),
+        }
+    }
+
+    fn decode_fields(
+        &self,
+        fields: &[Value],
+        field_types: Option<&Fields>,
+        level: usize,
+    ) -> Vec<(Option<String>, DecodedValue)> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match field_types {
+                Some(Fields::Named { named }) if named.len() == fields.len() => (
+                    Some(named[i].0.clone()),
+                    self.decode(field, Some(&named[i].1), level),
+                ),
+                Some(Fields::Unnamed { unnamed }) if unnamed.len() == fields.len() => {
+                    (None, self.decode(field, Some(&unnamed[i]), level))
+                }
+                _ => (None, self.decode(field, None, level)),
+            })
+            .collect()
+    }
+
+    fn decode_vault(&self, bytes: &[u8]) -> DecodedValue {
+        let vault_id = scrypto::resource::Vault::try_from(bytes).unwrap();
+        let vault: Vault = self
+            .substate_store
+            .get_decoded_child_substate(&self.component_address, &vault_id.0)
+            .unwrap()
+            .0;
+        let resource_address = vault.resource_address();
+        let resource_manager: ResourceManager = self
+            .substate_store
+            .get_decoded_substate(&resource_address)
+            .map(|(resource, _)| resource)
+            .unwrap();
+        DecodedValue::Vault {
+            id: vault_id.to_string(),
+            resource_address,
+            symbol: resource_manager.metadata().get("symbol").cloned(),
+            amount: vault.total_amount(),
+        }
+    }
+
+    fn decode_lazy_map(&self, bytes: &[u8], ty: Option<&Type>, level: usize) -> DecodedValue {
+        let lazy_map = LazyMap::<(), ()>::try_from(bytes).unwrap();
+        if matches!(self.depth, Some(depth) if level > depth) {
+            return DecodedValue::LazyMap {
+                id: lazy_map.to_string(),
+                entries: None,
+            };
+        }
+
+        let (key_type, value_type) = match ty {
+            Some(Type::Custom { generics, .. }) if generics.len() == 2 => {
+                (Some(&generics[0]), Some(&generics[1]))
+            }
+            _ => (None, None),
+        };
+        let map = self
+            .substate_store
+            .get_lazy_map_entries(self.component_address, &lazy_map.id);
+        let mut entries: Vec<(DecodedValue, DecodedValue)> = map
+            .iter()
+            .map(|(k, v)| {
+                let k = ScryptoValue::from_slice(k).unwrap();
+                let v = ScryptoValue::from_slice(v).unwrap();
+                (
+                    self.decode(&k.dom, key_type, level),
+                    self.decode(&v.dom, value_type, level),
+                )
+            })
+            .collect();
+        // Lazy map entries are unordered in the store; sort them for a stable output.
+        entries.sort_by_cached_key(|(k, _)| k.to_text(0));
+
+        DecodedValue::LazyMap {
+            id: lazy_map.to_string(),
+            entries: Some(entries),
+        }
+    }
+}
+
+fn collection_name(value: &Value) -> &'static str {
+    match value {
+        Value::Array { .. } => "Array",
+        Value::Vec { .. } => "Vec",
+        Value::TreeSet { .. } => "TreeSet",
+        Value::HashSet { .. } => "HashSet",
+        Value::TreeMap { .. } => "TreeMap",
+        Value::HashMap { .. } => "HashMap",
+        _ => panic!("Not a collection"),
+    }
+}
+
+fn format_plain(value: &Value) -> String {
+    ScryptoValueFormatter::format_value(value, &HashMap::new(), &HashMap::new())
+}
+
+impl DecodedValue {
+    /// Formats the value as an indented tree, with nested lines indented by `indent` levels.
+    pub fn to_text(&self, indent: usize) -> String {
+        let prefix = "  ".repeat(indent + 1);
+        let closing_prefix = "  ".repeat(indent);
+        let block = |header: String, open: &str, close: &str, lines: Vec<String>| {
+            if lines.is_empty() {
+                format!("{} {}{}", header, open, close)
+            } else {
+                format!(
+                    "{} {}\n{}\n{}{}",
+                    header,
+                    open,
+                    lines
+                        .iter()
+                        .map(|line| format!("{}{}", prefix, line))
+                        .collect::<Vec<String>>()
+                        .join("\n"),
+                    closing_prefix,
+                    close
+                )
+            }
+        };
+        let entry_lines = |entries: &[(DecodedValue, DecodedValue)]| {
+            entries
+                .iter()
+                .map(|(k, v)| format!("{} => {}", k.to_text(indent + 1), v.to_text(indent + 1)))
+                .collect::<Vec<String>>()
+        };
+
+        match self {
+            DecodedValue::Plain(value) => format_plain(value),
+            DecodedValue::Composite { name, fields } => {
+                let named = fields.iter().any(|(field_name, _)| field_name.is_some());
+                let lines = fields
+                    .iter()
+                    .map(|(field_name, field)| match field_name {
+                        Some(field_name) => {
+                            format!("{}: {}", field_name, field.to_text(indent + 1))
+                        }
+                        None => field.to_text(indent + 1),
+                    })
+                    .collect();
+                if named {
+                    block(name.clone(), "{", "}", lines)
+                } else if fields.is_empty() {
+                    name.clone()
+                } else {
+                    block(name.clone(), "(", ")", lines)
+                }
+            }
+            DecodedValue::Collection { name, elements } => block(
+                name.clone(),
+                "[",
+                "]",
+                elements.iter().map(|e| e.to_text(indent + 1)).collect(),
+            ),
+            DecodedValue::Map { name, entries } => {
+                block(name.clone(), "{", "}", entry_lines(entries))
+            }
+            DecodedValue::Vault {
+                id,
+                resource_address,
+                symbol,
+                amount,
+            } => format!(
+                "Vault(\"{}\") {{ amount: {}, resource address: {}{} }}",
+                id,
+                amount,
+                resource_address,
+                symbol
+                    .as_ref()
+                    .map(|symbol| format!(", symbol: \"{}\"", symbol))
+                    .unwrap_or_default()
+            ),
+            DecodedValue::LazyMap { id, entries } => match entries {
+                Some(entries) => block(
+                    format!("LazyMap(\"{}\")", id),
+                    "{",
+                    "}",
+                    entry_lines(entries),
+                ),
+                None => format!("LazyMap(\"{}\") {{ ... }}", id),
+            },
+        }
+    }
+
+    /// Converts the value into JSON.
+    pub fn to_json(&self) -> serde_json::Value {
+        let entries_to_json = |entries: &[(DecodedValue, DecodedValue)]| {
+            entries
+                .iter()
+                .map(|(k, v)| json!({ "key": k.to_json(), "value": v.to_json() }))
+                .collect::<Vec<serde_json::Value>>()
+        };
+
+        match self {
+            DecodedValue::Plain(value) => match value {
+                Value::Unit => serde_json::Value::Null,
+                Value::Bool { value } => json!(value),
+                Value::I8 { value } => json!(value),
+                Value::I16 { value } => json!(value),
+                Value::I32 { value } => json!(value),
+                Value::I64 { value } => json!(value),
+                Value::U8 { value } => json!(value),
+                Value::U16 { value } => json!(value),
+                Value::U32 { value } => json!(value),
+                Value::U64 { value } => json!(value),
+                Value::I128 { value } => json!(value.to_string()),
+                Value::U128 { value } => json!(value.to_string()),
+                Value::String { value } => json!(value),
+                _ => json!(format_plain(value)),
+            },
+            DecodedValue::Composite { name, fields } => {
+                if fields.iter().all(|(field_name, _)| field_name.is_some()) && !fields.is_empty() {
+                    let mut object = serde_json::Map::new();
+                    for (field_name, field) in fields {
+                        object.insert(field_name.clone().unwrap(), field.to_json());
+                    }
+                    json!({ "type": name, "fields": object })
+                } else {
+                    json!({
+                        "type": name,
+                        "fields": fields.iter().map(|(_, f)| f.to_json()).collect::<Vec<serde_json::Value>>()
+                    })
+                }
+            }
+            DecodedValue::Collection { name, elements } => json!({
+                "type": name,
+                "elements": elements.iter().map(|e| e.to_json()).collect::<Vec<serde_json::Value>>()
+            }),
+            DecodedValue::Map { name, entries } => json!({
+                "type": name,
+                "entries": entries_to_json(entries)
+            }),
+            DecodedValue::Vault {
+                id,
+                resource_address,
+                symbol,
+                amount,
+            } => json!({
+                "type": "Vault",
+                "vault_id": id,
+                "resource_address": resource_address.to_string(),
+                "symbol": symbol,
+                "amount": amount.to_string()
+            }),
+            DecodedValue::LazyMap { id, entries } => json!({
+                "type": "LazyMap",
+                "lazy_map_id": id,
+                "entries": entries.as_ref().map(|entries| entries_to_json(entries))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_named_fields() {
+        let value = DecodedValue::Composite {
+            name: "Counter".to_owned(),
+            fields: vec![
+                (
+                    Some("count".to_owned()),
+                    DecodedValue::Plain(Value::U32 { value: 5 }),
+                ),
+                (
+                    Some("tags".to_owned()),
+                    DecodedValue::Collection {
+                        name: "Vec<String>".to_owned(),
+                        elements: vec![DecodedValue::Plain(Value::String {
+                            value: "a".to_owned(),
+                        })],
+                    },
+                ),
+            ],
+        };
+        assert_eq!(
+            value.to_text(0),
+            "Counter {\n  count: 5u32\n  tags: Vec<String> [\n    \"a\"\n  ]\n}"
+        );
+        assert_eq!(
+            value.to_json(),
+            json!({
+                "type": "Counter",
+                "fields": {
+                    "count": 5,
+                    "tags": { "type": "Vec<String>", "elements": ["a"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_format_unexpanded_lazy_map() {
+        let value = DecodedValue::LazyMap {
+            id: "00".to_owned(),
+            entries: None,
+        };
+        assert_eq!(value.to_text(0), "LazyMap(\"00\") { ... }");
+        assert_eq!(
+            value.to_json(),
+            json!({ "type": "LazyMap", "lazy_map_id": "00", "entries": null })
+        );
+    }
+}
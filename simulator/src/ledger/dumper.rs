@@ -2,11 +2,15 @@
 use colored::*;
 use radix_engine::ledger::*;
 use radix_engine::model::*;
+use sbor::describe::Fields;
+use sbor::{Type, Value};
 use scrypto::engine::types::*;
-use scrypto::rust::collections::HashSet;
+use scrypto::rust::collections::{BTreeMap, HashMap, HashSet};
 use scrypto::values::*;
+use serde_json::json;
 use std::collections::VecDeque;
 
+use crate::ledger::*;
 use crate::utils::*;
 
 /// Represents an error when displaying an entity.
@@ -40,6 +44,15 @@ pub fn dump_package<T: SubstateStore, O: std::io::Write>(
                 "Code size".green().bold(),
                 b.code().len()
             );
+            writeln!(
+                output,
+                "{}: {}",
+                "Owner".green().bold(),
+                b.owner()
+                    .map(|owner| owner.to_string())
+                    .unwrap_or("None (immutable)".to_owned())
+            );
+            dump_metadata(b.metadata(), output);
             Ok(())
         }
         None => Err(DisplayError::PackageNotFound),
@@ -47,8 +60,13 @@ pub fn dump_package<T: SubstateStore, O: std::io::Write>(
 }
 
 /// Dump a component into console.
+///
+/// The state is decoded with the blueprint `schema` when given, and lazy maps are expanded
+/// up to `depth` levels deep, or fully if no depth is given.
 pub fn dump_component<T: SubstateStore + QueryableSubstateStore, O: std::io::Write>(
     component_address: ComponentAddress,
+    schema: Option<&Type>,
+    depth: Option<usize>,
     substate_store: &T,
     output: &mut O,
 ) -> Result<(), DisplayError> {
@@ -79,58 +97,106 @@ pub fn dump_component<T: SubstateStore + QueryableSubstateStore, O: std::io::Wri
                 }
             }
 
-            let state = c.state();
-            let state_data = ScryptoValue::from_slice(state).unwrap();
-            writeln!(output, "{}: {}", "State".green().bold(), state_data);
-
-            // Find all vaults owned by the component, assuming a tree structure.
-            let mut vaults_found: HashSet<VaultId> = state_data.vault_ids.iter().cloned().collect();
-            let mut queue: VecDeque<LazyMapId> = state_data.lazy_map_ids.iter().cloned().collect();
-            while !queue.is_empty() {
-                let lazy_map_id = queue.pop_front().unwrap();
-                let (maps, vaults) =
-                    dump_lazy_map(component_address, &lazy_map_id, substate_store, output)?;
-                queue.extend(maps);
-                vaults_found.extend(vaults);
-            }
+            dump_metadata(c.metadata(), output);
+            writeln!(
+                output,
+                "{}: {}",
+                "Metadata Update Rule".green().bold(),
+                c.metadata_access_rule()
+            );
+
+            let state = decode_state(component_address, c.state(), schema, depth, substate_store);
+            writeln!(output, "{}: {}", "State".green().bold(), state.to_text(0));
 
             // Dump resources
+            let vaults_found = find_vaults(component_address, substate_store)?;
             dump_resources(component_address, &vaults_found, substate_store, output)
         }
         None => Err(DisplayError::ComponentNotFound),
     }
 }
 
-fn dump_lazy_map<T: SubstateStore + QueryableSubstateStore, O: std::io::Write>(
+/// Dump a component as JSON.
+pub fn dump_component_json<T: SubstateStore + QueryableSubstateStore>(
     component_address: ComponentAddress,
-    lazy_map_id: &LazyMapId,
+    schema: Option<&Type>,
+    depth: Option<usize>,
     substate_store: &T,
-    output: &mut O,
-) -> Result<(Vec<LazyMapId>, Vec<VaultId>), DisplayError> {
-    let mut referenced_maps = Vec::new();
-    let mut referenced_vaults = Vec::new();
-    let map = substate_store.get_lazy_map_entries(component_address, lazy_map_id);
-    writeln!(
-        output,
-        "{}: {:?}{:?}",
-        "Lazy Map".green().bold(),
+) -> Result<serde_json::Value, DisplayError> {
+    let component: Component = substate_store
+        .get_decoded_substate(&component_address)
+        .map(|(component, _)| component)
+        .ok_or(DisplayError::ComponentNotFound)?;
+    let state = decode_state(
         component_address,
-        lazy_map_id
+        component.state(),
+        schema,
+        depth,
+        substate_store,
     );
-    for (last, (k, v)) in map.iter().identify_last() {
-        let k_validated = ScryptoValue::from_slice(k).unwrap();
-        let v_validated = ScryptoValue::from_slice(v).unwrap();
+
+    let mut resources = Vec::new();
+    for vault_id in find_vaults(component_address, substate_store)? {
+        let vault: Vault = substate_store
+            .get_decoded_child_substate(&component_address, &vault_id)
+            .unwrap()
+            .0;
+        let resource_manager: ResourceManager = substate_store
+            .get_decoded_substate(&vault.resource_address())
+            .map(|(resource, _)| resource)
+            .unwrap();
+        resources.push(json!({
+            "resource_address": vault.resource_address().to_string(),
+            "symbol": resource_manager.metadata().get("symbol"),
+            "amount": vault.total_amount().to_string(),
+        }));
+    }
+
+    Ok(json!({
+        "component_address": component_address.to_string(),
+        "package_address": component.package_address().to_string(),
+        "blueprint_name": component.blueprint_name(),
+        "metadata": component.metadata(),
+        "state": state.to_json(),
+        "resources": resources,
+    }))
+}
+
+fn dump_metadata<O: std::io::Write>(metadata: &BTreeMap<String, String>, output: &mut O) {
+    writeln!(output, "{}: {}", "Metadata".green().bold(), metadata.len());
+    for (last, (k, v)) in metadata.iter().identify_last() {
         writeln!(
             output,
-            "{} {} => {}",
+            "{} {}: {}",
             list_item_prefix(last),
-            k_validated,
-            v_validated
+            k.green().bold(),
+            v
         );
-        referenced_maps.extend(v_validated.lazy_map_ids);
-        referenced_vaults.extend(v_validated.vault_ids);
     }
-    Ok((referenced_maps, referenced_vaults))
+}
+
+/// Returns all vaults owned by a component, assuming a tree structure.
+pub fn find_vaults<T: SubstateStore + QueryableSubstateStore>(
+    component_address: ComponentAddress,
+    substate_store: &T,
+) -> Result<HashSet<VaultId>, DisplayError> {
+    let component: Component = substate_store
+        .get_decoded_substate(&component_address)
+        .map(|(component, _)| component)
+        .ok_or(DisplayError::ComponentNotFound)?;
+    let state_data = ScryptoValue::from_slice(component.state()).unwrap();
+
+    let mut vaults_found: HashSet<VaultId> = state_data.vault_ids.iter().cloned().collect();
+    let mut queue: VecDeque<LazyMapId> = state_data.lazy_map_ids.iter().cloned().collect();
+    while let Some(lazy_map_id) = queue.pop_front() {
+        let map = substate_store.get_lazy_map_entries(component_address, &lazy_map_id);
+        for (_, v) in map {
+            let v_validated = ScryptoValue::from_slice(&v).unwrap();
+            queue.extend(v_validated.lazy_map_ids);
+            vaults_found.extend(v_validated.vault_ids);
+        }
+    }
+    Ok(vaults_found)
 }
 
 fn dump_resources<T: SubstateStore, O: std::io::Write>(
@@ -169,7 +235,10 @@ fn dump_resources<T: SubstateStore, O: std::io::Write>(
                 .map(|symbol| format!(", symbol: \"{}\"", symbol))
                 .unwrap_or(String::new()),
         );
-        if matches!(resource_manager.resource_type(), ResourceType::NonFungible) {
+        if matches!(
+            resource_manager.resource_type(),
+            ResourceType::NonFungible { .. }
+        ) {
             let ids = vault.total_ids().unwrap();
             for (inner_last, id) in ids.iter().identify_last() {
                 let non_fungible: Option<NonFungible> = substate_store
@@ -198,9 +267,11 @@ fn dump_resources<T: SubstateStore, O: std::io::Write>(
     Ok(())
 }
 
-/// Dump a resource into console.
-pub fn dump_resource_manager<T: SubstateStore, O: std::io::Write>(
+/// Dump a resource into console, listing a page of its non-fungibles if any.
+pub fn dump_resource_manager<T: SubstateStore + QueryableSubstateStore, O: std::io::Write>(
     resource_address: ResourceAddress,
+    offset: usize,
+    limit: usize,
     substate_store: &T,
     output: &mut O,
 ) -> Result<(), DisplayError> {
@@ -224,10 +295,15 @@ pub fn dump_resource_manager<T: SubstateStore, O: std::io::Write>(
             for (last, e) in r.metadata().iter().identify_last() {
                 writeln!(
                     output,
-                    "{} {}: {}",
+                    "{} {}: {}{}",
                     list_item_prefix(last),
                     e.0.green().bold(),
-                    e.1
+                    e.1,
+                    if r.locked_metadata().contains(e.0) {
+                        " (locked)"
+                    } else {
+                        ""
+                    }
                 );
             }
             writeln!(
@@ -236,8 +312,84 @@ pub fn dump_resource_manager<T: SubstateStore, O: std::io::Write>(
                 "Total Supply".green().bold(),
                 r.total_supply()
             );
+            if let Some(max_supply) = r.max_supply() {
+                writeln!(output, "{}: {}", "Max Supply".green().bold(), max_supply);
+            }
+            if matches!(r.resource_type(), ResourceType::NonFungible { .. }) {
+                let schema = r.non_fungible_data_schema();
+                let non_fungibles =
+                    substate_store.get_non_fungibles(resource_address, offset, limit);
+                writeln!(
+                    output,
+                    "{}: {}",
+                    "Non-fungibles".green().bold(),
+                    r.minted_non_fungible_count()
+                );
+                for (last, (id, non_fungible)) in non_fungibles.iter().identify_last() {
+                    match non_fungible {
+                        Some(non_fungible) => {
+                            let immutable_data = format_non_fungible_data(
+                                &non_fungible.immutable_data(),
+                                schema.map(|s| &s.immutable),
+                            );
+                            let mutable_data = format_non_fungible_data(
+                                &non_fungible.mutable_data(),
+                                schema.map(|s| &s.mutable),
+                            );
+                            writeln!(
+                                output,
+                                "{} NonFungible {{ id: {}, immutable_data: {}, mutable_data: {} }}",
+                                list_item_prefix(last),
+                                id,
+                                immutable_data,
+                                mutable_data
+                            );
+                        }
+                        None => {
+                            writeln!(
+                                output,
+                                "{} NonFungible {{ id: {}, burned }}",
+                                list_item_prefix(last),
+                                id
+                            );
+                        }
+                    }
+                }
+            }
             Ok(())
         }
         None => Err(DisplayError::ResourceManagerNotFound),
     }
 }
+
+/// Formats non-fungible data, labelling the fields with their names if the schema is known.
+fn format_non_fungible_data(data: &[u8], schema: Option<&Type>) -> String {
+    let value = ScryptoValue::from_slice(data).unwrap();
+    match (&value.dom, schema) {
+        (
+            Value::Struct { fields },
+            Some(Type::Struct {
+                name,
+                fields: Fields::Named { named },
+            }),
+        ) if fields.len() == named.len() => {
+            let fields: Vec<String> = named
+                .iter()
+                .zip(fields)
+                .map(|((field_name, _), field)| {
+                    format!(
+                        "{}: {}",
+                        field_name,
+                        ScryptoValueFormatter::format_value(
+                            field,
+                            &HashMap::new(),
+                            &HashMap::new()
+                        )
+                    )
+                })
+                .collect();
+            format!("{} {{ {} }}", name, fields.join(", "))
+        }
+        _ => value.to_string(),
+    }
+}
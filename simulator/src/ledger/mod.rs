@@ -1,5 +1,7 @@
+mod decoder;
 mod dumper;
 mod radix_engine_db;
 
+pub use decoder::*;
 pub use dumper::*;
 pub use radix_engine_db::RadixEngineDB;
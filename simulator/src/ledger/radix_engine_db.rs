@@ -1,10 +1,14 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use radix_engine::errors::TimeError;
 use radix_engine::ledger::*;
+use radix_engine::model::NonFungible;
+use rocksdb::checkpoint::Checkpoint;
 use rocksdb::{DBWithThreadMode, Direction, IteratorMode, SingleThreaded, DB};
 use sbor::{Decode, Encode};
 use scrypto::buffer::*;
+use scrypto::crypto::*;
 use scrypto::engine::types::*;
 
 pub struct RadixEngineDB {
@@ -23,6 +27,32 @@ impl RadixEngineDB {
         ledger
     }
 
+    /// Creates a consistent point-in-time copy of this database at the given path,
+    /// which must not exist yet.
+    pub fn checkpoint(&self, path: PathBuf) -> Result<(), rocksdb::Error> {
+        Checkpoint::new(&self.db)?.create_checkpoint(path.as_path())
+    }
+
+    /// Returns a hash of all substates, excluding the epoch, time and nonce.
+    pub fn state_hash(&self) -> Hash {
+        let excluded = [
+            scrypto_encode(&"epoch"),
+            scrypto_encode(&"time"),
+            scrypto_encode(&"nonce"),
+        ];
+        let mut data = Vec::new();
+        for (key, value) in self.db.iterator(IteratorMode::Start) {
+            if excluded.iter().any(|k| k.as_slice() == key.as_ref()) {
+                continue;
+            }
+            data.extend((key.len() as u32).to_le_bytes());
+            data.extend(key.as_ref());
+            data.extend((value.len() as u32).to_le_bytes());
+            data.extend(value.as_ref());
+        }
+        hash(data)
+    }
+
     pub fn list_packages(&self) -> Vec<PackageAddress> {
         let start = &scrypto_encode(&PackageAddress([0; 26]));
         let end = &scrypto_encode(&PackageAddress([255; 26]));
@@ -116,6 +146,29 @@ impl SubstateStore for RadixEngineDB {
         self.write(&id, &scrypto_encode(&substate));
     }
 
+    fn get_non_fungibles(
+        &self,
+        resource_address: ResourceAddress,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(NonFungibleId, Option<NonFungible>)> {
+        let id = scrypto_encode(&resource_address);
+        let key_size = id.len();
+
+        let iter = self
+            .db
+            .iterator(IteratorMode::From(&id, Direction::Forward))
+            .take_while(|(key, _)| key.starts_with(&id))
+            .filter_map(|(key, value)| {
+                let non_fungible_id: NonFungibleId =
+                    scrypto_decode(key.split_at(key_size).1).ok()?;
+                let substate: Substate = scrypto_decode(&value.to_vec()).unwrap();
+                let non_fungible: Option<NonFungible> = scrypto_decode(&substate.value).unwrap();
+                Some((non_fungible_id, non_fungible))
+            });
+        iter.skip(offset).take(limit).collect()
+    }
+
     fn get_epoch(&self) -> u64 {
         let id = scrypto_encode(&"epoch");
         self.read(&id)
@@ -129,6 +182,27 @@ impl SubstateStore for RadixEngineDB {
         self.write(&id, &value)
     }
 
+    fn get_time(&self) -> u64 {
+        let id = scrypto_encode(&"time");
+        self.read(&id)
+            .map(|v| scrypto_decode(&v).unwrap())
+            .unwrap_or(0)
+    }
+
+    fn set_time(&mut self, time: u64) -> Result<(), TimeError> {
+        let current_time = self.get_time();
+        if time < current_time {
+            return Err(TimeError::TimeMovedBackwards {
+                current_time,
+                new_time: time,
+            });
+        }
+        let id = scrypto_encode(&"time");
+        let value = scrypto_encode(&time);
+        self.write(&id, &value);
+        Ok(())
+    }
+
     fn get_nonce(&self) -> u64 {
         let id = scrypto_encode(&"nonce");
         self.read(&id)
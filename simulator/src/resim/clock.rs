@@ -0,0 +1,61 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::resim::*;
+
+/// Parses a time, given either as an RFC 3339 timestamp or in milliseconds since the Unix
+/// epoch.
+pub fn parse_time(s: &str) -> Result<u64, Error> {
+    if let Ok(millis) = s.parse::<u64>() {
+        return Ok(millis);
+    }
+    let time = humantime::parse_rfc3339_weak(s).map_err(|_| Error::InvalidTime(s.to_owned()))?;
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| Error::InvalidTime(s.to_owned()))?
+        .as_millis();
+    u64::try_from(millis).map_err(|_| Error::InvalidTime(s.to_owned()))
+}
+
+/// Parses a duration such as `90s`, `1h 30m` or `7days`, and returns it in milliseconds.
+pub fn parse_duration(s: &str) -> Result<u64, Error> {
+    let duration =
+        humantime::parse_duration(s).map_err(|_| Error::InvalidDuration(s.to_owned()))?;
+    u64::try_from(duration.as_millis()).map_err(|_| Error::InvalidDuration(s.to_owned()))
+}
+
+/// Formats a time in milliseconds since the Unix epoch as an RFC 3339 timestamp.
+pub fn format_time(millis: u64) -> String {
+    let time = SystemTime::UNIX_EPOCH + Duration::from_millis(millis);
+    humantime::format_rfc3339_millis(time).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_time() {
+        assert_eq!(parse_time("1651406400000").unwrap(), 1651406400000);
+        assert_eq!(parse_time("2022-05-01T12:00:00Z").unwrap(), 1651406400000);
+        assert_eq!(
+            parse_time("2022-05-01 12:00:00.250").unwrap(),
+            1651406400250
+        );
+        assert!(matches!(
+            parse_time("yesterday"),
+            Err(Error::InvalidTime(_))
+        ));
+        assert_eq!(format_time(1651406400250), "2022-05-01T12:00:00.250Z");
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90s").unwrap(), 90_000);
+        assert_eq!(parse_duration("1h 30m").unwrap(), 5_400_000);
+        assert_eq!(parse_duration("7days").unwrap(), 604_800_000);
+        assert!(matches!(
+            parse_duration("soon"),
+            Err(Error::InvalidDuration(_))
+        ));
+    }
+}
@@ -0,0 +1,26 @@
+use clap::Parser;
+use radix_engine::ledger::SubstateStore;
+
+use crate::resim::*;
+
+/// Advance the current epoch
+#[derive(Parser, Debug)]
+pub struct AdvanceEpoch {
+    /// The number of epochs to advance by
+    #[clap(default_value = "1")]
+    epochs: u64,
+}
+
+impl AdvanceEpoch {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+        let epoch = ledger
+            .get_epoch()
+            .checked_add(self.epochs)
+            .ok_or(Error::EpochOverflow)?;
+        ledger.set_epoch(epoch);
+
+        writeln!(out, "Current epoch: {}", epoch).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use clap::Parser;
+use radix_engine::ledger::SubstateStore;
+
+use crate::resim::*;
+
+/// Advance the current time
+#[derive(Parser, Debug)]
+pub struct AdvanceTime {
+    /// The duration to advance by, e.g. 90s, "1h 30m" or 7days
+    duration: String,
+}
+
+impl AdvanceTime {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let duration = parse_duration(&self.duration)?;
+        let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+        let time = ledger
+            .get_time()
+            .checked_add(duration)
+            .ok_or(Error::TimeOverflow)?;
+        ledger.set_time(time).map_err(Error::TimeError)?;
+
+        writeln!(out, "Current time: {}", format_time(time)).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -16,14 +16,14 @@ pub struct CallFunction {
     /// The function name
     function_name: String,
 
-    /// The call arguments, e.g. \"5\", \"hello\", \"amount,resource_address\" for Bucket, or \"#id1,#id2,..,resource_address\" for non-fungible Bucket
+    /// The call arguments, e.g. \"5\", \"hello\", \"amount,resource_address\" for Bucket, \"#id1,#id2,..,resource_address\" for non-fungible Bucket, or JSON for composite types
     arguments: Vec<String>,
 
     /// Output a transaction manifest without execution
     #[clap(short, long)]
     manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys or keystore key names used for signing, separated by comma
     #[clap(short, long)]
     signing_keys: Option<String>,
 
@@ -43,7 +43,7 @@ impl CallFunction {
                 self.package_address,
                 &self.blueprint_name,
                 &self.function_name,
-                self.arguments.clone(),
+                substitute_accounts_in_args(&self.arguments)?,
                 Some(default_account),
                 &executor
                     .export_abi(self.package_address, &self.blueprint_name)
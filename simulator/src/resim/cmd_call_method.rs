@@ -9,20 +9,20 @@ use crate::resim::*;
 /// Call a method
 #[derive(Parser, Debug)]
 pub struct CallMethod {
-    /// The component that the method belongs to
-    component_address: ComponentAddress,
+    /// The component that the method belongs to, or an account as `@<name>`
+    component_address: String,
 
     /// The method name
     method_name: String,
 
-    /// The call arguments
+    /// The call arguments, with composite types given in JSON
     arguments: Vec<String>,
 
     /// Output a transaction manifest without execution
     #[clap(short, long)]
     manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys or keystore key names used for signing, separated by comma
     #[clap(short, long)]
     signing_keys: Option<String>,
 
@@ -36,15 +36,16 @@ impl CallMethod {
         let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
         let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
         let default_account = get_default_account()?;
+        let component_address = parse_component_address(&self.component_address)?;
 
         let transaction = TransactionBuilder::new()
             .call_method_with_abi(
-                self.component_address,
+                component_address,
                 &self.method_name,
-                self.arguments.clone(),
+                substitute_accounts_in_args(&self.arguments)?,
                 Some(default_account),
                 &executor
-                    .export_abi_by_component(self.component_address)
+                    .export_abi_by_component(component_address)
                     .map_err(Error::AbiExportError)?,
             )
             .map_err(Error::TransactionConstructionError)?
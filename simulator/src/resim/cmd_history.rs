@@ -0,0 +1,33 @@
+use clap::Parser;
+use colored::*;
+
+use crate::resim::*;
+
+/// List the transactions executed by this simulator
+#[derive(Parser, Debug)]
+pub struct History {}
+
+impl History {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let history = get_history()?;
+        if history.is_empty() {
+            writeln!(out, "No transaction found").map_err(Error::IOError)?;
+        }
+        for (i, entry) in history.iter().enumerate() {
+            writeln!(
+                out,
+                "{} {} {} (epoch {})",
+                format!("#{}", i).green().bold(),
+                entry.transaction_hash(),
+                if entry.success {
+                    "SUCCESS".blue()
+                } else {
+                    "FAILURE".red()
+                },
+                entry.epoch
+            )
+            .map_err(Error::IOError)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use clap::{Parser, Subcommand};
+use colored::*;
+use rand::Rng;
+use scrypto::crypto::*;
+
+use crate::resim::*;
+
+/// Manage private keys in the encrypted keystore
+#[derive(Parser, Debug)]
+pub struct Key {
+    #[clap(subcommand)]
+    command: KeyCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum KeyCommand {
+    New(KeyNew),
+    List(KeyList),
+    Import(KeyImport),
+    Export(KeyExport),
+}
+
+/// Generate a new key and add it to the keystore
+#[derive(Parser, Debug)]
+pub struct KeyNew {
+    /// The key name
+    name: String,
+}
+
+/// List all keys in the keystore
+#[derive(Parser, Debug)]
+pub struct KeyList {}
+
+/// Add an existing private key to the keystore
+#[derive(Parser, Debug)]
+pub struct KeyImport {
+    /// The key name
+    name: String,
+
+    /// The private key, hex-encoded
+    private_key: String,
+}
+
+/// Print the private key of a key in the keystore
+#[derive(Parser, Debug)]
+pub struct KeyExport {
+    /// The key name
+    name: String,
+}
+
+impl Key {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        match &self.command {
+            KeyCommand::New(cmd) => cmd.run(out),
+            KeyCommand::List(cmd) => cmd.run(out),
+            KeyCommand::Import(cmd) => cmd.run(out),
+            KeyCommand::Export(cmd) => cmd.run(out),
+        }
+    }
+}
+
+impl KeyNew {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let secret = rand::thread_rng().gen::<[u8; 32]>();
+        let private_key = EcdsaPrivateKey::from_bytes(&secret).unwrap();
+        let public_key = add_key(&self.name, &private_key)?;
+
+        writeln!(out, "Key {} created.", self.name).map_err(Error::IOError)?;
+        writeln!(out, "Public key: {}", public_key.to_string().green()).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl KeyList {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let keystore = get_keystore()?;
+        if keystore.keys.is_empty() {
+            writeln!(out, "No key found").map_err(Error::IOError)?;
+        }
+        for (name, key) in keystore.keys {
+            writeln!(out, "{}: {}", name.green().bold(), key.public_key).map_err(Error::IOError)?;
+        }
+        Ok(())
+    }
+}
+
+impl KeyImport {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let private_key = hex::decode(&self.private_key)
+            .map_err(|_| Error::InvalidPrivateKey)
+            .and_then(|bytes| {
+                EcdsaPrivateKey::from_bytes(&bytes).map_err(|_| Error::InvalidPrivateKey)
+            })?;
+        let public_key = add_key(&self.name, &private_key)?;
+
+        writeln!(out, "Key {} imported.", self.name).map_err(Error::IOError)?;
+        writeln!(out, "Public key: {}", public_key.to_string().green()).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl KeyExport {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let private_key = unlock_key(&self.name)?;
+
+        writeln!(
+            out,
+            "Private key: {}",
+            hex::encode(private_key.to_bytes()).green()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -20,7 +20,7 @@ pub struct Mint {
     #[clap(short, long)]
     manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys or keystore key names used for signing, separated by comma
     #[clap(short, long)]
     signing_keys: Option<String>,
 
@@ -8,6 +8,14 @@ use crate::resim::*;
 /// Create an account
 #[derive(Parser, Debug)]
 pub struct NewAccount {
+    /// The account name, for referring to it as `@<name>`
+    #[clap(short, long)]
+    name: Option<String>,
+
+    /// Store the account key in the keystore under this name, instead of the account name
+    #[clap(short, long)]
+    key: Option<String>,
+
     /// Output a transaction manifest without execution
     #[clap(short, long)]
     manifest: Option<PathBuf>,
@@ -21,16 +29,22 @@ impl NewAccount {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
         let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
+        let configs = get_configs()?;
+        let name = match (&self.name, &configs) {
+            (Some(name), Some(configs)) if configs.accounts.contains_key(name) => {
+                return Err(Error::AccountAlreadyExists(name.clone()));
+            }
+            (Some(name), _) => name.clone(),
+            (None, configs) => next_account_name(configs.as_ref(), &get_keystore()?),
+        };
+        let key_name = self.key.clone().unwrap_or(name.clone());
 
         if let Some(path) = &self.manifest {
             let secret = rand::thread_rng().gen::<[u8; 32]>();
             let private_key = EcdsaPrivateKey::from_bytes(&secret).unwrap();
             let public_key = private_key.public_key();
-            let auth_address = NonFungibleAddress::new(
-                ECDSA_TOKEN,
-                NonFungibleId::from_bytes(public_key.to_vec()),
-            );
-            let withdraw_auth = rule!(require(auth_address));
+            add_key(&key_name, &private_key)?;
+            let withdraw_auth = rule!(require_signature(public_key));
             let transaction = TransactionBuilder::new()
                 .call_method(SYSTEM_COMPONENT, "free_xrd", vec![])
                 .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
@@ -41,15 +55,23 @@ impl NewAccount {
             writeln!(out, "A manifest has been produced for the following key pair. To complete account creation, you will need to run the manifest!").map_err(Error::IOError)?;
             writeln!(out, "Public key: {}", public_key.to_string().green())
                 .map_err(Error::IOError)?;
-            writeln!(
-                out,
-                "Private key: {}",
-                hex::encode(private_key.to_bytes()).green()
-            )
-            .map_err(Error::IOError)?;
+            writeln!(out, "Key name: {}", key_name.green()).map_err(Error::IOError)?;
         } else {
-            let (public_key, private_key, account) = executor.new_account();
+            let (public_key, private_key) = executor.new_key_pair();
+            add_key(&key_name, &private_key)?;
+            let withdraw_auth = rule!(require_signature(public_key));
+            let transaction = TransactionBuilder::new()
+                .call_method(SYSTEM_COMPONENT, "free_xrd", vec![])
+                .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+                    builder.new_account_with_resource(&withdraw_auth, bucket_id)
+                })
+                .build(executor.get_nonce([]))
+                .sign([]);
+            let receipt = execute_and_record(&mut executor, &transaction)?;
+            receipt.result.map_err(Error::TransactionExecutionError)?;
+            let account = receipt.new_component_addresses[0];
             writeln!(out, "A new account has been created!").map_err(Error::IOError)?;
+            writeln!(out, "Account name: {}", name.green()).map_err(Error::IOError)?;
             writeln!(
                 out,
                 "Account component address: {}",
@@ -58,23 +80,29 @@ impl NewAccount {
             .map_err(Error::IOError)?;
             writeln!(out, "Public key: {}", public_key.to_string().green())
                 .map_err(Error::IOError)?;
-            writeln!(
-                out,
-                "Private key: {}",
-                hex::encode(private_key.to_bytes()).green()
-            )
-            .map_err(Error::IOError)?;
-            if get_configs()?.is_none() {
-                writeln!(
-                    out,
-                    "No configuration found on system. will use the above account as default."
-                )
-                .map_err(Error::IOError)?;
-                set_configs(&Configs {
-                    default_account: account,
-                    default_private_key: private_key.to_bytes(),
-                })?;
-            }
+            writeln!(out, "Key name: {}", key_name.green()).map_err(Error::IOError)?;
+            let profile = AccountProfile {
+                component_address: account,
+                key: KeyReference::Keystore(key_name),
+            };
+            let configs = match configs {
+                Some(mut configs) => {
+                    configs.accounts.insert(name, profile);
+                    configs
+                }
+                None => {
+                    writeln!(
+                        out,
+                        "No configuration found on system. will use the above account as default."
+                    )
+                    .map_err(Error::IOError)?;
+                    Configs {
+                        default_account: name.clone(),
+                        accounts: BTreeMap::from([(name, profile)]),
+                    }
+                }
+            };
+            set_configs(&configs)?;
         }
 
         Ok(())
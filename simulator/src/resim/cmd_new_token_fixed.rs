@@ -1,6 +1,7 @@
 use clap::Parser;
 use radix_engine::transaction::*;
 use scrypto::engine::types::*;
+use scrypto::resource::validate_metadata;
 use scrypto::rust::collections::*;
 
 use crate::resim::*;
@@ -31,11 +32,15 @@ pub struct NewTokenFixed {
     #[clap(long)]
     icon_url: Option<String>,
 
+    /// A metadata key to lock, so that it can never be updated; can be repeated
+    #[clap(long)]
+    lock: Vec<String>,
+
     /// Output a transaction manifest without execution
     #[clap(short, long)]
     manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys or keystore key names used for signing, separated by comma
     #[clap(short, long)]
     signing_keys: Option<String>,
 
@@ -65,9 +70,16 @@ impl NewTokenFixed {
         if let Some(icon_url) = self.icon_url.clone() {
             metadata.insert("icon_url".to_string(), icon_url);
         };
+        for (key, value) in &metadata {
+            validate_metadata(key, value).map_err(Error::InvalidMetadata)?;
+        }
 
         let transaction = TransactionBuilder::new()
-            .new_token_fixed(metadata, self.total_supply)
+            .new_token_fixed(
+                metadata,
+                self.lock.iter().cloned().collect(),
+                self.total_supply,
+            )
             .call_method_with_all_resources(default_account, "deposit_batch")
             .build_with_no_nonce();
         process_transaction(
@@ -1,6 +1,7 @@
 use clap::Parser;
 use radix_engine::transaction::*;
 use scrypto::engine::types::*;
+use scrypto::resource::validate_metadata;
 use scrypto::rust::collections::*;
 
 use crate::resim::*;
@@ -31,11 +32,15 @@ pub struct NewTokenMutable {
     #[clap(long)]
     icon_url: Option<String>,
 
+    /// A metadata key to lock, so that it can never be updated; can be repeated
+    #[clap(long)]
+    lock: Vec<String>,
+
     /// Output a transaction manifest without execution
     #[clap(short, long)]
     manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys or keystore key names used for signing, separated by comma
     #[clap(short, long)]
     signing_keys: Option<String>,
 
@@ -64,9 +69,16 @@ impl NewTokenMutable {
         if let Some(icon_url) = self.icon_url.clone() {
             metadata.insert("icon_url".to_string(), icon_url);
         };
+        for (key, value) in &metadata {
+            validate_metadata(key, value).map_err(Error::InvalidMetadata)?;
+        }
 
         let transaction = TransactionBuilder::new()
-            .new_token_mutable(metadata, self.minter_resource_address)
+            .new_token_mutable(
+                metadata,
+                self.lock.iter().cloned().collect(),
+                self.minter_resource_address,
+            )
             .build_with_no_nonce();
         process_transaction(
             &mut executor,
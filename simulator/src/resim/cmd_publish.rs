@@ -1,7 +1,10 @@
 use clap::Parser;
 use colored::*;
+use radix_engine::model::Transaction;
 use radix_engine::transaction::*;
 use scrypto::engine::types::*;
+use scrypto::resource::require_signature;
+use scrypto::{access_rule_node, rule};
 use std::ffi::OsStr;
 use std::fs;
 use std::path::PathBuf;
@@ -16,10 +19,19 @@ pub struct Publish {
     /// the path to a Scrypto package or a .wasm file
     path: PathBuf,
 
-    /// The package ID, for overwriting
+    /// The address of an existing package, for upgrading
     #[clap(long)]
     package_address: Option<PackageAddress>,
 
+    /// The key whose signature is required to upgrade the new package, as a private key, a
+    /// keystore key name or an account as `@<name>`; packages published without it are immutable
+    #[clap(long)]
+    owner: Option<String>,
+
+    /// The private keys or keystore key names used for signing an upgrade, separated by comma
+    #[clap(short, long)]
+    signing_keys: Option<String>,
+
     /// Output a transaction manifest without execution
     #[clap(short, long)]
     manifest: Option<PathBuf>,
@@ -42,9 +54,7 @@ impl Publish {
         if let Some(path) = &self.manifest {
             let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
             let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
-            let transaction = TransactionBuilder::new()
-                .publish_package(code.as_ref())
-                .build_with_no_nonce();
+            let transaction = self.build_transaction(&code)?;
             process_transaction(&mut executor, transaction, &None, &Some(path.clone()), out)?;
         } else {
             self.store_package(out, &code)?;
@@ -67,20 +77,21 @@ impl Publish {
     pub fn store_package<O: std::io::Write>(&self, out: &mut O, code: &[u8]) -> Result<(), Error> {
         let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
         let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
-        if let Some(package_address) = self.package_address.clone() {
-            // Overwrite package
-            executor
-                .overwrite_package(package_address, code.to_vec())
-                .map_err(|e| Error::PackageValidationError(e))?;
-            writeln!(out, "Package updated!").map_err(Error::IOError)?;
+        let mut transaction = self.build_transaction(code)?;
+        if self.package_address.is_some() {
+            process_transaction(&mut executor, transaction, &self.signing_keys, &None, out)?;
+            writeln!(out, "Package upgraded!").map_err(Error::IOError)?;
             Ok(())
         } else {
-            match executor.publish_package(code) {
-                Ok(package_address) => {
+            transaction.add_nonce(executor.get_nonce([]));
+            let transaction = transaction.sign([]);
+            let receipt = execute_and_record(&mut executor, &transaction)?;
+            match receipt.result {
+                Ok(()) => {
                     writeln!(
                         out,
                         "Success! New Package: {}",
-                        package_address.to_string().green()
+                        receipt.new_package_addresses[0].to_string().green()
                     )
                     .map_err(Error::IOError)?;
                     Ok(())
@@ -94,4 +105,18 @@ impl Publish {
             }
         }
     }
+
+    /// Builds the transaction which publishes or upgrades the package.
+    fn build_transaction(&self, code: &[u8]) -> Result<Transaction, Error> {
+        let mut builder = TransactionBuilder::new();
+        if let Some(package_address) = self.package_address {
+            builder.upgrade_package(package_address, code);
+        } else if let Some(owner) = &self.owner {
+            let public_key = KeyReference::parse(owner)?.public_key()?;
+            builder.publish_package_with_owner(code, rule!(require_signature(public_key)));
+        } else {
+            builder.publish_package(code);
+        }
+        Ok(builder.build_with_no_nonce())
+    }
 }
@@ -0,0 +1,81 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::ledger::*;
+use radix_engine::transaction::*;
+use std::fs;
+use std::path::Path;
+
+use crate::resim::*;
+
+/// Rebuild the ledger by re-executing the transaction history
+#[derive(Parser, Debug)]
+pub struct Replay {
+    /// The index of the first transaction whose receipt is printed
+    #[clap(long, default_value = "0")]
+    from: usize,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    trace: bool,
+}
+
+impl Replay {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let data_dir = get_data_dir()?;
+        let history = get_history()?;
+
+        // Rebuild in a staging directory, and only replace the ledger if all states match.
+        // Configurations and history are kept as they are.
+        let staging_dir =
+            std::env::temp_dir().join(format!("resim-replay-{}", uuid::Uuid::new_v4()));
+        let result = self
+            .replay(&history, &staging_dir, out)
+            .and_then(|_| copy_state_files(&data_dir, &staging_dir))
+            .and_then(|_| load_state(&staging_dir, &data_dir));
+        fs::remove_dir_all(&staging_dir).ok();
+        result?;
+
+        writeln!(
+            out,
+            "Replayed {} transactions, ledger state matches history.",
+            history.len()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+
+    fn replay<O: std::io::Write>(
+        &self,
+        history: &[HistoryEntry],
+        staging_dir: &Path,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let mut ledger = RadixEngineDB::with_bootstrap(staging_dir.join(SNAPSHOT_LEDGER_DIR_NAME));
+        let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
+
+        for (i, entry) in history.iter().enumerate() {
+            executor.substate_store_mut().set_epoch(entry.epoch);
+            executor
+                .substate_store_mut()
+                .set_time(entry.time)
+                .map_err(Error::TimeError)?;
+            let receipt = executor
+                .validate_and_execute(&entry.signed_transaction())
+                .map_err(Error::TransactionValidationError)?;
+            if i >= self.from {
+                writeln!(
+                    out,
+                    "{} {}",
+                    format!("#{}", i).green().bold(),
+                    entry.transaction_hash()
+                )
+                .map_err(Error::IOError)?;
+                writeln!(out, "{:?}", receipt).map_err(Error::IOError)?;
+            }
+            if executor.substate_store().state_hash() != entry.state_hash {
+                return Err(Error::ReplayStateMismatch(i));
+            }
+        }
+        Ok(())
+    }
+}
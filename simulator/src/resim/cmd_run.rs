@@ -11,7 +11,7 @@ pub struct Run {
     /// The path to a transaction manifest file
     path: PathBuf,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys or keystore key names used for signing, separated by comma
     #[clap(short, long)]
     signing_keys: Option<String>,
 
@@ -29,11 +29,36 @@ impl Run {
         .into()
     }
 
+    /// Replaces the component addresses given as `ComponentAddress("@<name>")` with the address
+    /// of the named account, leaving any other string literal untouched.
+    pub fn substitute_accounts(manifest: &str, configs: Option<&Configs>) -> Result<String, Error> {
+        let re = Regex::new(r#"ComponentAddress\(\s*"@([^"]+)"\s*\)"#).unwrap();
+        let mut result = String::new();
+        let mut last = 0;
+        for caps in re.captures_iter(manifest) {
+            let account = configs
+                .ok_or(Error::AccountNotFound(caps[1].to_owned()))?
+                .get_account(&caps[1])?;
+            let range = caps.get(0).unwrap().range();
+            result.push_str(&manifest[last..range.start]);
+            result.push_str(&format!(
+                "ComponentAddress(\"{}\")",
+                account.component_address
+            ));
+            last = range.end;
+        }
+        result.push_str(&manifest[last..]);
+        Ok(result)
+    }
+
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
         let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
         let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
-        let pre_processed_manifest = Self::pre_process_manifest(&manifest);
+        let pre_processed_manifest = Self::substitute_accounts(
+            &Self::pre_process_manifest(&manifest),
+            get_configs()?.as_ref(),
+        )?;
         let transaction =
             transaction_manifest::compile(&pre_processed_manifest).map_err(Error::CompileError)?;
         process_transaction(&mut executor, transaction, &self.signing_keys, &None, out)
@@ -43,6 +68,9 @@ impl Run {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use scrypto::engine::types::*;
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
 
     #[test]
     fn test_pre_process_manifest() {
@@ -64,4 +92,39 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_substitute_accounts() {
+        let component_address =
+            ComponentAddress::from_str("020000000000000000000000000000000000000000000000000002")
+                .unwrap();
+        let configs = Configs {
+            default_account: "alice".to_owned(),
+            accounts: BTreeMap::from([(
+                "alice".to_owned(),
+                AccountProfile {
+                    component_address,
+                    key: KeyReference::Keystore("alice".to_owned()),
+                },
+            )]),
+        };
+
+        let manifest =
+            r#"CALL_METHOD_WITH_ALL_RESOURCES ComponentAddress("@alice") "deposit_batch";"#;
+        let after = r#"CALL_METHOD_WITH_ALL_RESOURCES ComponentAddress("020000000000000000000000000000000000000000000000000002") "deposit_batch";"#;
+        assert_eq!(
+            Run::substitute_accounts(manifest, Some(&configs)).unwrap(),
+            after
+        );
+        let manifest = r#"CALL_METHOD ComponentAddress("@alice") "greet" "@alice";"#;
+        let after = r#"CALL_METHOD ComponentAddress("020000000000000000000000000000000000000000000000000002") "greet" "@alice";"#;
+        assert_eq!(
+            Run::substitute_accounts(manifest, Some(&configs)).unwrap(),
+            after
+        );
+        assert!(matches!(
+            Run::substitute_accounts(r#"ComponentAddress("@bob")"#, Some(&configs)),
+            Err(Error::AccountNotFound(name)) if name == "bob"
+        ));
+    }
 }
@@ -0,0 +1,209 @@
+use clap::Parser;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::time::Duration;
+
+use crate::resim::*;
+
+/// The maximum size of the request line and headers, in bytes.
+const MAX_HEADER_SIZE: u64 = 8 * 1024;
+
+/// The maximum size of a request body, in bytes.
+const MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+
+/// How long to wait for a client to send its request, so that a stalled one can't block others.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Serve a local JSON-RPC gateway over the simulator ledger
+#[derive(Parser, Debug)]
+pub struct Serve {
+    /// The port to listen on, on localhost only
+    #[clap(short, long, default_value = "3333")]
+    port: u16,
+}
+
+impl Serve {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let listener =
+            TcpListener::bind((Ipv4Addr::LOCALHOST, self.port)).map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "JSON-RPC gateway listening on http://{}",
+            listener.local_addr().map_err(Error::IOError)?
+        )
+        .map_err(Error::IOError)?;
+
+        // Requests are handled one at a time, so that only one of them opens the ledger.
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    if let Err(e) = handle_connection(stream) {
+                        writeln!(out, "Failed to handle request: {}", e).map_err(Error::IOError)?;
+                    }
+                }
+                Err(e) => {
+                    writeln!(out, "Failed to accept connection: {}", e).map_err(Error::IOError)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn handle_connection(mut stream: TcpStream) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let (status, body) = match read_request(&mut BufReader::new(&stream)) {
+        Ok((method, body)) if method == "POST" => ("200 OK", handle_json_rpc(&body)),
+        Ok(_) => ("405 Method Not Allowed", String::new()),
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData => {
+            write_response(&mut stream, "400 Bad Request", "")?;
+            return Err(e);
+        }
+        Err(e) => return Err(e),
+    };
+    write_response(&mut stream, status, &body)
+}
+
+fn write_response(stream: &mut TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Reads an HTTP request, and returns its method and body.
+///
+/// Requests with headers over `MAX_HEADER_SIZE` or a body over `MAX_BODY_SIZE` are rejected.
+fn read_request<R: BufRead>(reader: &mut R) -> std::io::Result<(String, String)> {
+    let mut headers = reader.by_ref().take(MAX_HEADER_SIZE);
+    let mut request_line = String::new();
+    headers.read_line(&mut request_line)?;
+    let method = request_line
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_owned();
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if headers.read_line(&mut header)? == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Incomplete or oversized headers",
+            ));
+        }
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse().map_err(|_| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid Content-Length")
+                })?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_SIZE {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Request body too large",
+        ));
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8(body)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    Ok((method, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_request() {
+        let request =
+            "POST / HTTP/1.1\r\nHost: localhost\r\ncontent-length: 13\r\n\r\n{\"id\": 1234}\n";
+        let (method, body) = read_request(&mut request.as_bytes()).unwrap();
+        assert_eq!(method, "POST");
+        assert_eq!(body, "{\"id\": 1234}\n");
+    }
+
+    #[test]
+    fn test_read_request_without_body() {
+        let request = "GET / HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        let (method, body) = read_request(&mut request.as_bytes()).unwrap();
+        assert_eq!(method, "GET");
+        assert_eq!(body, "");
+    }
+
+    #[test]
+    fn test_read_request_with_oversized_body() {
+        let request = format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_SIZE + 1
+        );
+        let err = read_request(&mut request.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_read_request_with_oversized_headers() {
+        let request = format!(
+            "POST / HTTP/1.1\r\nX-Padding: {}\r\n\r\n",
+            "a".repeat(MAX_HEADER_SIZE as usize)
+        );
+        let err = read_request(&mut request.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    fn send(request: &str) -> String {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let _ = handle_connection(stream);
+        });
+
+        let mut client = TcpStream::connect(address).unwrap();
+        client.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        server.join().unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serve_json_rpc_request() {
+        let body = r#"{"jsonrpc": "2.0", "id": 1, "method": "foo"}"#;
+        let response = send(&format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        ));
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Method not found: foo"));
+    }
+
+    #[test]
+    fn test_serve_rejects_other_methods() {
+        let response = send("GET / HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+
+    #[test]
+    fn test_serve_rejects_oversized_body() {
+        let response = send(&format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_SIZE + 1
+        ));
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+    }
+}
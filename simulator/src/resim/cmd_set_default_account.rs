@@ -1,24 +1,50 @@
 use clap::Parser;
-use scrypto::engine::types::*;
+use std::collections::BTreeMap;
 
 use crate::resim::*;
 
 /// Set default account
 #[derive(Parser, Debug)]
 pub struct SetDefaultAccount {
-    /// The account component address
-    component_address: ComponentAddress,
+    /// The account name
+    name: String,
 
-    /// The private key for accessing the account
-    private_key: String,
+    /// The account component address, to add or replace an account of this name
+    #[clap(requires = "private_key")]
+    component_address: Option<String>,
+
+    /// The private key for accessing the account, or its name in the keystore
+    private_key: Option<String>,
 }
 
 impl SetDefaultAccount {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        set_configs(&Configs {
-            default_account: self.component_address,
-            default_private_key: hex::decode(&self.private_key).unwrap(),
-        })?;
+        let profile = match (&self.component_address, &self.private_key) {
+            (Some(component_address), Some(private_key)) => Some(AccountProfile {
+                component_address: parse_component_address(component_address)?,
+                key: KeyReference::parse(private_key)?,
+            }),
+            _ => None,
+        };
+
+        let configs = match (get_configs()?, profile) {
+            (Some(mut configs), Some(profile)) => {
+                configs.accounts.insert(self.name.clone(), profile);
+                configs.default_account = self.name.clone();
+                configs
+            }
+            (Some(mut configs), None) => {
+                configs.get_account(&self.name)?;
+                configs.default_account = self.name.clone();
+                configs
+            }
+            (None, Some(profile)) => Configs {
+                default_account: self.name.clone(),
+                accounts: BTreeMap::from([(self.name.clone(), profile)]),
+            },
+            (None, None) => return Err(Error::AccountNotFound(self.name.clone())),
+        };
+        set_configs(&configs)?;
 
         writeln!(out, "Default account updated!").map_err(Error::IOError)?;
         Ok(())
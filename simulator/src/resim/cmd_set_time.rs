@@ -0,0 +1,22 @@
+use clap::Parser;
+use radix_engine::ledger::SubstateStore;
+
+use crate::resim::*;
+
+/// Set the current time, which can only move forward
+#[derive(Parser, Debug)]
+pub struct SetTime {
+    /// The new time, as an RFC 3339 timestamp (e.g. 2022-05-01T12:00:00Z) or in milliseconds since the Unix epoch
+    time: String,
+}
+
+impl SetTime {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let time = parse_time(&self.time)?;
+        let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+        ledger.set_time(time).map_err(Error::TimeError)?;
+
+        writeln!(out, "Current time: {}", format_time(time)).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use clap::Parser;
+use radix_engine::transaction::*;
 use scrypto::engine::types::*;
 use std::str::FromStr;
 
@@ -8,20 +9,67 @@ use crate::resim::*;
 /// Show an entity in the ledger state
 #[derive(Parser, Debug)]
 pub struct Show {
-    /// The address of a package, component or resource manager
+    /// The address of a package, component or resource manager, or an account as `@<name>`
     address: String,
+
+    /// How many levels of nested lazy maps to expand; all by default
+    #[clap(long)]
+    depth: Option<usize>,
+
+    /// Print a component as JSON
+    #[clap(long)]
+    json: bool,
+
+    /// How many non-fungibles of a resource to skip
+    #[clap(long)]
+    offset: Option<usize>,
+
+    /// How many non-fungibles of a resource to list; all by default
+    #[clap(long)]
+    limit: Option<usize>,
 }
 
 impl Show {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+        let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+
+        let address = match self.address.strip_prefix('@') {
+            Some(name) => get_account(name)?.component_address.to_string(),
+            None => self.address.clone(),
+        };
 
-        if let Ok(package_address) = PackageAddress::from_str(&self.address) {
+        if let Ok(package_address) = PackageAddress::from_str(&address) {
             dump_package(package_address, &ledger, out).map_err(Error::LedgerDumpError)
-        } else if let Ok(component_address) = ComponentAddress::from_str(&self.address) {
-            dump_component(component_address, &ledger, out).map_err(Error::LedgerDumpError)
-        } else if let Ok(resource_address) = ResourceAddress::from_str(&self.address) {
-            dump_resource_manager(resource_address, &ledger, out).map_err(Error::LedgerDumpError)
+        } else if let Ok(component_address) = ComponentAddress::from_str(&address) {
+            // Fall back to undecorated state if the blueprint doesn't export a schema.
+            let schema = TransactionExecutor::new(&mut ledger, false)
+                .export_abi_by_component(component_address)
+                .ok()
+                .map(|abi| abi.structure);
+
+            if self.json {
+                let json =
+                    dump_component_json(component_address, schema.as_ref(), self.depth, &ledger)
+                        .map_err(Error::LedgerDumpError)?;
+                writeln!(
+                    out,
+                    "{}",
+                    serde_json::to_string_pretty(&json).map_err(Error::JSONError)?
+                )
+                .map_err(Error::IOError)
+            } else {
+                dump_component(component_address, schema.as_ref(), self.depth, &ledger, out)
+                    .map_err(Error::LedgerDumpError)
+            }
+        } else if let Ok(resource_address) = ResourceAddress::from_str(&address) {
+            dump_resource_manager(
+                resource_address,
+                self.offset.unwrap_or(0),
+                self.limit.unwrap_or(usize::MAX),
+                &ledger,
+                out,
+            )
+            .map_err(Error::LedgerDumpError)
         } else {
             Err(Error::InvalidId(self.address.clone()))
         }
@@ -2,6 +2,7 @@ use clap::Parser;
 use colored::*;
 
 use crate::resim::*;
+use crate::utils::*;
 
 /// Show simulator configurations
 #[derive(Parser, Debug)]
@@ -17,22 +18,23 @@ impl ShowConfigs {
                 configs.default_account
             )
             .map_err(Error::IOError)?;
-            writeln!(
-                out,
-                "{}: {}",
-                "Default Public Key".green().bold(),
-                EcdsaPrivateKey::from_bytes(&configs.default_private_key)
-                    .unwrap()
-                    .public_key()
-            )
-            .map_err(Error::IOError)?;
-            writeln!(
-                out,
-                "{}: {}",
-                "Default Private Key".green().bold(),
-                hex::encode(configs.default_private_key)
-            )
-            .map_err(Error::IOError)?;
+            writeln!(out, "{}:", "Accounts".green().bold()).map_err(Error::IOError)?;
+            for (last, (name, account)) in configs.accounts.iter().identify_last() {
+                let key = match &account.key {
+                    KeyReference::Plain(_) => "plaintext key".to_owned(),
+                    KeyReference::Keystore(name) => format!("key name: {}", name),
+                };
+                writeln!(
+                    out,
+                    "{} @{} {{ component address: {}, public key: {}, {} }}",
+                    list_item_prefix(last),
+                    name.green().bold(),
+                    account.component_address,
+                    account.key.public_key()?,
+                    key
+                )
+                .map_err(Error::IOError)?;
+            }
         } else {
             writeln!(out, "No configuration found").map_err(Error::IOError)?;
         }
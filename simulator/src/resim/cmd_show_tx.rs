@@ -0,0 +1,38 @@
+use clap::Parser;
+use colored::*;
+
+use crate::resim::*;
+
+/// Show a transaction from the history
+#[derive(Parser, Debug)]
+pub struct ShowTx {
+    /// The transaction hash
+    hash: String,
+}
+
+impl ShowTx {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let entry = get_history()?
+            .into_iter()
+            .find(|entry| entry.transaction_hash().to_string() == self.hash)
+            .ok_or(Error::TransactionNotFound(self.hash.clone()))?;
+
+        writeln!(
+            out,
+            "{} {}",
+            "Transaction Hash:".green().bold(),
+            entry.transaction_hash()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(out, "{} {}", "Epoch:".green().bold(), entry.epoch).map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{} {}",
+            "Time:".green().bold(),
+            format_time(entry.time)
+        )
+        .map_err(Error::IOError)?;
+        writeln!(out, "{}", entry.receipt).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
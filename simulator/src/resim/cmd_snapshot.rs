@@ -0,0 +1,254 @@
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::resim::*;
+
+pub const SNAPSHOTS_DIR_NAME: &'static str = "snapshots";
+pub const SNAPSHOT_LEDGER_DIR_NAME: &'static str = "ledger";
+
+/// Files saved along with the ledger.
+/// The keystore is not part of the state, so that keys created after a snapshot are never
+/// lost by loading it.
+const STATE_FILE_NAMES: [&'static str; 2] = [CONFIG_FILE_NAME, HISTORY_FILE_NAME];
+
+/// Save, load or list snapshots of the simulator state
+#[derive(Parser, Debug)]
+pub struct Snapshot {
+    #[clap(subcommand)]
+    command: SnapshotCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SnapshotCommand {
+    Save(SnapshotSave),
+    Load(SnapshotLoad),
+    List(SnapshotList),
+}
+
+/// Save the ledger and configurations as a named snapshot
+#[derive(Parser, Debug)]
+pub struct SnapshotSave {
+    /// The snapshot name
+    name: String,
+}
+
+/// Restore the ledger and configurations from a named snapshot
+#[derive(Parser, Debug)]
+pub struct SnapshotLoad {
+    /// The snapshot name
+    name: String,
+}
+
+/// List all snapshots
+#[derive(Parser, Debug)]
+pub struct SnapshotList {}
+
+impl Snapshot {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        match &self.command {
+            SnapshotCommand::Save(cmd) => cmd.run(out),
+            SnapshotCommand::Load(cmd) => cmd.run(out),
+            SnapshotCommand::List(cmd) => cmd.run(out),
+        }
+    }
+}
+
+impl SnapshotSave {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let snapshot_dir = get_snapshot_dir(&self.name)?;
+        if snapshot_dir.exists() {
+            return Err(Error::SnapshotAlreadyExists(self.name.clone()));
+        }
+
+        // Write into a staging directory first, so that a snapshot either exists as a
+        // whole or not at all.
+        let staging_dir = get_snapshots_dir()?.join(format!(".{}.tmp", self.name));
+        if staging_dir.exists() {
+            fs::remove_dir_all(&staging_dir).map_err(Error::IOError)?;
+        }
+        save_state(&get_data_dir()?, &staging_dir)?;
+        fs::rename(&staging_dir, &snapshot_dir).map_err(Error::IOError)?;
+
+        writeln!(out, "Snapshot {} saved.", self.name).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl SnapshotLoad {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let snapshot_dir = get_snapshot_dir(&self.name)?;
+        if !snapshot_dir.join(SNAPSHOT_LEDGER_DIR_NAME).exists() {
+            return Err(Error::SnapshotNotFound(self.name.clone()));
+        }
+
+        load_state(&snapshot_dir, &get_data_dir()?)?;
+
+        writeln!(out, "Snapshot {} loaded.", self.name).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl SnapshotList {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(get_snapshots_dir()?).map_err(Error::IOError)? {
+            let name = entry
+                .map_err(Error::IOError)?
+                .file_name()
+                .to_string_lossy()
+                .to_string();
+            if !name.starts_with('.') {
+                names.push(name);
+            }
+        }
+        names.sort();
+
+        if names.is_empty() {
+            writeln!(out, "No snapshot found").map_err(Error::IOError)?;
+        }
+        for name in names {
+            writeln!(out, "{}", name).map_err(Error::IOError)?;
+        }
+        Ok(())
+    }
+}
+
+/// A throwaway copy of the current simulator state, used by `--fork`.
+///
+/// While alive, `DATA_DIR` points to the copy; the copy is removed on drop.
+pub struct Fork {
+    dir: PathBuf,
+}
+
+impl Fork {
+    pub fn new() -> Result<Self, Error> {
+        let data_dir = get_data_dir()?;
+        let dir = std::env::temp_dir().join(format!("resim-fork-{}", uuid::Uuid::new_v4()));
+        RadixEngineDB::with_bootstrap(data_dir.clone())
+            .checkpoint(dir.clone())
+            .map_err(Error::LedgerCheckpointError)?;
+
+        copy_state_files(&data_dir, &dir)?;
+        let keystore = data_dir.join(KEYSTORE_FILE_NAME);
+        if keystore.exists() {
+            fs::copy(keystore, dir.join(KEYSTORE_FILE_NAME)).map_err(Error::IOError)?;
+        }
+
+        std::env::set_var(ENV_DATA_DIR, &dir);
+        Ok(Self { dir })
+    }
+}
+
+impl Drop for Fork {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.dir).ok();
+    }
+}
+
+/// Returns the directory where snapshots are stored.
+pub fn get_snapshots_dir() -> Result<PathBuf, Error> {
+    let path = get_data_dir()?.join(SNAPSHOTS_DIR_NAME);
+    if !path.exists() {
+        fs::create_dir_all(&path).map_err(Error::IOError)?;
+    }
+    Ok(path)
+}
+
+fn get_snapshot_dir(name: &str) -> Result<PathBuf, Error> {
+    if name.is_empty() || name.starts_with('.') || name.contains(|c| c == '/' || c == '\\') {
+        return Err(Error::InvalidSnapshotName(name.to_owned()));
+    }
+    Ok(get_snapshots_dir()?.join(name))
+}
+
+/// Copies the ledger, configurations and history in `data_dir` into a new directory `dest`.
+pub fn save_state(data_dir: &Path, dest: &Path) -> Result<(), Error> {
+    fs::create_dir_all(dest).map_err(Error::IOError)?;
+
+    let ledger = RadixEngineDB::with_bootstrap(data_dir.to_path_buf());
+    ledger
+        .checkpoint(dest.join(SNAPSHOT_LEDGER_DIR_NAME))
+        .map_err(Error::LedgerCheckpointError)?;
+
+    copy_state_files(data_dir, dest)
+}
+
+/// Replaces the ledger, configurations and history in `data_dir` with the ones saved in
+/// `src`, keeping any snapshots and the keystore.
+///
+/// The new state is written into a sibling staging directory, which then takes the place of
+/// `data_dir`, so that a failure never leaves `data_dir` half restored.
+pub fn load_state(src: &Path, data_dir: &Path) -> Result<(), Error> {
+    let staging_dir = sibling_dir(data_dir, "load");
+    let result = stage_state(src, &staging_dir).and_then(|_| swap_state(&staging_dir, data_dir));
+    fs::remove_dir_all(&staging_dir).ok();
+    result
+}
+
+/// Copies the ledger, configurations and history saved in `src` into a new directory `dest`.
+fn stage_state(src: &Path, dest: &Path) -> Result<(), Error> {
+    fs::create_dir_all(dest).map_err(Error::IOError)?;
+    for entry in fs::read_dir(src.join(SNAPSHOT_LEDGER_DIR_NAME)).map_err(Error::IOError)? {
+        let entry = entry.map_err(Error::IOError)?;
+        fs::copy(entry.path(), dest.join(entry.file_name())).map_err(Error::IOError)?;
+    }
+    copy_state_files(src, dest)
+}
+
+/// Moves the snapshots and the keystore of `data_dir` into `staging_dir`, and then replaces
+/// `data_dir` with `staging_dir`.
+///
+/// On failure, `data_dir` is left as it was.
+fn swap_state(staging_dir: &Path, data_dir: &Path) -> Result<(), Error> {
+    fs::create_dir_all(data_dir).map_err(Error::IOError)?;
+
+    let mut kept = Vec::new();
+    for name in [SNAPSHOTS_DIR_NAME, KEYSTORE_FILE_NAME] {
+        if data_dir.join(name).exists() {
+            if let Err(e) = fs::rename(data_dir.join(name), staging_dir.join(name)) {
+                move_entries(&kept, staging_dir, data_dir);
+                return Err(Error::IOError(e));
+            }
+            kept.push(name);
+        }
+    }
+
+    let backup_dir = sibling_dir(data_dir, "old");
+    if let Err(e) = fs::rename(data_dir, &backup_dir) {
+        move_entries(&kept, staging_dir, data_dir);
+        return Err(Error::IOError(e));
+    }
+    if let Err(e) = fs::rename(staging_dir, data_dir) {
+        fs::rename(&backup_dir, data_dir).ok();
+        move_entries(&kept, staging_dir, data_dir);
+        return Err(Error::IOError(e));
+    }
+    fs::remove_dir_all(&backup_dir).ok();
+    Ok(())
+}
+
+/// Moves the named entries from `src` back to `dest`, ignoring failures.
+fn move_entries(names: &[&str], src: &Path, dest: &Path) {
+    for name in names {
+        fs::rename(src.join(name), dest.join(name)).ok();
+    }
+}
+
+/// Returns a unique path next to `dir`, for staging a replacement of it.
+fn sibling_dir(dir: &Path, purpose: &str) -> PathBuf {
+    let mut name = dir.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}-{}", purpose, uuid::Uuid::new_v4()));
+    dir.with_file_name(name)
+}
+
+/// Copies the configurations and history files from `src` to `dest`.
+pub fn copy_state_files(src: &Path, dest: &Path) -> Result<(), Error> {
+    for name in STATE_FILE_NAMES {
+        let file = src.join(name);
+        if file.exists() {
+            fs::copy(file, dest.join(name)).map_err(Error::IOError)?;
+        }
+    }
+    Ok(())
+}
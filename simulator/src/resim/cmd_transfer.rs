@@ -13,14 +13,14 @@ pub struct Transfer {
     /// The resource address.
     resource_address: ResourceAddress,
 
-    /// The recipient component address.
-    recipient: ComponentAddress,
+    /// The recipient component address, or an account as `@<name>`.
+    recipient: String,
 
     /// Output a transaction manifest without execution
     #[clap(short, long)]
     manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys or keystore key names used for signing, separated by comma
     #[clap(short, long)]
     signing_keys: Option<String>,
 
@@ -39,7 +39,10 @@ impl Transfer {
                 self.resource_address,
                 get_default_account()?,
             )
-            .call_method_with_all_resources(self.recipient, "deposit_batch")
+            .call_method_with_all_resources(
+                parse_component_address(&self.recipient)?,
+                "deposit_batch",
+            )
             .build_with_no_nonce();
         process_transaction(
             &mut executor,
@@ -1,5 +1,7 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use sbor::*;
 use scrypto::buffer::*;
@@ -8,11 +10,136 @@ use scrypto::engine::types::*;
 use crate::resim::*;
 use std::env;
 
+pub const CONFIG_FILE_NAME: &'static str = "config.sbor";
+
 /// Simulator configurations.
 #[derive(Debug, Clone, TypeId, Encode, Decode)]
 pub struct Configs {
-    pub default_account: ComponentAddress,
-    pub default_private_key: Vec<u8>,
+    /// The name of the default account
+    pub default_account: String,
+    /// All accounts, by name
+    pub accounts: BTreeMap<String, AccountProfile>,
+}
+
+/// The configurations as stored, so that files written by older versions can still be read.
+#[derive(TypeId, Encode, Decode)]
+enum VersionedConfigs {
+    V1(Configs),
+}
+
+/// The configurations written before they were versioned, with a single unnamed account.
+#[derive(TypeId, Decode)]
+struct LegacyConfigs {
+    default_account: ComponentAddress,
+    default_private_key: Vec<u8>,
+}
+
+/// The configurations written before accounts were named, with a key reference.
+#[derive(TypeId, Decode)]
+struct UnnamedConfigs {
+    default_account: ComponentAddress,
+    default_key: KeyReference,
+}
+
+impl From<UnnamedConfigs> for Configs {
+    fn from(unnamed: UnnamedConfigs) -> Self {
+        let name = "account1".to_owned();
+        let profile = AccountProfile {
+            component_address: unnamed.default_account,
+            key: unnamed.default_key,
+        };
+        Self {
+            default_account: name.clone(),
+            accounts: BTreeMap::from([(name, profile)]),
+        }
+    }
+}
+
+impl From<LegacyConfigs> for Configs {
+    fn from(legacy: LegacyConfigs) -> Self {
+        UnnamedConfigs {
+            default_account: legacy.default_account,
+            default_key: KeyReference::Plain(legacy.default_private_key),
+        }
+        .into()
+    }
+}
+
+/// A named account, referred to as `@<name>` in place of its address or key.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct AccountProfile {
+    pub component_address: ComponentAddress,
+    pub key: KeyReference,
+}
+
+impl Configs {
+    pub fn get_account(&self, name: &str) -> Result<&AccountProfile, Error> {
+        self.accounts
+            .get(name)
+            .ok_or(Error::AccountNotFound(name.to_owned()))
+    }
+
+    pub fn get_default_account(&self) -> Result<&AccountProfile, Error> {
+        self.get_account(&self.default_account)
+    }
+}
+
+/// A private key, given in plain or by its name in the keystore.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub enum KeyReference {
+    Plain(Vec<u8>),
+    Keystore(String),
+}
+
+impl KeyReference {
+    /// Parses a hex-encoded private key, an account as `@<name>`, or else the name of a key
+    /// in the keystore.
+    pub fn parse(key: &str) -> Result<Self, Error> {
+        if let Some(name) = key.strip_prefix('@') {
+            return get_account(name).map(|account| account.key);
+        }
+        if key.len() == EcdsaPrivateKey::LENGTH * 2 {
+            if let Ok(bytes) = hex::decode(key) {
+                EcdsaPrivateKey::from_bytes(&bytes).map_err(|_| Error::InvalidPrivateKey)?;
+                return Ok(Self::Plain(bytes));
+            }
+        }
+        get_key(key).map(|_| Self::Keystore(key.to_owned()))
+    }
+
+    /// Returns the private key, asking for the passphrase of keys in the keystore.
+    pub fn private_key(&self) -> Result<EcdsaPrivateKey, Error> {
+        match self {
+            Self::Plain(bytes) => {
+                EcdsaPrivateKey::from_bytes(bytes).map_err(|_| Error::InvalidPrivateKey)
+            }
+            Self::Keystore(name) => unlock_key(name),
+        }
+    }
+
+    /// Returns the public key, without unlocking keys in the keystore.
+    pub fn public_key(&self) -> Result<EcdsaPublicKey, Error> {
+        match self {
+            Self::Plain(_) => self
+                .private_key()
+                .map(|private_key| private_key.public_key()),
+            Self::Keystore(name) => get_key(name).map(|key| key.public_key),
+        }
+    }
+}
+
+/// Returns an unused account name, for accounts created without one.
+///
+/// Names of keys in the keystore are skipped too, as account keys are stored under the
+/// account name by default.
+pub fn next_account_name(configs: Option<&Configs>, keystore: &Keystore) -> String {
+    (1..)
+        .map(|i| format!("account{}", i))
+        .find(|name| {
+            !configs.map_or(false, |configs| configs.accounts.contains_key(name))
+                && !keystore.keys.contains_key(name)
+        })
+        .unwrap()
 }
 
 /// Returns the data directory.
@@ -34,17 +161,14 @@ pub fn get_data_dir() -> Result<PathBuf, Error> {
 /// Returns the config file.
 pub fn get_config_file() -> Result<PathBuf, Error> {
     let mut path = get_data_dir()?;
-    path.push("config");
-    Ok(path.with_extension("sbor"))
+    path.push(CONFIG_FILE_NAME);
+    Ok(path)
 }
 
 pub fn get_configs() -> Result<Option<Configs>, Error> {
     let path = get_config_file()?;
     if path.exists() {
-        Ok(Some(
-            scrypto_decode(&fs::read(path).map_err(Error::IOError)?.as_ref())
-                .map_err(Error::ConfigDecodingError)?,
-        ))
+        decode_configs(&fs::read(path).map_err(Error::IOError)?).map(Some)
     } else {
         Ok(None)
     }
@@ -52,17 +176,134 @@ pub fn get_configs() -> Result<Option<Configs>, Error> {
 
 pub fn set_configs(configs: &Configs) -> Result<(), Error> {
     let path = get_config_file()?;
-    fs::write(path, scrypto_encode(configs)).map_err(Error::IOError)
+    fs::write(path, encode_configs(configs)).map_err(Error::IOError)
+}
+
+fn encode_configs(configs: &Configs) -> Vec<u8> {
+    scrypto_encode(&VersionedConfigs::V1(configs.clone()))
+}
+
+/// Decodes configurations, falling back to the layouts used before they were versioned.
+fn decode_configs(bytes: &[u8]) -> Result<Configs, Error> {
+    match scrypto_decode::<VersionedConfigs>(bytes) {
+        Ok(VersionedConfigs::V1(configs)) => Ok(configs),
+        Err(error) => scrypto_decode::<UnnamedConfigs>(bytes)
+            .map(Configs::from)
+            .or_else(|_| scrypto_decode::<LegacyConfigs>(bytes).map(Configs::from))
+            .map_err(|_| Error::ConfigDecodingError(error)),
+    }
 }
 
 pub fn get_default_account() -> Result<ComponentAddress, Error> {
     get_configs()?
-        .ok_or(Error::NoDefaultAccount)
-        .map(|config| config.default_account)
+        .ok_or(Error::NoDefaultAccount)?
+        .get_default_account()
+        .map(|account| account.component_address)
 }
 
 pub fn get_default_private_key() -> Result<EcdsaPrivateKey, Error> {
     get_configs()?
-        .ok_or(Error::NoDefaultAccount)
-        .map(|config| EcdsaPrivateKey::from_bytes(&config.default_private_key).unwrap())
+        .ok_or(Error::NoDefaultAccount)?
+        .get_default_account()?
+        .key
+        .private_key()
+}
+
+/// Returns the account of the given name.
+pub fn get_account(name: &str) -> Result<AccountProfile, Error> {
+    get_configs()?
+        .ok_or(Error::AccountNotFound(name.to_owned()))?
+        .get_account(name)
+        .map(Clone::clone)
+}
+
+/// Replaces the arguments that refer to an account as `@<name>` with its address.
+pub fn substitute_accounts_in_args(args: &[String]) -> Result<Vec<String>, Error> {
+    args.iter()
+        .map(|arg| match arg.strip_prefix('@') {
+            Some(name) => get_account(name).map(|account| account.component_address.to_string()),
+            None => Ok(arg.clone()),
+        })
+        .collect()
+}
+
+/// Parses a component address, or an account as `@<name>`.
+pub fn parse_component_address(address: &str) -> Result<ComponentAddress, Error> {
+    match address.strip_prefix('@') {
+        Some(name) => get_account(name).map(|account| account.component_address),
+        None => {
+            ComponentAddress::from_str(address).map_err(|_| Error::InvalidId(address.to_owned()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scrypto::constants::SYSTEM_COMPONENT;
+
+    #[test]
+    fn test_encode_decode_configs() {
+        let configs = Configs {
+            default_account: "alice".to_owned(),
+            accounts: BTreeMap::from([(
+                "alice".to_owned(),
+                AccountProfile {
+                    component_address: SYSTEM_COMPONENT,
+                    key: KeyReference::Keystore("alice".to_owned()),
+                },
+            )]),
+        };
+
+        let decoded = decode_configs(&encode_configs(&configs)).unwrap();
+        assert_eq!(decoded.default_account, "alice");
+        assert_eq!(
+            decoded.get_default_account().unwrap().component_address,
+            SYSTEM_COMPONENT
+        );
+    }
+
+    #[test]
+    fn test_decode_legacy_configs() {
+        #[derive(TypeId, Encode)]
+        struct LegacyConfigs {
+            default_account: ComponentAddress,
+            default_private_key: Vec<u8>,
+        }
+        let bytes = scrypto_encode(&LegacyConfigs {
+            default_account: SYSTEM_COMPONENT,
+            default_private_key: vec![7u8; 32],
+        });
+
+        let configs = decode_configs(&bytes).unwrap();
+        let account = configs.get_default_account().unwrap();
+        assert_eq!(account.component_address, SYSTEM_COMPONENT);
+        assert!(matches!(&account.key, KeyReference::Plain(bytes) if bytes == &vec![7u8; 32]));
+    }
+
+    #[test]
+    fn test_decode_unnamed_configs() {
+        #[derive(TypeId, Encode)]
+        struct UnnamedConfigs {
+            default_account: ComponentAddress,
+            default_key: KeyReference,
+        }
+        let bytes = scrypto_encode(&UnnamedConfigs {
+            default_account: SYSTEM_COMPONENT,
+            default_key: KeyReference::Keystore("alice".to_owned()),
+        });
+
+        let configs = decode_configs(&bytes).unwrap();
+        let account = configs.get_default_account().unwrap();
+        assert_eq!(account.component_address, SYSTEM_COMPONENT);
+        assert!(matches!(&account.key, KeyReference::Keystore(name) if name == "alice"));
+    }
+
+    #[test]
+    fn test_decode_invalid_configs() {
+        assert!(matches!(
+            decode_configs(&[0u8; 4]),
+            Err(Error::ConfigDecodingError(_))
+        ));
+    }
 }
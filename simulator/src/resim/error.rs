@@ -3,6 +3,7 @@ use std::io;
 use radix_engine::errors::*;
 use radix_engine::transaction::*;
 use sbor::*;
+use scrypto::resource::MetadataError;
 
 use crate::ledger::*;
 use crate::utils::*;
@@ -16,6 +17,8 @@ pub enum Error {
 
     ConfigDecodingError(sbor::DecodeError),
 
+    HistoryDecodingError(sbor::DecodeError),
+
     IOError(io::Error),
 
     DataError(DecodeError),
@@ -42,5 +45,45 @@ pub enum Error {
 
     InvalidId(String),
 
+    InvalidMetadata(MetadataError),
+
     InvalidPrivateKey,
+
+    LedgerCheckpointError(rocksdb::Error),
+
+    InvalidSnapshotName(String),
+
+    SnapshotNotFound(String),
+
+    SnapshotAlreadyExists(String),
+
+    TransactionNotFound(String),
+
+    ReplayStateMismatch(usize),
+
+    KeystoreDecodingError(sbor::DecodeError),
+
+    KeyNotFound(String),
+
+    KeyAlreadyExists(String),
+
+    KeyDecryptionError,
+
+    InvalidEncryptedKey,
+
+    PassphraseMismatch,
+
+    AccountNotFound(String),
+
+    AccountAlreadyExists(String),
+
+    InvalidTime(String),
+
+    InvalidDuration(String),
+
+    TimeError(TimeError),
+
+    EpochOverflow,
+
+    TimeOverflow,
 }
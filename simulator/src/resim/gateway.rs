@@ -0,0 +1,479 @@
+use radix_engine::ledger::*;
+use radix_engine::model::*;
+use radix_engine::transaction::*;
+use scrypto::buffer::*;
+use scrypto::crypto::*;
+use scrypto::engine::types::*;
+use scrypto::values::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::str::FromStr;
+
+use crate::ledger::*;
+use crate::resim::*;
+
+pub const JSON_RPC_VERSION: &'static str = "2.0";
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const SERVER_ERROR: i64 = -32000;
+
+/// Represents a JSON-RPC error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    fn invalid_params<T: ToString>(message: T) -> Self {
+        Self {
+            code: INVALID_PARAMS,
+            message: message.to_string(),
+        }
+    }
+
+    fn server_error<T: ToString>(message: T) -> Self {
+        Self {
+            code: SERVER_ERROR,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<Error> for RpcError {
+    fn from(error: Error) -> Self {
+        Self::server_error(format!("{:?}", error))
+    }
+}
+
+/// Handles a JSON-RPC 2.0 request body, and returns the response body.
+///
+/// Every call opens the ledger and releases it before returning, like any other resim
+/// command, so that the gateway and the CLI can be used alternately.
+pub fn handle_json_rpc(body: &str) -> String {
+    let response = match serde_json::from_str::<Value>(body) {
+        Ok(request) => handle_request(&request),
+        Err(e) => error_response(
+            Value::Null,
+            RpcError {
+                code: PARSE_ERROR,
+                message: e.to_string(),
+            },
+        ),
+    };
+    response.to_string()
+}
+
+fn handle_request(request: &Value) -> Value {
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    if request.get("jsonrpc").and_then(Value::as_str) != Some(JSON_RPC_VERSION) {
+        return error_response(
+            id,
+            RpcError {
+                code: INVALID_REQUEST,
+                message: "Not a JSON-RPC 2.0 request".to_owned(),
+            },
+        );
+    }
+    let method = match request.get("method").and_then(Value::as_str) {
+        Some(method) => method,
+        None => {
+            return error_response(
+                id,
+                RpcError {
+                    code: INVALID_REQUEST,
+                    message: "Missing method".to_owned(),
+                },
+            )
+        }
+    };
+    let params = request.get("params").cloned().unwrap_or(json!({}));
+
+    let result = match method {
+        "submit_transaction" => submit_transaction(&params),
+        "preview_transaction" => preview_transaction(&params),
+        "get_receipt" => get_receipt(&params),
+        "get_package" => get_package(&params),
+        "get_component" => get_component(&params),
+        "get_resource" => get_resource(&params),
+        "get_vault" => get_vault(&params),
+        "get_balances" => get_balances(&params),
+        "list_packages" => list_packages(),
+        "list_components" => list_components(),
+        "list_resources" => list_resources(),
+        _ => Err(RpcError {
+            code: METHOD_NOT_FOUND,
+            message: format!("Method not found: {}", method),
+        }),
+    };
+
+    match result {
+        Ok(result) => json!({
+            "jsonrpc": JSON_RPC_VERSION,
+            "id": id,
+            "result": result,
+        }),
+        Err(error) => error_response(id, error),
+    }
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": JSON_RPC_VERSION,
+        "id": id,
+        "error": {
+            "code": error.code,
+            "message": error.message,
+        },
+    })
+}
+
+//==============
+// transactions
+//==============
+
+/// Executes and commits a signed transaction, given as either SBOR hex or a manifest.
+fn submit_transaction(params: &Value) -> Result<Value, RpcError> {
+    let transaction = parse_transaction(params)?;
+    let mut signatures = Vec::new();
+    for signature in params
+        .get("signatures")
+        .and_then(Value::as_array)
+        .unwrap_or(&Vec::new())
+    {
+        let public_key = EcdsaPublicKey::from_str(get_str(signature, "public_key")?)
+            .map_err(|_| RpcError::invalid_params("Invalid public key"))?;
+        let signature = EcdsaSignature::from_str(get_str(signature, "signature")?)
+            .map_err(|_| RpcError::invalid_params("Invalid signature"))?;
+        signatures.push((public_key, signature));
+    }
+    let signed = SignedTransaction {
+        transaction,
+        signatures,
+    };
+
+    let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    let mut executor = TransactionExecutor::new(&mut ledger, false);
+    let receipt = execute_and_record(&mut executor, &signed)?;
+    Ok(receipt_to_json(&receipt))
+}
+
+/// Executes an unsigned transaction against a throwaway copy of the ledger, as if it
+/// were signed by the given public keys.
+fn preview_transaction(params: &Value) -> Result<Value, RpcError> {
+    let transaction = parse_transaction(params)?;
+    let mut signers = Vec::new();
+    for public_key in params
+        .get("signer_public_keys")
+        .and_then(Value::as_array)
+        .unwrap_or(&Vec::new())
+    {
+        signers.push(
+            public_key
+                .as_str()
+                .and_then(|s| EcdsaPublicKey::from_str(s).ok())
+                .ok_or(RpcError::invalid_params("Invalid public key"))?,
+        );
+    }
+    let mut validated = SignedTransaction {
+        transaction,
+        signatures: Vec::new(),
+    }
+    .validate()
+    .map_err(|e| RpcError::server_error(format!("{:?}", e)))?;
+    validated.signers = signers;
+
+    let preview_dir = std::env::temp_dir().join(format!("resim-preview-{}", uuid::Uuid::new_v4()));
+    RadixEngineDB::with_bootstrap(get_data_dir()?)
+        .checkpoint(preview_dir.clone())
+        .map_err(|e| RpcError::server_error(e))?;
+    let receipt = {
+        let mut ledger = RadixEngineDB::new(preview_dir.clone());
+        let mut executor = TransactionExecutor::new(&mut ledger, false);
+        executor.execute(validated)
+    };
+    fs::remove_dir_all(preview_dir).ok();
+    Ok(receipt_to_json(&receipt))
+}
+
+fn get_receipt(params: &Value) -> Result<Value, RpcError> {
+    let transaction_hash = get_str(params, "transaction_hash")?;
+    let entry = get_history()?
+        .into_iter()
+        .find(|entry| entry.transaction_hash().to_string() == transaction_hash)
+        .ok_or(RpcError::server_error(format!(
+            "Transaction not found: {}",
+            transaction_hash
+        )))?;
+    Ok(json!({
+        "transaction_hash": transaction_hash,
+        "epoch": entry.epoch,
+        "time": entry.time,
+        "success": entry.success,
+        "receipt": entry.receipt,
+    }))
+}
+
+fn parse_transaction(params: &Value) -> Result<Transaction, RpcError> {
+    if let Some(transaction) = params.get("transaction").and_then(Value::as_str) {
+        let bytes = hex::decode(transaction)
+            .map_err(|_| RpcError::invalid_params("Transaction is not hex encoded"))?;
+        scrypto_decode(&bytes).map_err(|e| RpcError::invalid_params(format!("{:?}", e)))
+    } else if let Some(manifest) = params.get("manifest").and_then(Value::as_str) {
+        transaction_manifest::compile(manifest)
+            .map_err(|e| RpcError::invalid_params(format!("{:?}", e)))
+    } else {
+        Err(RpcError::invalid_params(
+            "Either transaction or manifest is required",
+        ))
+    }
+}
+
+fn receipt_to_json(receipt: &Receipt) -> Value {
+    json!({
+        "transaction_hash": receipt.validated_transaction.raw_hash.to_string(),
+        "status": match &receipt.result {
+            Ok(()) => "SUCCESS".to_owned(),
+            Err(e) => format!("{:?}", e),
+        },
+        "outputs": receipt.outputs.iter().map(|o| o.to_string()).collect::<Vec<String>>(),
+        "logs": receipt
+            .logs
+            .iter()
+            .map(|(level, message)| json!({ "level": format!("{:?}", level), "message": message }))
+            .collect::<Vec<Value>>(),
+        "new_package_addresses": receipt
+            .new_package_addresses
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>(),
+        "new_component_addresses": receipt
+            .new_component_addresses
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>(),
+        "new_resource_addresses": receipt
+            .new_resource_addresses
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>(),
+    })
+}
+
+//=======
+// state
+//=======
+
+fn get_package(params: &Value) -> Result<Value, RpcError> {
+    let package_address = PackageAddress::from_str(get_str(params, "address")?)
+        .map_err(|e| RpcError::invalid_params(format!("{:?}", e)))?;
+    let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    let package: Package = ledger
+        .get_decoded_substate(&package_address)
+        .map(|(package, _)| package)
+        .ok_or(RpcError::server_error("Package not found"))?;
+    Ok(json!({
+        "package_address": package_address.to_string(),
+        "code_size": package.code().len(),
+    }))
+}
+
+fn get_component(params: &Value) -> Result<Value, RpcError> {
+    let component_address = ComponentAddress::from_str(get_str(params, "address")?)
+        .map_err(|e| RpcError::invalid_params(format!("{:?}", e)))?;
+    let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    let component: Component = ledger
+        .get_decoded_substate(&component_address)
+        .map(|(component, _)| component)
+        .ok_or(RpcError::server_error("Component not found"))?;
+    let state = ScryptoValue::from_slice(component.state())
+        .map_err(|e| RpcError::server_error(format!("{:?}", e)))?;
+    let vaults = find_vaults(component_address, &ledger)
+        .map_err(|e| RpcError::server_error(format!("{:?}", e)))?
+        .into_iter()
+        .map(|vault_id| vault_to_json(component_address, vault_id, &ledger))
+        .collect::<Result<Vec<Value>, RpcError>>()?;
+
+    let executor = TransactionExecutor::new(&mut ledger, false);
+    let abi = executor
+        .export_abi_by_component(component_address)
+        .map_err(|e| RpcError::server_error(format!("{:?}", e)))?;
+
+    Ok(json!({
+        "component_address": component_address.to_string(),
+        "package_address": component.package_address().to_string(),
+        "blueprint_name": component.blueprint_name(),
+        "state": state.to_string(),
+        "vaults": vaults,
+        "abi": serde_json::to_value(&abi).map_err(|e| RpcError::server_error(e))?,
+    }))
+}
+
+fn get_resource(params: &Value) -> Result<Value, RpcError> {
+    let resource_address = ResourceAddress::from_str(get_str(params, "address")?)
+        .map_err(|e| RpcError::invalid_params(format!("{:?}", e)))?;
+    let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    let resource_manager: ResourceManager = ledger
+        .get_decoded_substate(&resource_address)
+        .map(|(resource_manager, _)| resource_manager)
+        .ok_or(RpcError::server_error("Resource not found"))?;
+    Ok(json!({
+        "resource_address": resource_address.to_string(),
+        "resource_type": format!("{:?}", resource_manager.resource_type()),
+        "metadata": resource_manager.metadata(),
+        "total_supply": resource_manager.total_supply().to_string(),
+        "max_supply": resource_manager.max_supply().map(|s| s.to_string()),
+    }))
+}
+
+fn get_vault(params: &Value) -> Result<Value, RpcError> {
+    let component_address = ComponentAddress::from_str(get_str(params, "component_address")?)
+        .map_err(|e| RpcError::invalid_params(format!("{:?}", e)))?;
+    let vault = scrypto::resource::Vault::from_str(get_str(params, "vault_id")?)
+        .map_err(|e| RpcError::invalid_params(format!("{:?}", e)))?;
+    let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    vault_to_json(component_address, vault.0, &ledger)
+}
+
+/// Returns the total amount of each resource held by a component, e.g. an account.
+fn get_balances(params: &Value) -> Result<Value, RpcError> {
+    let component_address = ComponentAddress::from_str(get_str(params, "address")?)
+        .map_err(|e| RpcError::invalid_params(format!("{:?}", e)))?;
+    let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    let mut balances = HashMap::<String, Decimal>::new();
+    for vault_id in find_vaults(component_address, &ledger)
+        .map_err(|e| RpcError::server_error(format!("{:?}", e)))?
+    {
+        let vault: Vault = ledger
+            .get_decoded_child_substate(&component_address, &vault_id)
+            .map(|(vault, _)| vault)
+            .ok_or(RpcError::server_error("Vault not found"))?;
+        let balance = balances
+            .entry(vault.resource_address().to_string())
+            .or_insert(Decimal::zero());
+        *balance = *balance + vault.total_amount();
+    }
+
+    let mut balances: Vec<(String, Decimal)> = balances.into_iter().collect();
+    balances.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(Value::Array(
+        balances
+            .into_iter()
+            .map(|(resource_address, amount)| {
+                json!({
+                    "resource_address": resource_address,
+                    "amount": amount.to_string(),
+                })
+            })
+            .collect(),
+    ))
+}
+
+fn list_packages() -> Result<Value, RpcError> {
+    let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    Ok(json!(ledger
+        .list_packages()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()))
+}
+
+fn list_components() -> Result<Value, RpcError> {
+    let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    Ok(json!(ledger
+        .list_components()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()))
+}
+
+fn list_resources() -> Result<Value, RpcError> {
+    let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    Ok(json!(ledger
+        .list_resource_managers()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()))
+}
+
+fn vault_to_json(
+    component_address: ComponentAddress,
+    vault_id: VaultId,
+    ledger: &RadixEngineDB,
+) -> Result<Value, RpcError> {
+    let vault: Vault = ledger
+        .get_decoded_child_substate(&component_address, &vault_id)
+        .map(|(vault, _)| vault)
+        .ok_or(RpcError::server_error("Vault not found"))?;
+    let non_fungible_ids = match vault.resource_type() {
+        ResourceType::NonFungible { .. } => Some(
+            vault
+                .total_ids()
+                .map_err(|e| RpcError::server_error(format!("{:?}", e)))?
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+        ),
+        _ => None,
+    };
+    Ok(json!({
+        "vault_id": scrypto::resource::Vault(vault_id).to_string(),
+        "resource_address": vault.resource_address().to_string(),
+        "amount": vault.total_amount().to_string(),
+        "non_fungible_ids": non_fungible_ids,
+    }))
+}
+
+fn get_str<'a>(params: &'a Value, name: &str) -> Result<&'a str, RpcError> {
+    params
+        .get(name)
+        .and_then(Value::as_str)
+        .ok_or(RpcError::invalid_params(format!(
+            "Missing parameter: {}",
+            name
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let response: Value = serde_json::from_str(&handle_json_rpc("{")).unwrap();
+        assert_eq!(response["error"]["code"], json!(PARSE_ERROR));
+        assert_eq!(response["id"], Value::Null);
+    }
+
+    #[test]
+    fn test_invalid_request() {
+        let response: Value =
+            serde_json::from_str(&handle_json_rpc(r#"{"id": 1, "method": "list_packages"}"#))
+                .unwrap();
+        assert_eq!(response["error"]["code"], json!(INVALID_REQUEST));
+        assert_eq!(response["id"], json!(1));
+    }
+
+    #[test]
+    fn test_method_not_found() {
+        let response: Value = serde_json::from_str(&handle_json_rpc(
+            r#"{"jsonrpc": "2.0", "id": "a", "method": "foo"}"#,
+        ))
+        .unwrap();
+        assert_eq!(response["error"]["code"], json!(METHOD_NOT_FOUND));
+        assert_eq!(response["id"], json!("a"));
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let response: Value = serde_json::from_str(&handle_json_rpc(
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "get_component", "params": {}}"#,
+        ))
+        .unwrap();
+        assert_eq!(response["error"]["code"], json!(INVALID_PARAMS));
+    }
+}
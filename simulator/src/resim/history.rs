@@ -0,0 +1,95 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use radix_engine::model::*;
+use sbor::*;
+use scrypto::buffer::*;
+use scrypto::crypto::*;
+
+use crate::resim::*;
+
+pub const HISTORY_FILE_NAME: &'static str = "history.sbor";
+
+/// A transaction executed by the simulator.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct HistoryEntry {
+    pub transaction: Transaction,
+    pub signatures: Vec<(EcdsaPublicKey, EcdsaSignature)>,
+    /// The epoch the transaction was executed in
+    pub epoch: u64,
+    /// The time the transaction was executed at, in milliseconds since the Unix epoch
+    pub time: u64,
+    pub success: bool,
+    /// The printed receipt
+    pub receipt: String,
+    /// The ledger state hash after execution
+    pub state_hash: Hash,
+}
+
+impl HistoryEntry {
+    pub fn transaction_hash(&self) -> Hash {
+        self.transaction.raw_hash()
+    }
+
+    pub fn signed_transaction(&self) -> SignedTransaction {
+        SignedTransaction {
+            transaction: self.transaction.clone(),
+            signatures: self.signatures.clone(),
+        }
+    }
+}
+
+/// Returns the transaction history file.
+pub fn get_history_file() -> Result<PathBuf, Error> {
+    let mut path = get_data_dir()?;
+    path.push(HISTORY_FILE_NAME);
+    Ok(path)
+}
+
+/// Returns all transactions in execution order.
+pub fn get_history() -> Result<Vec<HistoryEntry>, Error> {
+    let path = get_history_file()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    // The file is a sequence of length-prefixed SBOR entries.
+    let bytes = fs::read(path).map_err(Error::IOError)?;
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        if offset + 4 > bytes.len() {
+            return Err(Error::HistoryDecodingError(DecodeError::Underflow {
+                required: 4,
+                remaining: bytes.len() - offset,
+            }));
+        }
+        let mut len = [0u8; 4];
+        len.copy_from_slice(&bytes[offset..offset + 4]);
+        let len = u32::from_le_bytes(len) as usize;
+        let end = offset + 4 + len;
+        if end > bytes.len() {
+            return Err(Error::HistoryDecodingError(DecodeError::Underflow {
+                required: len,
+                remaining: bytes.len() - offset - 4,
+            }));
+        }
+        entries.push(scrypto_decode(&bytes[offset + 4..end]).map_err(Error::HistoryDecodingError)?);
+        offset = end;
+    }
+    Ok(entries)
+}
+
+/// Appends a transaction to the history.
+pub fn append_history(entry: &HistoryEntry) -> Result<(), Error> {
+    let bytes = scrypto_encode(entry);
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_history_file()?)
+        .map_err(Error::IOError)?;
+    file.write_all(&(bytes.len() as u32).to_le_bytes())
+        .map_err(Error::IOError)?;
+    file.write_all(&bytes).map_err(Error::IOError)
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use chacha20poly1305::aead::{Aead, NewAead, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::Rng;
+use sbor::*;
+use scrypto::buffer::*;
+use scrypto::crypto::*;
+
+use crate::resim::*;
+
+pub const KEYSTORE_FILE_NAME: &'static str = "keystore.sbor";
+pub const ENV_KEYSTORE_PASSPHRASE: &'static str = "KEYSTORE_PASSPHRASE";
+
+/// The scrypt parameters used for new keys, as recommended for interactive logins.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+/// Named private keys, each encrypted with a passphrase.
+#[derive(Debug, Clone, Default, TypeId, Encode, Decode)]
+pub struct Keystore {
+    pub keys: BTreeMap<String, EncryptedKey>,
+}
+
+/// A private key encrypted with ChaCha20-Poly1305, under a key derived from a passphrase
+/// with scrypt.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct EncryptedKey {
+    /// The public key, kept in plain so that keys can be listed without a passphrase
+    pub public_key: EcdsaPublicKey,
+    pub scrypt_log_n: u8,
+    pub scrypt_r: u32,
+    pub scrypt_p: u32,
+    pub salt: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedKey {
+    pub fn encrypt(private_key: &EcdsaPrivateKey, passphrase: &str) -> Self {
+        let public_key = private_key.public_key();
+        let salt = rand::thread_rng().gen::<[u8; SALT_LENGTH]>().to_vec();
+        let nonce = rand::thread_rng().gen::<[u8; NONCE_LENGTH]>().to_vec();
+        let cipher = Self::cipher(passphrase, &salt, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)
+            .expect("Default scrypt parameters should be valid");
+        let ciphertext = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &private_key.to_bytes(),
+                    aad: &public_key.to_vec(),
+                },
+            )
+            .expect("Failed to encrypt private key");
+
+        Self {
+            public_key,
+            scrypt_log_n: SCRYPT_LOG_N,
+            scrypt_r: SCRYPT_R,
+            scrypt_p: SCRYPT_P,
+            salt,
+            nonce,
+            ciphertext,
+        }
+    }
+
+    pub fn decrypt(&self, passphrase: &str) -> Result<EcdsaPrivateKey, Error> {
+        if self.nonce.len() != NONCE_LENGTH {
+            return Err(Error::InvalidEncryptedKey);
+        }
+        let cipher = Self::cipher(
+            passphrase,
+            &self.salt,
+            self.scrypt_log_n,
+            self.scrypt_r,
+            self.scrypt_p,
+        )?;
+        let bytes = cipher
+            .decrypt(
+                Nonce::from_slice(&self.nonce),
+                Payload {
+                    msg: &self.ciphertext,
+                    aad: &self.public_key.to_vec(),
+                },
+            )
+            .map_err(|_| Error::KeyDecryptionError)?;
+        EcdsaPrivateKey::from_bytes(&bytes).map_err(|_| Error::InvalidPrivateKey)
+    }
+
+    fn cipher(
+        passphrase: &str,
+        salt: &[u8],
+        log_n: u8,
+        r: u32,
+        p: u32,
+    ) -> Result<ChaCha20Poly1305, Error> {
+        let params = scrypt::Params::new(log_n, r, p).map_err(|_| Error::InvalidEncryptedKey)?;
+        let mut key = [0u8; 32];
+        scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+            .map_err(|_| Error::InvalidEncryptedKey)?;
+        Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+    }
+}
+
+/// Returns the keystore file.
+pub fn get_keystore_file() -> Result<PathBuf, Error> {
+    let mut path = get_data_dir()?;
+    path.push(KEYSTORE_FILE_NAME);
+    Ok(path)
+}
+
+pub fn get_keystore() -> Result<Keystore, Error> {
+    let path = get_keystore_file()?;
+    if path.exists() {
+        scrypto_decode(&fs::read(path).map_err(Error::IOError)?)
+            .map_err(Error::KeystoreDecodingError)
+    } else {
+        Ok(Keystore::default())
+    }
+}
+
+pub fn set_keystore(keystore: &Keystore) -> Result<(), Error> {
+    let path = get_keystore_file()?;
+    fs::write(path, scrypto_encode(keystore)).map_err(Error::IOError)
+}
+
+/// Encrypts a private key and adds it to the keystore under a new name.
+pub fn add_key(name: &str, private_key: &EcdsaPrivateKey) -> Result<EcdsaPublicKey, Error> {
+    let mut keystore = get_keystore()?;
+    if keystore.keys.contains_key(name) {
+        return Err(Error::KeyAlreadyExists(name.to_owned()));
+    }
+    let passphrase = read_passphrase(&format!("New passphrase for key {}: ", name), true)?;
+    let encrypted_key = EncryptedKey::encrypt(private_key, &passphrase);
+    let public_key = encrypted_key.public_key;
+    keystore.keys.insert(name.to_owned(), encrypted_key);
+    set_keystore(&keystore)?;
+    Ok(public_key)
+}
+
+/// Returns the encrypted key of the given name.
+pub fn get_key(name: &str) -> Result<EncryptedKey, Error> {
+    get_keystore()?
+        .keys
+        .remove(name)
+        .ok_or(Error::KeyNotFound(name.to_owned()))
+}
+
+/// Decrypts the key of the given name, asking for its passphrase.
+pub fn unlock_key(name: &str) -> Result<EcdsaPrivateKey, Error> {
+    let encrypted_key = get_key(name)?;
+    let passphrase = read_passphrase(&format!("Passphrase for key {}: ", name), false)?;
+    encrypted_key.decrypt(&passphrase)
+}
+
+/// Reads a passphrase from the `KEYSTORE_PASSPHRASE` environment variable, or else from the
+/// terminal.
+pub fn read_passphrase(prompt: &str, confirm: bool) -> Result<String, Error> {
+    if let Ok(passphrase) = env::var(ENV_KEYSTORE_PASSPHRASE) {
+        return Ok(passphrase);
+    }
+
+    let passphrase = rpassword::prompt_password(prompt).map_err(Error::IOError)?;
+    if confirm {
+        let confirmation =
+            rpassword::prompt_password("Confirm passphrase: ").map_err(Error::IOError)?;
+        if confirmation != passphrase {
+            return Err(Error::PassphraseMismatch);
+        }
+    }
+    Ok(passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_key() {
+        let private_key = EcdsaPrivateKey::from_bytes(&[7u8; 32]).unwrap();
+        let encrypted_key = EncryptedKey::encrypt(&private_key, "correct horse");
+        assert_eq!(encrypted_key.public_key, private_key.public_key());
+        assert_ne!(encrypted_key.ciphertext, private_key.to_bytes());
+
+        let decrypted_key = encrypted_key.decrypt("correct horse").unwrap();
+        assert_eq!(decrypted_key.to_bytes(), private_key.to_bytes());
+        assert!(matches!(
+            encrypted_key.decrypt("battery staple"),
+            Err(Error::KeyDecryptionError)
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_malformed_key() {
+        let private_key = EcdsaPrivateKey::from_bytes(&[7u8; 32]).unwrap();
+
+        let mut encrypted_key = EncryptedKey::encrypt(&private_key, "correct horse");
+        encrypted_key.nonce.pop();
+        assert!(matches!(
+            encrypted_key.decrypt("correct horse"),
+            Err(Error::InvalidEncryptedKey)
+        ));
+
+        let mut encrypted_key = EncryptedKey::encrypt(&private_key, "correct horse");
+        encrypted_key.scrypt_r = 0;
+        assert!(matches!(
+            encrypted_key.decrypt("correct horse"),
+            Err(Error::InvalidEncryptedKey)
+        ));
+    }
+}
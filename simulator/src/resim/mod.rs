@@ -1,7 +1,11 @@
+mod cmd_advance_epoch;
+mod cmd_advance_time;
 mod cmd_call_function;
 mod cmd_call_method;
 mod cmd_export_abi;
 mod cmd_generate_key_pair;
+mod cmd_history;
+mod cmd_key;
 mod cmd_mint;
 mod cmd_new_account;
 mod cmd_new_badge_fixed;
@@ -9,21 +13,34 @@ mod cmd_new_badge_mutable;
 mod cmd_new_token_fixed;
 mod cmd_new_token_mutable;
 mod cmd_publish;
+mod cmd_replay;
 mod cmd_reset;
 mod cmd_run;
+mod cmd_serve;
 mod cmd_set_current_epoch;
 mod cmd_set_default_account;
+mod cmd_set_time;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_show_tx;
+mod cmd_snapshot;
 mod cmd_transfer;
+mod clock;
 mod config;
 mod error;
+mod gateway;
+mod history;
+mod keystore;
 
+pub use cmd_advance_epoch::*;
+pub use cmd_advance_time::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
 pub use cmd_export_abi::*;
 pub use cmd_generate_key_pair::*;
+pub use cmd_history::*;
+pub use cmd_key::*;
 pub use cmd_mint::*;
 pub use cmd_new_account::*;
 pub use cmd_new_badge_fixed::*;
@@ -31,16 +48,25 @@ pub use cmd_new_badge_mutable::*;
 pub use cmd_new_token_fixed::*;
 pub use cmd_new_token_mutable::*;
 pub use cmd_publish::*;
+pub use cmd_replay::*;
 pub use cmd_reset::*;
 pub use cmd_run::*;
+pub use cmd_serve::*;
 pub use cmd_set_current_epoch::*;
 pub use cmd_set_default_account::*;
+pub use cmd_set_time::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_show_tx::*;
+pub use cmd_snapshot::*;
 pub use cmd_transfer::*;
+pub use clock::*;
 pub use config::*;
 pub use error::*;
+pub use gateway::*;
+pub use history::*;
+pub use keystore::*;
 
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
@@ -64,6 +90,10 @@ use crate::ledger::*;
 pub struct ResimCli {
     #[clap(subcommand)]
     pub(crate) command: Command,
+
+    /// Run the command against a throwaway copy of the current state
+    #[clap(long, global = true)]
+    pub(crate) fork: bool,
 }
 
 impl ResimCli {
@@ -74,10 +104,14 @@ impl ResimCli {
 
 #[derive(Subcommand, Debug)]
 pub enum Command {
+    AdvanceEpoch(AdvanceEpoch),
+    AdvanceTime(AdvanceTime),
     CallFunction(CallFunction),
     CallMethod(CallMethod),
     ExportAbi(ExportAbi),
     GenerateKeyPair(GenerateKeyPair),
+    History(History),
+    Key(Key),
     Mint(Mint),
     NewAccount(NewAccount),
     NewBadgeFixed(NewBadgeFixed),
@@ -85,26 +119,37 @@ pub enum Command {
     NewTokenFixed(NewTokenFixed),
     NewTokenMutable(NewTokenMutable),
     Publish(Publish),
+    Replay(Replay),
     Reset(Reset),
     Run(Run),
+    Serve(Serve),
     SetCurrentEpoch(SetCurrentEpoch),
     SetDefaultAccount(SetDefaultAccount),
+    SetTime(SetTime),
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
+    ShowTx(ShowTx),
     Show(Show),
+    Snapshot(Snapshot),
     Transfer(Transfer),
 }
 
 pub fn run() -> Result<(), Error> {
     let cli = ResimCli::parse();
 
+    let _fork = if cli.fork { Some(Fork::new()?) } else { None };
+
     let mut out = std::io::stdout();
 
     match cli.command {
+        Command::AdvanceEpoch(cmd) => cmd.run(&mut out),
+        Command::AdvanceTime(cmd) => cmd.run(&mut out),
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
         Command::ExportAbi(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
+        Command::History(cmd) => cmd.run(&mut out),
+        Command::Key(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
         Command::NewAccount(cmd) => cmd.run(&mut out),
         Command::NewBadgeFixed(cmd) => cmd.run(&mut out),
@@ -112,19 +157,24 @@ pub fn run() -> Result<(), Error> {
         Command::NewTokenFixed(cmd) => cmd.run(&mut out),
         Command::NewTokenMutable(cmd) => cmd.run(&mut out),
         Command::Publish(cmd) => cmd.run(&mut out),
+        Command::Replay(cmd) => cmd.run(&mut out),
         Command::Reset(cmd) => cmd.run(&mut out),
         Command::Run(cmd) => cmd.run(&mut out),
+        Command::Serve(cmd) => cmd.run(&mut out),
         Command::SetCurrentEpoch(cmd) => cmd.run(&mut out),
         Command::SetDefaultAccount(cmd) => cmd.run(&mut out),
+        Command::SetTime(cmd) => cmd.run(&mut out),
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
+        Command::ShowTx(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
+        Command::Snapshot(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
     }
 }
 
-pub fn process_transaction<L: SubstateStore, O: std::io::Write>(
-    executor: &mut TransactionExecutor<L>,
+pub fn process_transaction<O: std::io::Write>(
+    executor: &mut TransactionExecutor<RadixEngineDB>,
     mut transaction: Transaction,
     signing_keys: &Option<String>,
     manifest_path: &Option<PathBuf>,
@@ -148,26 +198,40 @@ pub fn process_transaction<L: SubstateStore, O: std::io::Write>(
             let nonce = executor.get_nonce(&pks);
             transaction.add_nonce(nonce);
             let signed = transaction.sign(sks.iter().collect::<Vec<&EcdsaPrivateKey>>());
-            let receipt = executor
-                .validate_and_execute(&signed)
-                .map_err(Error::TransactionValidationError)?;
+            let receipt = execute_and_record(executor, &signed)?;
             writeln!(out, "{:?}", receipt).map_err(Error::IOError)?;
             receipt.result.map_err(Error::TransactionExecutionError)
         }
     }
 }
 
+/// Validates and executes a signed transaction, and appends it to the transaction history.
+pub fn execute_and_record(
+    executor: &mut TransactionExecutor<RadixEngineDB>,
+    signed: &SignedTransaction,
+) -> Result<Receipt, Error> {
+    let epoch = executor.substate_store().get_epoch();
+    let time = executor.substate_store().get_time();
+    let receipt = executor
+        .validate_and_execute(signed)
+        .map_err(Error::TransactionValidationError)?;
+    append_history(&HistoryEntry {
+        transaction: signed.transaction.clone(),
+        signatures: signed.signatures.clone(),
+        epoch,
+        time,
+        success: receipt.result.is_ok(),
+        receipt: format!("{:?}", receipt),
+        state_hash: executor.substate_store().state_hash(),
+    })?;
+    Ok(receipt)
+}
+
 pub fn parse_signing_keys(signing_keys: &Option<String>) -> Result<Vec<EcdsaPrivateKey>, Error> {
     let private_keys = if let Some(keys) = signing_keys {
         keys.split(",")
             .map(str::trim)
-            .map(|key| {
-                hex::decode(key)
-                    .map_err(|_| Error::InvalidPrivateKey)
-                    .and_then(|bytes| {
-                        EcdsaPrivateKey::from_bytes(&bytes).map_err(|_| Error::InvalidPrivateKey)
-                    })
-            })
+            .map(|key| KeyReference::parse(key).and_then(|key| key.private_key()))
             .collect::<Result<Vec<EcdsaPrivateKey>, Error>>()?
     } else {
         vec![get_default_private_key()?]
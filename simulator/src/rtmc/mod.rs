@@ -1,33 +1,91 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use scrypto::buffer::scrypto_encode;
 use std::path::PathBuf;
-use transaction_manifest::compile;
+use transaction_manifest::{compile, format};
 
 /// Radix transaction manifest compiler
 #[derive(Parser, Debug)]
-#[clap(author, version, about, long_about = None, name = "rtmc")]
+#[clap(
+    author,
+    version,
+    about,
+    long_about = None,
+    name = "rtmc",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 pub struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Path to the output file
-    #[clap(short, long)]
-    output: PathBuf,
+    #[clap(short, long, required = true)]
+    output: Option<PathBuf>,
 
     /// Input file
     #[clap(required = true)]
-    input: PathBuf,
+    input: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    Fmt(Fmt),
+}
+
+/// Format transaction manifests in canonical style
+#[derive(Parser, Debug)]
+pub struct Fmt {
+    /// Check if the files are formatted, without modifying them
+    #[clap(long)]
+    check: bool,
+
+    /// Input files
+    #[clap(required = true)]
+    inputs: Vec<PathBuf>,
 }
 
 #[derive(Debug)]
 pub enum Error {
     IoError(std::io::Error),
     CompileError(transaction_manifest::CompileError),
+    FormatError(transaction_manifest::FormatError),
+    NotFormatted(Vec<PathBuf>),
 }
 
 pub fn run() -> Result<(), Error> {
     let args = Args::parse();
 
-    let content = std::fs::read_to_string(args.input).map_err(Error::IoError)?;
-    let transaction = compile(&content).map_err(Error::CompileError)?;
-    std::fs::write(args.output, scrypto_encode(&transaction)).map_err(Error::IoError)?;
+    match args.command {
+        Some(Command::Fmt(cmd)) => cmd.run(),
+        None => {
+            let content = std::fs::read_to_string(args.input.unwrap()).map_err(Error::IoError)?;
+            let transaction = compile(&content).map_err(Error::CompileError)?;
+            std::fs::write(args.output.unwrap(), scrypto_encode(&transaction))
+                .map_err(Error::IoError)?;
+            Ok(())
+        }
+    }
+}
+
+impl Fmt {
+    pub fn run(&self) -> Result<(), Error> {
+        let mut not_formatted = Vec::new();
+        for input in &self.inputs {
+            let content = std::fs::read_to_string(input).map_err(Error::IoError)?;
+            let formatted = format(&content).map_err(Error::FormatError)?;
+            if formatted != content {
+                if self.check {
+                    not_formatted.push(input.clone());
+                } else {
+                    std::fs::write(input, formatted).map_err(Error::IoError)?;
+                }
+            }
+        }
 
-    Ok(())
+        if not_formatted.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::NotFormatted(not_formatted))
+        }
+    }
 }
@@ -7,6 +7,9 @@ cd "$(dirname "$0")/.."
 
 resim="cargo run --bin resim $@ --"
 
+# Account keys are kept in the keystore
+export KEYSTORE_PASSPHRASE=resim
+
 # Create test accounts and public keys
 $resim reset
 temp=`$resim new-account | awk '/Account component address:/ {print $NF}'`
@@ -16,6 +19,12 @@ account2=`$resim new-account | awk '/Account component address:/ {print $NF}'`
 # Test - create fixed supply badge
 minter_badge=`$resim new-badge-fixed 1 --name 'MintBadge' | awk '/Resource:/ {print $NF}'`
 
+# Test - create fixed supply token with well-known metadata, rejecting invalid entries
+$resim new-token-fixed 1000 --symbol 'TT' --name 'TestToken' --url 'https://example.com'
+locked_token=`$resim new-token-fixed 1000 --name 'LockedToken' --lock name --lock symbol | awk '/Resource:/ {print $NF}'`
+$resim show $locked_token | grep 'LockedToken (locked)'
+if $resim new-token-fixed 1000 --symbol 'NOT A SYMBOL'; then exit 1; fi
+
 # Test - create mutable supply token
 token_address=`$resim new-token-mutable $minter_badge | awk '/Resource:/ {print $NF}'`
 
@@ -28,6 +37,13 @@ package=`$resim publish ../examples/hello-world | awk '/Package:/ {print $NF}'`
 component=`$resim call-function $package Hello instantiate_hello | awk '/Component:/ {print $NF}'`
 $resim call-method $component free_token
 
+# Test - upgrade a package owned by the first account, while packages without an owner are immutable
+if $resim publish ../examples/hello-world --package-address $package; then exit 1; fi
+owned_package=`$resim publish ../examples/hello-world --owner @account1 | awk '/Package:/ {print $NF}'`
+owned_component=`$resim call-function $owned_package Hello instantiate_hello | awk '/Component:/ {print $NF}'`
+$resim publish ../examples/hello-world --package-address $owned_package --signing-keys @account1
+$resim call-method $owned_component free_token
+
 # Test - export abi
 $resim export-abi $package Hello
 
@@ -36,6 +52,7 @@ $resim show $package
 $resim show $component
 $resim show $account
 $resim show $account2
+$resim show $component --depth 1 --json
 $resim show $token_address
 
 # Test - output manifest
@@ -52,6 +69,50 @@ $resim run ./target/temp2.rtm --signing-keys 4fc0db017bf9b80743b7151fee3f04bad81
 
 # Test - nft
 package=`$resim publish ./tests/nft | awk '/Package:/ {print $NF}'`
-$resim call-function $package Foo nfts
+nft_address=`$resim call-function $package Foo nfts | awk '/Resource:/ {print $NF}'`
+$resim show $account
+$resim show $nft_address --offset 1 --limit 1
+
+# Test - snapshot and fork
+$resim snapshot save fixture
+$resim --fork new-account
+$resim snapshot list
+$resim new-account
+$resim snapshot load fixture
 $resim show $account
 
+# Test - transaction history and replay
+$resim history
+tx_hash=`$resim history | tail -n 1 | awk '{print $2}'`
+$resim show-tx $tx_hash
+$resim replay --from 1
+
+# Test - encrypted keystore
+$resim key import alice 4fc0db017bf9b80743b7151fee3f04bad817f2d8d7e34ae96c022fe7451b0ea3
+$resim key new bob
+$resim key list
+$resim key export alice
+$resim run ./target/temp2.rtm --signing-keys alice,bob
+$resim new-account --key carol
+
+# Test - loading a snapshot keeps keys created after it
+$resim snapshot save before-erin
+$resim key new erin
+$resim snapshot load before-erin
+$resim key export erin
+
+# Test - named accounts
+$resim new-account --name dave
+$resim transfer 1 $token_address @dave
+$resim call-method @dave balance $token_address
+$resim set-default-account dave
+$resim show @dave
+$resim show-configs
+$resim set-default-account account1
+
+# Test - epoch and time
+$resim set-current-epoch 10
+$resim advance-epoch 2
+$resim set-time 2022-05-01T12:00:00Z
+$resim advance-time "1h 30m"
+$resim show-ledger
@@ -40,6 +40,18 @@ pub enum Instruction {
         resource_address: Value,
     },
 
+    AssertWorktopIsEmpty,
+
+    AssertWorktopContainsAtMost {
+        amount: Value,
+        resource_address: Value,
+    },
+
+    AssertWorktopContainsExactIds {
+        ids: Value,
+        resource_address: Value,
+    },
+
     PopFromAuthZone {
         new_proof: Value,
     },
@@ -101,6 +113,45 @@ pub enum Instruction {
 
     PublishPackage {
         code: Value,
+        metadata: Option<Value>,
+        owner: Option<Value>,
+    },
+
+    UpgradePackage {
+        package_address: Value,
+        code: Value,
+    },
+
+    RecallFromVault {
+        component_address: Value,
+        vault: Value,
+        amount: Value,
+    },
+
+    FreezeVault {
+        component_address: Value,
+        vault: Value,
+    },
+
+    UnfreezeVault {
+        component_address: Value,
+        vault: Value,
+    },
+
+    SetComponentMetadata {
+        component_address: Value,
+        key: Value,
+        value: Value,
+    },
+
+    SetPackageMetadata {
+        package_address: Value,
+        key: Value,
+        value: Value,
+    },
+
+    Nonce {
+        nonce: Value,
     },
 }
 
@@ -136,12 +187,16 @@ pub enum Type {
 
     /* Custom types */
     Decimal,
+    PreciseDecimal,
+    I256,
+    U256,
     PackageAddress,
     ComponentAddress,
     ResourceAddress,
     Hash,
     Bucket,
     Proof,
+    Vault,
     NonFungibleId,
     NonFungibleAddress,
 
@@ -178,12 +233,16 @@ pub enum Value {
     HashMap(Type, Type, Vec<Value>),
 
     Decimal(Box<Value>),
+    PreciseDecimal(Box<Value>),
+    I256(Box<Value>),
+    U256(Box<Value>),
     PackageAddress(Box<Value>),
     ComponentAddress(Box<Value>),
     ResourceAddress(Box<Value>),
     Hash(Box<Value>),
     Bucket(Box<Value>),
     Proof(Box<Value>),
+    Vault(Box<Value>),
     NonFungibleId(Box<Value>),
     NonFungibleAddress(Box<Value>),
 
@@ -218,12 +277,16 @@ impl Value {
             Value::HashSet(_, _) => Type::HashSet,
             Value::HashMap(_, _, _) => Type::HashMap,
             Value::Decimal(_) => Type::Decimal,
+            Value::PreciseDecimal(_) => Type::PreciseDecimal,
+            Value::I256(_) => Type::I256,
+            Value::U256(_) => Type::U256,
             Value::PackageAddress(_) => Type::PackageAddress,
             Value::ComponentAddress(_) => Type::ComponentAddress,
             Value::ResourceAddress(_) => Type::ResourceAddress,
             Value::Hash(_) => Type::Hash,
             Value::Bucket(_) => Type::Bucket,
             Value::Proof(_) => Type::Proof,
+            Value::Vault(_) => Type::Vault,
             Value::NonFungibleId(_) => Type::NonFungibleId,
             Value::NonFungibleAddress(_) => Type::NonFungibleAddress,
             Value::Bytes(_) => Type::Vec,
@@ -1,9 +1,16 @@
 use radix_engine::engine::*;
 use radix_engine::model::*;
+use radix_engine::transaction::AbiProvider;
+use sbor::describe::{Fields, Type};
+use scrypto::abi;
+use scrypto::buffer::scrypto_decode;
 use scrypto::engine::types::*;
+use scrypto::resource::{AccessRule, AccessRules};
 use scrypto::rust::collections::*;
 use scrypto::values::*;
 
+use crate::formatter::format_string;
+
 #[derive(Debug, Clone)]
 pub enum DecompileError {
     IdValidatorError(IdValidatorError),
@@ -11,6 +18,25 @@ pub enum DecompileError {
 }
 
 pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
+    decompile_internal(tx, None)
+}
+
+/// Decompiles a transaction, annotating each function or method call with its signature as
+/// described by the blueprint ABI.
+///
+/// Calls whose ABI can't be exported, e.g. a method of a component instantiated within the
+/// same transaction, are decompiled without annotation.
+pub fn decompile_with_abi<A: AbiProvider>(
+    tx: &Transaction,
+    abi_provider: &A,
+) -> Result<String, DecompileError> {
+    decompile_internal(tx, Some(abi_provider as &dyn AbiProvider))
+}
+
+fn decompile_internal(
+    tx: &Transaction,
+    abi_provider: Option<&dyn AbiProvider>,
+) -> Result<String, DecompileError> {
     let mut buf = String::new();
     let mut id_validator = IdValidator::new();
     let mut buckets = HashMap::<BucketId, String>::new();
@@ -54,7 +80,7 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
                 buf.push_str(&format!(
                     "TAKE_FROM_WORKTOP_BY_IDS TreeSet<NonFungibleId>({}) ResourceAddress(\"{}\") Bucket(\"{}\");\n",
                     ids.iter()
-                    .map(|k| format!("NonFungibleId(\"{}\")", k))
+                    .map(ScryptoValueFormatter::format_non_fungible_id)
                     .collect::<Vec<String>>()
                     .join(", "),
                     resource_address, name
@@ -94,7 +120,32 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
                 buf.push_str(&format!(
                     "ASSERT_WORKTOP_CONTAINS_BY_IDS TreeSet<NonFungibleId>({}) ResourceAddress(\"{}\");\n",
                     ids.iter()
-                        .map(|k| format!("NonFungibleId(\"{}\")", k))
+                        .map(ScryptoValueFormatter::format_non_fungible_id)
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    resource_address
+                ));
+            }
+            Instruction::AssertWorktopIsEmpty => {
+                buf.push_str("ASSERT_WORKTOP_IS_EMPTY;\n");
+            }
+            Instruction::AssertWorktopContainsAtMost {
+                amount,
+                resource_address,
+            } => {
+                buf.push_str(&format!(
+                    "ASSERT_WORKTOP_CONTAINS_AT_MOST Decimal(\"{}\") ResourceAddress(\"{}\");\n",
+                    amount, resource_address
+                ));
+            }
+            Instruction::AssertWorktopContainsExactIds {
+                ids,
+                resource_address,
+            } => {
+                buf.push_str(&format!(
+                    "ASSERT_WORKTOP_CONTAINS_EXACT_IDS TreeSet<NonFungibleId>({}) ResourceAddress(\"{}\");\n",
+                    ids.iter()
+                        .map(ScryptoValueFormatter::format_non_fungible_id)
                         .collect::<Vec<String>>()
                         .join(", "),
                     resource_address
@@ -160,7 +211,7 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
                 proofs.insert(proof_id, name.clone());
                 buf.push_str(&format!(
                     "CREATE_PROOF_FROM_AUTH_ZONE_BY_IDS TreeSet<NonFungibleId>({}) ResourceAddress(\"{}\") Proof(\"{}\");\n",ids.iter()
-                    .map(|k| format!("NonFungibleId(\"{}\")", k))
+                    .map(ScryptoValueFormatter::format_non_fungible_id)
                     .collect::<Vec<String>>()
                     .join(", "),
                     resource_address, name
@@ -214,9 +265,23 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
                 function,
                 args,
             } => {
+                if let Some(abi) =
+                    abi_provider.and_then(|p| p.export_abi(package_address, &blueprint_name).ok())
+                {
+                    if let Some(f) = abi.functions.iter().find(|f| f.name == function) {
+                        buf.push_str(&format!(
+                            "# {}::{}\n",
+                            abi.blueprint_name,
+                            format_signature(None, &f.name, &f.inputs, &f.input_names, &f.output)
+                        ));
+                        buf.push_str(&format_access_rule_args(&f.inputs, &f.input_names, &args));
+                    }
+                }
                 buf.push_str(&format!(
-                    "CALL_FUNCTION PackageAddress(\"{}\") \"{}\" \"{}\"",
-                    package_address, blueprint_name, function
+                    "CALL_FUNCTION PackageAddress(\"{}\") {} {}",
+                    package_address,
+                    format_string(&blueprint_name),
+                    format_string(&function)
                 ));
                 for arg in args {
                     let validated_arg = ScryptoValue::from_slice(&arg)
@@ -234,9 +299,28 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
                 method,
                 args,
             } => {
+                if let Some(abi) =
+                    abi_provider.and_then(|p| p.export_abi_by_component(component_address).ok())
+                {
+                    if let Some(m) = abi.methods.iter().find(|m| m.name == method) {
+                        buf.push_str(&format!(
+                            "# {}::{}\n",
+                            abi.blueprint_name,
+                            format_signature(
+                                Some(&m.mutability),
+                                &m.name,
+                                &m.inputs,
+                                &m.input_names,
+                                &m.output
+                            )
+                        ));
+                        buf.push_str(&format_access_rule_args(&m.inputs, &m.input_names, &args));
+                    }
+                }
                 buf.push_str(&format!(
-                    "CALL_METHOD ComponentAddress(\"{}\") \"{}\"",
-                    component_address, method
+                    "CALL_METHOD ComponentAddress(\"{}\") {}",
+                    component_address,
+                    format_string(&method)
                 ));
                 for arg in args {
                     let validated_arg = ScryptoValue::from_slice(&arg)
@@ -257,18 +341,104 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
                     .move_all_resources()
                     .map_err(DecompileError::IdValidatorError)?;
                 buf.push_str(&format!(
-                    "CALL_METHOD_WITH_ALL_RESOURCES ComponentAddress(\"{}\") \"{}\";\n",
-                    component_address, method
+                    "CALL_METHOD_WITH_ALL_RESOURCES ComponentAddress(\"{}\") {};\n",
+                    component_address,
+                    format_string(&method)
+                ));
+            }
+            Instruction::PublishPackage {
+                code,
+                metadata,
+                owner,
+            } => {
+                if let Some(owner) = &owner {
+                    buf.push_str(&format!("# owner: {}\n", owner));
+                }
+                buf.push_str(&format!(
+                    "PUBLISH_PACKAGE Bytes(\"{}\")",
+                    hex::encode(&code)
                 ));
+                if !metadata.is_empty() || owner.is_some() {
+                    let entries: Vec<String> = metadata
+                        .iter()
+                        .flat_map(|(k, v)| [format_string(k), format_string(v)])
+                        .collect();
+                    buf.push_str(&format!(" TreeMap<String, String>({})", entries.join(", ")));
+                }
+                if let Some(owner) = &owner {
+                    buf.push(' ');
+                    buf.push_str(&ScryptoValue::from_value(owner).to_string());
+                }
+                buf.push_str(";\n");
             }
-            Instruction::PublishPackage { code } => {
+            Instruction::UpgradePackage {
+                package_address,
+                code,
+            } => {
                 buf.push_str(&format!(
-                    "PUBLISH_PACKAGE Bytes(\"{}\");\n",
+                    "UPGRADE_PACKAGE PackageAddress(\"{}\") Bytes(\"{}\");\n",
+                    package_address,
                     hex::encode(&code)
                 ));
             }
-            Instruction::Nonce { .. } => {
-                // TODO: add support for this
+            Instruction::RecallFromVault {
+                component_address,
+                vault_id,
+                amount,
+            } => {
+                buf.push_str(&format!(
+                    "RECALL_FROM_VAULT ComponentAddress(\"{}\") Vault(\"{}\") Decimal(\"{}\");\n",
+                    component_address,
+                    scrypto::resource::Vault(vault_id),
+                    amount
+                ));
+            }
+            Instruction::FreezeVault {
+                component_address,
+                vault_id,
+            } => {
+                buf.push_str(&format!(
+                    "FREEZE_VAULT ComponentAddress(\"{}\") Vault(\"{}\");\n",
+                    component_address,
+                    scrypto::resource::Vault(vault_id)
+                ));
+            }
+            Instruction::UnfreezeVault {
+                component_address,
+                vault_id,
+            } => {
+                buf.push_str(&format!(
+                    "UNFREEZE_VAULT ComponentAddress(\"{}\") Vault(\"{}\");\n",
+                    component_address,
+                    scrypto::resource::Vault(vault_id)
+                ));
+            }
+            Instruction::SetComponentMetadata {
+                component_address,
+                key,
+                value,
+            } => {
+                buf.push_str(&format!(
+                    "SET_COMPONENT_METADATA ComponentAddress(\"{}\") {} {};\n",
+                    component_address,
+                    format_string(&key),
+                    format_string(&value)
+                ));
+            }
+            Instruction::SetPackageMetadata {
+                package_address,
+                key,
+                value,
+            } => {
+                buf.push_str(&format!(
+                    "SET_PACKAGE_METADATA PackageAddress(\"{}\") {} {};\n",
+                    package_address,
+                    format_string(&key),
+                    format_string(&value)
+                ));
+            }
+            Instruction::Nonce { nonce } => {
+                buf.push_str(&format!("NONCE {}u64;\n", nonce));
             }
         }
     }
@@ -276,10 +446,153 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
     Ok(buf)
 }
 
+/// Returns the name of the i-th input, falling back to its position for ABIs without names.
+fn input_name(input_names: &[String], i: usize) -> String {
+    input_names
+        .get(i)
+        .cloned()
+        .unwrap_or_else(|| format!("arg{}", i))
+}
+
+/// Formats a function or method signature.
+fn format_signature(
+    mutability: Option<&abi::Mutability>,
+    name: &str,
+    inputs: &[Type],
+    input_names: &[String],
+    output: &Type,
+) -> String {
+    let mut args = Vec::new();
+    match mutability {
+        Some(abi::Mutability::Immutable) => args.push("&self".to_string()),
+        Some(abi::Mutability::Mutable) => args.push("&mut self".to_string()),
+        None => {}
+    }
+    for (i, input) in inputs.iter().enumerate() {
+        args.push(format!(
+            "{}: {}",
+            input_name(input_names, i),
+            format_type(input)
+        ));
+    }
+    format!("{}({}) -> {}", name, args.join(", "), format_type(output))
+}
+
+/// Formats the access rules passed as arguments of a call, as comments in `rule!` syntax.
+fn format_access_rule_args(inputs: &[Type], input_names: &[String], args: &[Vec<u8>]) -> String {
+    let mut buf = String::new();
+    for (i, (input, arg)) in inputs.iter().zip(args).enumerate() {
+        match input {
+            Type::Enum { name, .. } if name == "AccessRule" => {
+                if let Ok(rule) = scrypto_decode::<AccessRule>(arg) {
+                    buf.push_str(&format!("# {}: {}\n", input_name(input_names, i), rule));
+                }
+            }
+            Type::Struct { name, .. } if name == "AccessRules" => {
+                if let Ok(rules) = scrypto_decode::<AccessRules>(arg) {
+                    let mut methods: Vec<(&String, &AccessRule)> = rules.iter().collect();
+                    methods.sort_by_key(|(method, _)| *method);
+                    for (method, rule) in methods {
+                        buf.push_str(&format!(
+                            "# {}.{}: {}\n",
+                            input_name(input_names, i),
+                            method,
+                            rule
+                        ));
+                    }
+                    buf.push_str(&format!(
+                        "# {}.default: {}\n",
+                        input_name(input_names, i),
+                        rules.get_default()
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    buf
+}
+
+/// Formats a type described by the ABI, in Rust syntax.
+fn format_type(ty: &Type) -> String {
+    match ty {
+        Type::Unit => "()".to_string(),
+        Type::Bool => "bool".to_string(),
+        Type::I8 => "i8".to_string(),
+        Type::I16 => "i16".to_string(),
+        Type::I32 => "i32".to_string(),
+        Type::I64 => "i64".to_string(),
+        Type::I128 => "i128".to_string(),
+        Type::U8 => "u8".to_string(),
+        Type::U16 => "u16".to_string(),
+        Type::U32 => "u32".to_string(),
+        Type::U64 => "u64".to_string(),
+        Type::U128 => "u128".to_string(),
+        Type::String => "String".to_string(),
+        Type::Option { value } => format!("Option<{}>", format_type(value)),
+        Type::Array { element, length } => format!("[{}; {}]", format_type(element), length),
+        Type::Tuple { elements } => format!(
+            "({})",
+            elements
+                .iter()
+                .map(format_type)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        Type::Struct { name, fields } => match fields {
+            Fields::Named { named } if !named.is_empty() => format!(
+                "{} {{ {} }}",
+                name,
+                named
+                    .iter()
+                    .map(|(n, t)| format!("{}: {}", n, format_type(t)))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            _ => name.clone(),
+        },
+        Type::Enum { name, .. } => name.clone(),
+        Type::Result { okay, error } => {
+            format!("Result<{}, {}>", format_type(okay), format_type(error))
+        }
+        Type::Vec { element } => format!("Vec<{}>", format_type(element)),
+        Type::TreeSet { element } => format!("BTreeSet<{}>", format_type(element)),
+        Type::TreeMap { key, value } => {
+            format!("BTreeMap<{}, {}>", format_type(key), format_type(value))
+        }
+        Type::HashSet { element } => format!("HashSet<{}>", format_type(element)),
+        Type::HashMap { key, value } => {
+            format!("HashMap<{}, {}>", format_type(key), format_type(value))
+        }
+        Type::Custom { name, generics } => {
+            if generics.is_empty() {
+                name.clone()
+            } else {
+                format!(
+                    "{}<{}>",
+                    name,
+                    generics
+                        .iter()
+                        .map(format_type)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::compile;
+    use radix_engine::errors::RuntimeError;
+    use sbor::Describe;
+    use scrypto::buffer::scrypto_encode;
+    use scrypto::constants::{SYSTEM_COMPONENT, SYSTEM_PACKAGE};
+    use scrypto::crypto::EcdsaPrivateKey;
+    use scrypto::prelude::require_signature;
+    use scrypto::{access_rule_node, rule};
 
     #[test]
     fn test_decompile() {
@@ -290,4 +603,140 @@ mod tests {
 
         assert_eq!(compile(manifest).unwrap(), tx);
     }
+
+    #[test]
+    fn test_decompile_nonce() {
+        let mut tx = compile(include_str!("../examples/complex.rtm")).unwrap();
+        tx.add_nonce(5);
+
+        let manifest = &decompile(&tx).unwrap();
+        assert!(manifest.ends_with("NONCE 5u64;\n"));
+        assert_eq!(compile(manifest).unwrap(), tx);
+    }
+
+    struct TestAbiProvider {
+        blueprint: abi::Blueprint,
+    }
+
+    impl AbiProvider for TestAbiProvider {
+        fn export_abi(
+            &self,
+            _package_address: PackageAddress,
+            _blueprint_name: &str,
+        ) -> Result<abi::Blueprint, RuntimeError> {
+            Ok(self.blueprint.clone())
+        }
+
+        fn export_abi_by_component(
+            &self,
+            _component_address: ComponentAddress,
+        ) -> Result<abi::Blueprint, RuntimeError> {
+            Ok(self.blueprint.clone())
+        }
+    }
+
+    #[test]
+    fn test_decompile_with_abi() {
+        let abi_provider = TestAbiProvider {
+            blueprint: abi::Blueprint {
+                package_address: SYSTEM_PACKAGE.to_string(),
+                blueprint_name: "Locker".to_owned(),
+                structure: Type::Unit,
+                functions: vec![abi::Function {
+                    name: "new".to_owned(),
+                    inputs: vec![AccessRule::describe(), u32::describe()],
+                    input_names: vec!["withdraw_rule".to_owned(), "capacity".to_owned()],
+                    output: ComponentAddress::describe(),
+                }],
+                methods: vec![abi::Method {
+                    name: "set_rules".to_owned(),
+                    mutability: abi::Mutability::Mutable,
+                    inputs: vec![AccessRules::describe()],
+                    input_names: vec!["rules".to_owned()],
+                    output: Type::Unit,
+                }],
+            },
+        };
+        let tx = Transaction {
+            instructions: vec![
+                Instruction::CallFunction {
+                    package_address: SYSTEM_PACKAGE,
+                    blueprint_name: "Locker".to_owned(),
+                    function: "new".to_owned(),
+                    args: vec![scrypto_encode(&rule!(deny_all)), scrypto_encode(&10u32)],
+                },
+                Instruction::CallMethod {
+                    component_address: SYSTEM_COMPONENT,
+                    method: "set_rules".to_owned(),
+                    args: vec![scrypto_encode(
+                        &AccessRules::new().default(rule!(allow_all)),
+                    )],
+                },
+            ],
+        };
+
+        let manifest = &decompile_with_abi(&tx, &abi_provider).unwrap();
+        println!("{}", manifest);
+
+        assert!(manifest.starts_with(
+            "# Locker::new(withdraw_rule: AccessRule, capacity: u32) -> ComponentAddress\n# withdraw_rule: rule!(deny_all)\nCALL_FUNCTION "
+        ));
+        assert!(manifest.contains("# Locker::set_rules(&mut self, rules: AccessRules"));
+        assert!(manifest.contains("# rules.default: rule!(allow_all)\nCALL_METHOD "));
+        assert_eq!(compile(manifest).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_format_type() {
+        assert_eq!(
+            format_signature(
+                Some(&abi::Mutability::Mutable),
+                "swap",
+                &[
+                    Type::Custom {
+                        name: "Bucket".to_owned(),
+                        generics: vec![],
+                    },
+                    Type::Option {
+                        value: Box::new(Type::U32),
+                    },
+                ],
+                &["payment".to_owned()],
+                &Type::Tuple {
+                    elements: vec![Type::String, Type::Bool],
+                },
+            ),
+            "swap(&mut self, payment: Bucket, arg1: Option<u32>) -> (String, bool)"
+        );
+    }
+
+    #[test]
+    fn test_format_access_rule_args() {
+        let public_key = EcdsaPrivateKey::from_bytes(&[1u8; 32])
+            .unwrap()
+            .public_key();
+        let access_rules = AccessRules::new()
+            .method("withdraw", rule!(require_signature(public_key)))
+            .default(rule!(allow_all));
+
+        assert_eq!(
+            format_access_rule_args(
+                &[Type::U8, AccessRule::describe(), AccessRules::describe()],
+                &[
+                    "amount".to_owned(),
+                    "mint_rule".to_owned(),
+                    "access_rules".to_owned(),
+                ],
+                &[
+                    scrypto_encode(&1u8),
+                    scrypto_encode(&rule!(deny_all)),
+                    scrypto_encode(&access_rules),
+                ],
+            ),
+            format!(
+                "# mint_rule: rule!(deny_all)\n# access_rules.withdraw: rule!(require_signature({}))\n# access_rules.default: rule!(allow_all)\n",
+                public_key
+            )
+        );
+    }
 }
@@ -0,0 +1,483 @@
+use crate::ast::{Instruction, Type, Value};
+use crate::lexer::{tokenize_with_comments, Comment, LexerError, Token, TokenKind};
+use crate::parser::{Parser, ParserError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    LexerError(LexerError),
+    ParserError(ParserError),
+}
+
+/// An instruction, together with the rows it spans in the source text.
+struct SourceInstruction {
+    instruction: Instruction,
+    start_row: usize,
+    end_row: usize,
+}
+
+/// Formats a transaction manifest in canonical style.
+///
+/// Unlike decompiling a compiled transaction, this works on the source text, so bucket and
+/// proof names as well as comments are preserved. Each instruction is printed on its own line,
+/// and consecutive blank lines are collapsed into one.
+pub fn format(s: &str) -> Result<String, FormatError> {
+    let (tokens, comments) = tokenize_with_comments(s).map_err(FormatError::LexerError)?;
+    let instructions = parse_instructions(tokens)?;
+
+    let mut buf = String::new();
+    let mut last_row: Option<usize> = None;
+    let mut comments = comments.into_iter().peekable();
+    for (i, inst) in instructions.iter().enumerate() {
+        // leading comments, including those within a multi-line instruction
+        while let Some(comment) = comments.next_if(|c| {
+            c.span.start.0 < inst.end_row
+                || (c.span.start.0 == inst.end_row
+                    && instructions
+                        .get(i + 1)
+                        .map_or(false, |next| next.start_row == inst.end_row))
+        }) {
+            push_line_break(&mut buf, &mut last_row, comment.span.start.0);
+            buf.push_str(&format_comment(&comment));
+            buf.push('\n');
+        }
+
+        push_line_break(&mut buf, &mut last_row, inst.start_row);
+        buf.push_str(&format_instruction(&inst.instruction));
+
+        // trailing comment on the same line
+        if let Some(comment) = comments.next_if(|c| c.span.start.0 == inst.end_row) {
+            buf.push(' ');
+            buf.push_str(&format_comment(&comment));
+        }
+        buf.push('\n');
+        last_row = Some(inst.end_row);
+    }
+
+    for comment in comments {
+        push_line_break(&mut buf, &mut last_row, comment.span.start.0);
+        buf.push_str(&format_comment(&comment));
+        buf.push('\n');
+    }
+
+    Ok(buf)
+}
+
+fn parse_instructions(tokens: Vec<Token>) -> Result<Vec<SourceInstruction>, FormatError> {
+    let mut result = Vec::new();
+    let mut chunk = Vec::new();
+    for token in tokens {
+        let is_end = token.kind == TokenKind::Semicolon;
+        chunk.push(token);
+        if is_end {
+            result.push(parse_instruction(chunk)?);
+            chunk = Vec::new();
+        }
+    }
+    if !chunk.is_empty() {
+        result.push(parse_instruction(chunk)?);
+    }
+    Ok(result)
+}
+
+fn parse_instruction(tokens: Vec<Token>) -> Result<SourceInstruction, FormatError> {
+    let start_row = tokens.first().map(|t| t.span.start.0).unwrap_or_default();
+    let end_row = tokens.last().map(|t| t.span.end.0).unwrap_or_default();
+    let instruction = Parser::new(tokens)
+        .parse_instruction()
+        .map_err(FormatError::ParserError)?;
+    Ok(SourceInstruction {
+        instruction,
+        start_row,
+        end_row,
+    })
+}
+
+/// Inserts a blank line if the source text has one or more blank lines before `row`.
+fn push_line_break(buf: &mut String, last_row: &mut Option<usize>, row: usize) {
+    if let Some(last) = *last_row {
+        if row > last + 1 {
+            buf.push('\n');
+        }
+    }
+    *last_row = Some(row);
+}
+
+fn format_comment(comment: &Comment) -> String {
+    format!("#{}", comment.text.trim_end())
+}
+
+pub fn format_instruction(instruction: &Instruction) -> String {
+    let (name, values): (&str, Vec<&Value>) = match instruction {
+        Instruction::TakeFromWorktop {
+            resource_address,
+            new_bucket,
+        } => ("TAKE_FROM_WORKTOP", vec![resource_address, new_bucket]),
+        Instruction::TakeFromWorktopByAmount {
+            amount,
+            resource_address,
+            new_bucket,
+        } => (
+            "TAKE_FROM_WORKTOP_BY_AMOUNT",
+            vec![amount, resource_address, new_bucket],
+        ),
+        Instruction::TakeFromWorktopByIds {
+            ids,
+            resource_address,
+            new_bucket,
+        } => (
+            "TAKE_FROM_WORKTOP_BY_IDS",
+            vec![ids, resource_address, new_bucket],
+        ),
+        Instruction::ReturnToWorktop { bucket } => ("RETURN_TO_WORKTOP", vec![bucket]),
+        Instruction::AssertWorktopContains { resource_address } => {
+            ("ASSERT_WORKTOP_CONTAINS", vec![resource_address])
+        }
+        Instruction::AssertWorktopContainsByAmount {
+            amount,
+            resource_address,
+        } => (
+            "ASSERT_WORKTOP_CONTAINS_BY_AMOUNT",
+            vec![amount, resource_address],
+        ),
+        Instruction::AssertWorktopContainsByIds {
+            ids,
+            resource_address,
+        } => (
+            "ASSERT_WORKTOP_CONTAINS_BY_IDS",
+            vec![ids, resource_address],
+        ),
+        Instruction::AssertWorktopIsEmpty => ("ASSERT_WORKTOP_IS_EMPTY", vec![]),
+        Instruction::AssertWorktopContainsAtMost {
+            amount,
+            resource_address,
+        } => (
+            "ASSERT_WORKTOP_CONTAINS_AT_MOST",
+            vec![amount, resource_address],
+        ),
+        Instruction::AssertWorktopContainsExactIds {
+            ids,
+            resource_address,
+        } => (
+            "ASSERT_WORKTOP_CONTAINS_EXACT_IDS",
+            vec![ids, resource_address],
+        ),
+        Instruction::PopFromAuthZone { new_proof } => ("POP_FROM_AUTH_ZONE", vec![new_proof]),
+        Instruction::PushToAuthZone { proof } => ("PUSH_TO_AUTH_ZONE", vec![proof]),
+        Instruction::ClearAuthZone => ("CLEAR_AUTH_ZONE", vec![]),
+        Instruction::CreateProofFromAuthZone {
+            resource_address,
+            new_proof,
+        } => (
+            "CREATE_PROOF_FROM_AUTH_ZONE",
+            vec![resource_address, new_proof],
+        ),
+        Instruction::CreateProofFromAuthZoneByAmount {
+            amount,
+            resource_address,
+            new_proof,
+        } => (
+            "CREATE_PROOF_FROM_AUTH_ZONE_BY_AMOUNT",
+            vec![amount, resource_address, new_proof],
+        ),
+        Instruction::CreateProofFromAuthZoneByIds {
+            ids,
+            resource_address,
+            new_proof,
+        } => (
+            "CREATE_PROOF_FROM_AUTH_ZONE_BY_IDS",
+            vec![ids, resource_address, new_proof],
+        ),
+        Instruction::CreateProofFromBucket { bucket, new_proof } => {
+            ("CREATE_PROOF_FROM_BUCKET", vec![bucket, new_proof])
+        }
+        Instruction::CloneProof { proof, new_proof } => ("CLONE_PROOF", vec![proof, new_proof]),
+        Instruction::DropProof { proof } => ("DROP_PROOF", vec![proof]),
+        Instruction::CallFunction {
+            package_address,
+            blueprint_name,
+            function,
+            args,
+        } => {
+            let mut values = vec![package_address, blueprint_name, function];
+            values.extend(args.iter());
+            ("CALL_FUNCTION", values)
+        }
+        Instruction::CallMethod {
+            component_address,
+            method,
+            args,
+        } => {
+            let mut values = vec![component_address, method];
+            values.extend(args.iter());
+            ("CALL_METHOD", values)
+        }
+        Instruction::CallMethodWithAllResources {
+            component_address,
+            method,
+        } => (
+            "CALL_METHOD_WITH_ALL_RESOURCES",
+            vec![component_address, method],
+        ),
+        Instruction::PublishPackage {
+            code,
+            metadata,
+            owner,
+        } => {
+            let mut values = vec![code];
+            values.extend(metadata.iter());
+            values.extend(owner.iter());
+            ("PUBLISH_PACKAGE", values)
+        }
+        Instruction::UpgradePackage {
+            package_address,
+            code,
+        } => ("UPGRADE_PACKAGE", vec![package_address, code]),
+        Instruction::RecallFromVault {
+            component_address,
+            vault,
+            amount,
+        } => ("RECALL_FROM_VAULT", vec![component_address, vault, amount]),
+        Instruction::FreezeVault {
+            component_address,
+            vault,
+        } => ("FREEZE_VAULT", vec![component_address, vault]),
+        Instruction::UnfreezeVault {
+            component_address,
+            vault,
+        } => ("UNFREEZE_VAULT", vec![component_address, vault]),
+        Instruction::SetComponentMetadata {
+            component_address,
+            key,
+            value,
+        } => (
+            "SET_COMPONENT_METADATA",
+            vec![component_address, key, value],
+        ),
+        Instruction::SetPackageMetadata {
+            package_address,
+            key,
+            value,
+        } => ("SET_PACKAGE_METADATA", vec![package_address, key, value]),
+        Instruction::Nonce { nonce } => ("NONCE", vec![nonce]),
+    };
+
+    let mut buf = String::from(name);
+    for value in values {
+        buf.push(' ');
+        buf.push_str(&format_value(value));
+    }
+    buf.push(';');
+    buf
+}
+
+pub fn format_value(value: &Value) -> String {
+    match value {
+        Value::Unit => "()".to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::I8(value) => format!("{}i8", value),
+        Value::I16(value) => format!("{}i16", value),
+        Value::I32(value) => format!("{}i32", value),
+        Value::I64(value) => format!("{}i64", value),
+        Value::I128(value) => format!("{}i128", value),
+        Value::U8(value) => format!("{}u8", value),
+        Value::U16(value) => format!("{}u16", value),
+        Value::U32(value) => format!("{}u32", value),
+        Value::U64(value) => format!("{}u64", value),
+        Value::U128(value) => format!("{}u128", value),
+        Value::String(value) => format_string(value),
+        Value::Struct(fields) => format!("Struct({})", format_values(fields)),
+        Value::Enum(name, fields) => format!(
+            "Enum({}{}{})",
+            format_string(name),
+            if fields.is_empty() { "" } else { ", " },
+            format_values(fields)
+        ),
+        Value::Option(value) => match &**value {
+            Some(x) => format!("Some({})", format_value(x)),
+            None => "None".to_string(),
+        },
+        Value::Array(ty, elements) => {
+            format!("Array<{}>({})", format_type(ty), format_values(elements))
+        }
+        Value::Tuple(elements) => format!("Tuple({})", format_values(elements)),
+        Value::Result(value) => match &**value {
+            Ok(x) => format!("Ok({})", format_value(x)),
+            Err(x) => format!("Err({})", format_value(x)),
+        },
+        Value::Vec(ty, elements) => {
+            format!("Vec<{}>({})", format_type(ty), format_values(elements))
+        }
+        Value::TreeSet(ty, elements) => {
+            format!("TreeSet<{}>({})", format_type(ty), format_values(elements))
+        }
+        Value::TreeMap(key, value, elements) => format!(
+            "TreeMap<{}, {}>({})",
+            format_type(key),
+            format_type(value),
+            format_values(elements)
+        ),
+        Value::HashSet(ty, elements) => {
+            format!("HashSet<{}>({})", format_type(ty), format_values(elements))
+        }
+        Value::HashMap(key, value, elements) => format!(
+            "HashMap<{}, {}>({})",
+            format_type(key),
+            format_type(value),
+            format_values(elements)
+        ),
+        Value::Decimal(inner) => format!("Decimal({})", format_value(inner)),
+        Value::PreciseDecimal(inner) => format!("PreciseDecimal({})", format_value(inner)),
+        Value::I256(inner) => format!("I256({})", format_value(inner)),
+        Value::U256(inner) => format!("U256({})", format_value(inner)),
+        Value::PackageAddress(inner) => format!("PackageAddress({})", format_value(inner)),
+        Value::ComponentAddress(inner) => format!("ComponentAddress({})", format_value(inner)),
+        Value::ResourceAddress(inner) => format!("ResourceAddress({})", format_value(inner)),
+        Value::Hash(inner) => format!("Hash({})", format_value(inner)),
+        Value::Bucket(inner) => format!("Bucket({})", format_value(inner)),
+        Value::Proof(inner) => format!("Proof({})", format_value(inner)),
+        Value::Vault(inner) => format!("Vault({})", format_value(inner)),
+        Value::NonFungibleId(inner) => format!("NonFungibleId({})", format_value(inner)),
+        Value::NonFungibleAddress(inner) => {
+            format!("NonFungibleAddress({})", format_value(inner))
+        }
+        Value::Bytes(bytes) => format!("Bytes(\"{}\")", hex::encode(bytes)),
+    }
+}
+
+pub fn format_type(ty: &Type) -> &'static str {
+    match ty {
+        Type::Unit => "Unit",
+        Type::Bool => "Bool",
+        Type::I8 => "I8",
+        Type::I16 => "I16",
+        Type::I32 => "I32",
+        Type::I64 => "I64",
+        Type::I128 => "I128",
+        Type::U8 => "U8",
+        Type::U16 => "U16",
+        Type::U32 => "U32",
+        Type::U64 => "U64",
+        Type::U128 => "U128",
+        Type::String => "String",
+        Type::Struct => "Struct",
+        Type::Enum => "Enum",
+        Type::Option => "Option",
+        Type::Array => "Array",
+        Type::Tuple => "Tuple",
+        Type::Result => "Result",
+        Type::Vec => "Vec",
+        Type::TreeSet => "TreeSet",
+        Type::TreeMap => "TreeMap",
+        Type::HashSet => "HashSet",
+        Type::HashMap => "HashMap",
+        Type::Decimal => "Decimal",
+        Type::PreciseDecimal => "PreciseDecimal",
+        Type::I256 => "I256",
+        Type::U256 => "U256",
+        Type::PackageAddress => "PackageAddress",
+        Type::ComponentAddress => "ComponentAddress",
+        Type::ResourceAddress => "ResourceAddress",
+        Type::Hash => "Hash",
+        Type::Bucket => "Bucket",
+        Type::Proof => "Proof",
+        Type::Vault => "Vault",
+        Type::NonFungibleId => "NonFungibleId",
+        Type::NonFungibleAddress => "NonFungibleAddress",
+        Type::Bytes => "Bytes",
+    }
+}
+
+/// Formats a string literal, escaping characters as per the JSON string specifications.
+pub fn format_string(s: &str) -> String {
+    let mut buf = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\x08' => buf.push_str("\\b"),
+            '\x0c' => buf.push_str("\\f"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if c.is_control() => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    buf.push_str(&format!("\\u{:04x}", unit));
+                }
+            }
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+    buf
+}
+
+fn format_values(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(format_value)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compile;
+
+    #[test]
+    fn test_format_is_idempotent() {
+        let manifest = include_str!("../examples/complex.rtm");
+
+        let formatted = format(manifest).unwrap();
+        assert_eq!(format(&formatted).unwrap(), formatted);
+        assert_eq!(compile(&formatted).unwrap(), compile(manifest).unwrap());
+    }
+
+    #[test]
+    fn test_format_keeps_names_and_comments() {
+        let manifest = r#"
+# Take all XRD
+TAKE_FROM_WORKTOP   ResourceAddress("030000000000000000000000000000000000000000000000000004")
+    Bucket("xrd");    # keep this
+
+
+CREATE_PROOF_FROM_BUCKET Bucket("xrd") Proof("auth"); DROP_PROOF Proof("auth");
+RETURN_TO_WORKTOP Bucket("xrd");
+# The end
+"#;
+        assert_eq!(
+            format(manifest).unwrap(),
+            r#"# Take all XRD
+TAKE_FROM_WORKTOP ResourceAddress("030000000000000000000000000000000000000000000000000004") Bucket("xrd"); # keep this
+
+CREATE_PROOF_FROM_BUCKET Bucket("xrd") Proof("auth");
+DROP_PROOF Proof("auth");
+RETURN_TO_WORKTOP Bucket("xrd");
+# The end
+"#
+        );
+    }
+
+    #[test]
+    fn test_format_values() {
+        let manifest = r#"CALL_METHOD ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1") "test" Enum("A") Enum("B",1u8) Struct( "a\"b\n" ,None ) HashMap<String,U8>("k",1u8) Vec<NonFungibleAddress>();"#;
+        assert_eq!(
+            format(manifest).unwrap(),
+            r#"CALL_METHOD ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1") "test" Enum("A") Enum("B", 1u8) Struct("a\"b\n", None) HashMap<String, U8>("k", 1u8) Vec<NonFungibleAddress>();
+"#
+        );
+    }
+
+    #[test]
+    fn test_format_failures() {
+        assert!(matches!(
+            format("CLEAR_AUTH_ZONE"),
+            Err(FormatError::ParserError(ParserError::UnexpectedEof))
+        ));
+        assert!(matches!(
+            format("CLEAR_AUTH_ZONE; ?"),
+            Err(FormatError::LexerError(_))
+        ));
+    }
+}
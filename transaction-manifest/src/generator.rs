@@ -4,9 +4,12 @@ use radix_engine::model::*;
 use sbor::any::{encode_any, Value};
 use sbor::type_id::*;
 use sbor::Encoder;
+use scrypto::buffer::scrypto_decode;
 use scrypto::engine::types::*;
-use scrypto::rust::collections::BTreeSet;
+use scrypto::math::{PreciseDecimal, I256, U256};
+use scrypto::resource::AccessRule;
 use scrypto::rust::collections::HashMap;
+use scrypto::rust::collections::{BTreeMap, BTreeSet};
 use scrypto::rust::str::FromStr;
 use scrypto::types::*;
 use scrypto::values::*;
@@ -25,6 +28,9 @@ pub enum GeneratorError {
     InvalidComponentAddress(String),
     InvalidResourceAddress(String),
     InvalidDecimal(String),
+    InvalidPreciseDecimal(String),
+    InvalidI256(String),
+    InvalidU256(String),
     InvalidHash(String),
     InvalidLazyMapId(String),
     InvalidVaultId(String),
@@ -187,6 +193,21 @@ pub fn generate_instruction(
             ids: generate_non_fungible_ids(ids)?,
             resource_address: generate_resource_address(resource_address)?,
         },
+        ast::Instruction::AssertWorktopIsEmpty => Instruction::AssertWorktopIsEmpty,
+        ast::Instruction::AssertWorktopContainsAtMost {
+            amount,
+            resource_address,
+        } => Instruction::AssertWorktopContainsAtMost {
+            amount: generate_decimal(amount)?,
+            resource_address: generate_resource_address(resource_address)?,
+        },
+        ast::Instruction::AssertWorktopContainsExactIds {
+            ids,
+            resource_address,
+        } => Instruction::AssertWorktopContainsExactIds {
+            ids: generate_non_fungible_ids(ids)?,
+            resource_address: generate_resource_address(resource_address)?,
+        },
         ast::Instruction::PopFromAuthZone { new_proof } => {
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
@@ -325,9 +346,72 @@ pub fn generate_instruction(
                 method: generate_string(method)?,
             }
         }
-        ast::Instruction::PublishPackage { code } => Instruction::PublishPackage {
+        ast::Instruction::PublishPackage {
+            code,
+            metadata,
+            owner,
+        } => Instruction::PublishPackage {
+            code: generate_bytes(code)?,
+            metadata: match metadata {
+                Some(metadata) => generate_metadata(metadata)?,
+                None => BTreeMap::new(),
+            },
+            owner: match owner {
+                Some(owner) => Some(generate_access_rule(owner, resolver)?),
+                None => None,
+            },
+        },
+        ast::Instruction::UpgradePackage {
+            package_address,
+            code,
+        } => Instruction::UpgradePackage {
+            package_address: generate_package_address(package_address)?,
             code: generate_bytes(code)?,
         },
+        ast::Instruction::RecallFromVault {
+            component_address,
+            vault,
+            amount,
+        } => Instruction::RecallFromVault {
+            component_address: generate_component_address(component_address)?,
+            vault_id: generate_vault(vault)?,
+            amount: generate_decimal(amount)?,
+        },
+        ast::Instruction::FreezeVault {
+            component_address,
+            vault,
+        } => Instruction::FreezeVault {
+            component_address: generate_component_address(component_address)?,
+            vault_id: generate_vault(vault)?,
+        },
+        ast::Instruction::UnfreezeVault {
+            component_address,
+            vault,
+        } => Instruction::UnfreezeVault {
+            component_address: generate_component_address(component_address)?,
+            vault_id: generate_vault(vault)?,
+        },
+        ast::Instruction::SetComponentMetadata {
+            component_address,
+            key,
+            value,
+        } => Instruction::SetComponentMetadata {
+            component_address: generate_component_address(component_address)?,
+            key: generate_string(key)?,
+            value: generate_string(value)?,
+        },
+        ast::Instruction::SetPackageMetadata {
+            package_address,
+            key,
+            value,
+        } => Instruction::SetPackageMetadata {
+            package_address: generate_package_address(package_address)?,
+            key: generate_string(key)?,
+            value: generate_string(value)?,
+        },
+        ast::Instruction::Nonce { nonce } => Instruction::Nonce {
+            nonce: generate_u64(nonce)?,
+        },
     })
 }
 
@@ -364,6 +448,44 @@ fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
     }
 }
 
+fn generate_u64(value: &ast::Value) -> Result<u64, GeneratorError> {
+    match value {
+        ast::Value::U64(n) => Ok(*n),
+        v @ _ => invalid_type!(v, ast::Type::U64),
+    }
+}
+
+fn generate_metadata(value: &ast::Value) -> Result<BTreeMap<String, String>, GeneratorError> {
+    match value {
+        ast::Value::TreeMap(_, _, elements) => {
+            if elements.len() % 2 != 0 {
+                return Err(GeneratorError::OddNumberOfElements(elements.len()));
+            }
+            let mut metadata = BTreeMap::new();
+            for pair in elements.chunks(2) {
+                metadata.insert(generate_string(&pair[0])?, generate_string(&pair[1])?);
+            }
+            Ok(metadata)
+        }
+        v @ _ => invalid_type!(v, ast::Type::TreeMap),
+    }
+}
+
+fn generate_access_rule(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+) -> Result<AccessRule, GeneratorError> {
+    let v = generate_value(value, Some(ast::Type::Enum), resolver)?;
+
+    let mut bytes = Vec::new();
+    let mut enc = Encoder::with_type(&mut bytes);
+    encode_any(None, &v, &mut enc);
+    match scrypto_decode(&bytes) {
+        Ok(rule) => Ok(rule),
+        Err(_) => invalid_type!(value, ast::Type::Enum),
+    }
+}
+
 fn generate_bytes(value: &ast::Value) -> Result<Vec<u8>, GeneratorError> {
     match value {
         ast::Value::Bytes(bytes) => Ok(bytes.clone()),
@@ -407,6 +529,41 @@ fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
     }
 }
 
+fn generate_precise_decimal(value: &ast::Value) -> Result<PreciseDecimal, GeneratorError> {
+    match value {
+        ast::Value::PreciseDecimal(inner) => match &**inner {
+            ast::Value::String(s) => PreciseDecimal::from_str(s)
+                .map_err(|_| GeneratorError::InvalidPreciseDecimal(s.into())),
+            v @ _ => invalid_type!(v, ast::Type::String),
+        },
+        v @ _ => invalid_type!(v, ast::Type::PreciseDecimal),
+    }
+}
+
+fn generate_i256(value: &ast::Value) -> Result<I256, GeneratorError> {
+    match value {
+        ast::Value::I256(inner) => match &**inner {
+            ast::Value::String(s) => {
+                I256::from_str(s).map_err(|_| GeneratorError::InvalidI256(s.into()))
+            }
+            v @ _ => invalid_type!(v, ast::Type::String),
+        },
+        v @ _ => invalid_type!(v, ast::Type::I256),
+    }
+}
+
+fn generate_u256(value: &ast::Value) -> Result<U256, GeneratorError> {
+    match value {
+        ast::Value::U256(inner) => match &**inner {
+            ast::Value::String(s) => {
+                U256::from_str(s).map_err(|_| GeneratorError::InvalidU256(s.into()))
+            }
+            v @ _ => invalid_type!(v, ast::Type::String),
+        },
+        v @ _ => invalid_type!(v, ast::Type::U256),
+    }
+}
+
 fn generate_package_address(value: &ast::Value) -> Result<PackageAddress, GeneratorError> {
     match value {
         ast::Value::PackageAddress(inner) => match &**inner {
@@ -516,12 +673,33 @@ fn generate_proof(
     }
 }
 
+fn generate_vault(value: &ast::Value) -> Result<VaultId, GeneratorError> {
+    match value {
+        ast::Value::Vault(inner) => match &**inner {
+            ast::Value::String(s) => scrypto::resource::Vault::from_str(s)
+                .map(|v| v.0)
+                .map_err(|_| GeneratorError::InvalidVaultId(s.into())),
+            v @ _ => invalid_type!(v, ast::Type::String),
+        },
+        v @ _ => invalid_type!(v, ast::Type::Vault),
+    }
+}
+
 fn generate_non_fungible_id(value: &ast::Value) -> Result<NonFungibleId, GeneratorError> {
     match value {
         ast::Value::NonFungibleId(inner) => match &**inner {
+            ast::Value::U32(u) => Ok(NonFungibleId::from_u32(*u)),
+            ast::Value::U64(u) => Ok(NonFungibleId::from_u64(*u)),
+            ast::Value::U128(u) => Ok(NonFungibleId::from_uuid(*u)),
             ast::Value::String(s) => NonFungibleId::from_str(s)
                 .map_err(|_| GeneratorError::InvalidNonFungibleId(s.into())),
-            v @ _ => invalid_type!(v, ast::Type::String),
+            v @ _ => invalid_type!(
+                v,
+                ast::Type::U32,
+                ast::Type::U64,
+                ast::Type::U128,
+                ast::Type::String
+            ),
         },
         v @ _ => invalid_type!(v, ast::Type::NonFungibleId),
     }
@@ -640,6 +818,18 @@ fn generate_value(
             type_id: ScryptoType::Decimal.id(),
             bytes: v.to_vec(),
         }),
+        ast::Value::PreciseDecimal(_) => generate_precise_decimal(value).map(|v| Value::Custom {
+            type_id: ScryptoType::PreciseDecimal.id(),
+            bytes: v.to_vec(),
+        }),
+        ast::Value::I256(_) => generate_i256(value).map(|v| Value::Custom {
+            type_id: ScryptoType::I256.id(),
+            bytes: v.to_vec(),
+        }),
+        ast::Value::U256(_) => generate_u256(value).map(|v| Value::Custom {
+            type_id: ScryptoType::U256.id(),
+            bytes: v.to_vec(),
+        }),
         ast::Value::PackageAddress(_) => generate_package_address(value).map(|v| Value::Custom {
             type_id: ScryptoType::PackageAddress.id(),
             bytes: v.to_vec(),
@@ -666,6 +856,10 @@ fn generate_value(
             type_id: ScryptoType::Proof.id(),
             bytes: scrypto::resource::Proof(v).to_vec(),
         }),
+        ast::Value::Vault(_) => generate_vault(value).map(|v| Value::Custom {
+            type_id: ScryptoType::Vault.id(),
+            bytes: scrypto::resource::Vault(v).to_vec(),
+        }),
         ast::Value::NonFungibleId(_) => generate_non_fungible_id(value).map(|v| Value::Custom {
             type_id: ScryptoType::NonFungibleId.id(),
             bytes: v.to_vec(),
@@ -752,12 +946,16 @@ fn generate_type_id(ty: &ast::Type) -> u8 {
         ast::Type::HashSet => TYPE_HASH_SET,
         ast::Type::HashMap => TYPE_HASH_MAP,
         ast::Type::Decimal => ScryptoType::Decimal.id(),
+        ast::Type::PreciseDecimal => ScryptoType::PreciseDecimal.id(),
+        ast::Type::I256 => ScryptoType::I256.id(),
+        ast::Type::U256 => ScryptoType::U256.id(),
         ast::Type::PackageAddress => ScryptoType::PackageAddress.id(),
         ast::Type::ComponentAddress => ScryptoType::ComponentAddress.id(),
         ast::Type::ResourceAddress => ScryptoType::ResourceAddress.id(),
         ast::Type::Hash => ScryptoType::Hash.id(),
         ast::Type::Bucket => ScryptoType::Bucket.id(),
         ast::Type::Proof => ScryptoType::Proof.id(),
+        ast::Type::Vault => ScryptoType::Vault.id(),
         ast::Type::NonFungibleId => ScryptoType::NonFungibleId.id(),
         ast::Type::NonFungibleAddress => ScryptoType::NonFungibleAddress.id(),
         ast::Type::Bytes => TYPE_VEC,
@@ -856,6 +1054,52 @@ mod tests {
                 ]
             }
         );
+        generate_value_ok!(
+            r#"Tuple(PreciseDecimal("-1.5"), I256("-7"), U256("7"))"#,
+            Value::Tuple {
+                elements: vec![
+                    Value::Custom {
+                        type_id: ScryptoType::PreciseDecimal.id(),
+                        bytes: PreciseDecimal::from_str("-1.5").unwrap().to_vec()
+                    },
+                    Value::Custom {
+                        type_id: ScryptoType::I256.id(),
+                        bytes: I256::from(-7).to_vec()
+                    },
+                    Value::Custom {
+                        type_id: ScryptoType::U256.id(),
+                        bytes: U256::from(7u32).to_vec()
+                    },
+                ]
+            }
+        );
+        generate_value_ok!(
+            r#"Tuple(NonFungibleId(1u32), NonFungibleId(2u64), NonFungibleId(3u128), NonFungibleId("\"car\""), NonFungibleId("0a0b"))"#,
+            Value::Tuple {
+                elements: vec![
+                    Value::Custom {
+                        type_id: ScryptoType::NonFungibleId.id(),
+                        bytes: NonFungibleId::from_u32(1).to_vec()
+                    },
+                    Value::Custom {
+                        type_id: ScryptoType::NonFungibleId.id(),
+                        bytes: NonFungibleId::from_u64(2).to_vec()
+                    },
+                    Value::Custom {
+                        type_id: ScryptoType::NonFungibleId.id(),
+                        bytes: NonFungibleId::from_uuid(3).to_vec()
+                    },
+                    Value::Custom {
+                        type_id: ScryptoType::NonFungibleId.id(),
+                        bytes: NonFungibleId::from_string("car").to_vec()
+                    },
+                    Value::Custom {
+                        type_id: ScryptoType::NonFungibleId.id(),
+                        bytes: NonFungibleId::from_bytes(vec![10, 11]).to_vec()
+                    },
+                ]
+            }
+        );
         generate_value_ok!(r#"Struct()"#, Value::Struct { fields: vec![] });
         generate_value_ok!(
             r#"Enum("Variant", "abc")"#,
@@ -951,6 +1195,15 @@ mod tests {
             r#"Decimal("invalid_decimal")"#,
             GeneratorError::InvalidDecimal("invalid_decimal".into())
         );
+        generate_value_error!(r#"U256("-1")"#, GeneratorError::InvalidU256("-1".into()));
+        generate_value_error!(
+            r#"Vault("abc")"#,
+            GeneratorError::InvalidVaultId("abc".into())
+        );
+        generate_value_error!(
+            r#"NonFungibleId("abc")"#,
+            GeneratorError::InvalidNonFungibleId("abc".into())
+        );
         generate_value_error!(
             r#"HashMap<String, String>("abc")"#,
             GeneratorError::OddNumberOfElements(1)
@@ -988,6 +1241,33 @@ mod tests {
                 .unwrap(),
             }
         );
+        generate_instruction_ok!(
+            r#"ASSERT_WORKTOP_IS_EMPTY;"#,
+            Instruction::AssertWorktopIsEmpty
+        );
+        generate_instruction_ok!(
+            r#"ASSERT_WORKTOP_CONTAINS_AT_MOST  Decimal("1.0")  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d");"#,
+            Instruction::AssertWorktopContainsAtMost {
+                amount: Decimal::from(1),
+                resource_address: ResourceAddress::from_str(
+                    "03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d"
+                )
+                .unwrap(),
+            }
+        );
+        generate_instruction_ok!(
+            r#"ASSERT_WORKTOP_CONTAINS_EXACT_IDS  TreeSet<NonFungibleId>(NonFungibleId("11"), NonFungibleId("22"))  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d");"#,
+            Instruction::AssertWorktopContainsExactIds {
+                ids: BTreeSet::from([
+                    NonFungibleId::from_str("11").unwrap(),
+                    NonFungibleId::from_str("22").unwrap()
+                ]),
+                resource_address: ResourceAddress::from_str(
+                    "03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d"
+                )
+                .unwrap(),
+            }
+        );
         generate_instruction_ok!(
             r#"CALL_FUNCTION  PackageAddress("01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c")  "Airdrop"  "new"  500u32  HashMap<String, U8>("key", 1u8);"#,
             Instruction::CallFunction {
@@ -1024,6 +1304,79 @@ mod tests {
                 method: "deposit_batch".into(),
             }
         );
+        generate_instruction_ok!(
+            r#"RECALL_FROM_VAULT  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1")  Vault("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa05000000")  Decimal("1.5");"#,
+            Instruction::RecallFromVault {
+                component_address: ComponentAddress::from_str(
+                    "0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into()
+                )
+                .unwrap(),
+                vault_id: (Hash([0xaa; 32]), 5),
+                amount: Decimal::from_str("1.5").unwrap(),
+            }
+        );
+        generate_instruction_ok!(
+            r#"FREEZE_VAULT  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1")  Vault("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa05000000");"#,
+            Instruction::FreezeVault {
+                component_address: ComponentAddress::from_str(
+                    "0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into()
+                )
+                .unwrap(),
+                vault_id: (Hash([0xaa; 32]), 5),
+            }
+        );
+        generate_instruction_ok!(
+            r#"PUBLISH_PACKAGE  Bytes("0061736d")  TreeMap<String, String>("name", "Hello", "url", "https://hello.io");"#,
+            Instruction::PublishPackage {
+                code: vec![0x00, 0x61, 0x73, 0x6d],
+                metadata: BTreeMap::from([
+                    ("name".to_owned(), "Hello".to_owned()),
+                    ("url".to_owned(), "https://hello.io".to_owned()),
+                ]),
+                owner: None,
+            }
+        );
+        generate_instruction_ok!(
+            r#"PUBLISH_PACKAGE  Bytes("0061736d")  TreeMap<String, String>()  Enum("AllowAll");"#,
+            Instruction::PublishPackage {
+                code: vec![0x00, 0x61, 0x73, 0x6d],
+                metadata: BTreeMap::new(),
+                owner: Some(AccessRule::AllowAll),
+            }
+        );
+        generate_instruction_ok!(
+            r#"UPGRADE_PACKAGE  PackageAddress("01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c")  Bytes("0061736d");"#,
+            Instruction::UpgradePackage {
+                package_address: PackageAddress::from_str(
+                    "01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c".into()
+                )
+                .unwrap(),
+                code: vec![0x00, 0x61, 0x73, 0x6d],
+            }
+        );
+        generate_instruction_ok!(
+            r#"SET_COMPONENT_METADATA  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1")  "name"  "Hello";"#,
+            Instruction::SetComponentMetadata {
+                component_address: ComponentAddress::from_str(
+                    "0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into()
+                )
+                .unwrap(),
+                key: "name".into(),
+                value: "Hello".into(),
+            }
+        );
+        generate_instruction_ok!(
+            r#"SET_PACKAGE_METADATA  PackageAddress("01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c")  "name"  "Hello";"#,
+            Instruction::SetPackageMetadata {
+                package_address: PackageAddress::from_str(
+                    "01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c".into()
+                )
+                .unwrap(),
+                key: "name".into(),
+                value: "Hello".into(),
+            }
+        );
+        generate_instruction_ok!(r#"NONCE 5u64;"#, Instruction::Nonce { nonce: 5 });
     }
 
     #[test]
@@ -1140,8 +1493,16 @@ mod tests {
                         .unwrap(),
                         method: "deposit_batch".into(),
                     },
-                    Instruction::PublishPackage { code: code.clone() },
-                    Instruction::PublishPackage { code: code.clone() }
+                    Instruction::PublishPackage {
+                        code: code.clone(),
+                        metadata: BTreeMap::new(),
+                        owner: None,
+                    },
+                    Instruction::PublishPackage {
+                        code: code.clone(),
+                        metadata: BTreeMap::new(),
+                        owner: None,
+                    }
                 ]
             }
         );
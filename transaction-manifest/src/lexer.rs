@@ -51,12 +51,16 @@ pub enum TokenKind {
     HashSet,
     HashMap,
     Decimal,
+    PreciseDecimal,
+    I256,
+    U256,
     PackageAddress,
     ComponentAddress,
     ResourceAddress,
     Hash,
     Bucket,
     Proof,
+    Vault,
     NonFungibleId,
     NonFungibleAddress,
 
@@ -85,6 +89,9 @@ pub enum TokenKind {
     AssertWorktopContains,
     AssertWorktopContainsByAmount,
     AssertWorktopContainsByIds,
+    AssertWorktopIsEmpty,
+    AssertWorktopContainsAtMost,
+    AssertWorktopContainsExactIds,
     PopFromAuthZone,
     PushToAuthZone,
     ClearAuthZone,
@@ -98,6 +105,13 @@ pub enum TokenKind {
     CallMethod,
     CallMethodWithAllResources,
     PublishPackage,
+    UpgradePackage,
+    RecallFromVault,
+    FreezeVault,
+    UnfreezeVault,
+    SetComponentMetadata,
+    SetPackageMetadata,
+    Nonce,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -106,6 +120,13 @@ pub struct Token {
     pub span: Span,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    /// The comment text, excluding the leading `#`
+    pub text: String,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LexerError {
     UnexpectedEof,
@@ -121,6 +142,8 @@ pub struct Lexer {
     text: Vec<char>,
     /// A 0-indexed cursor indicating the next char
     current: usize,
+    /// The comments skipped so far
+    comments: Vec<Comment>,
 }
 
 pub fn tokenize(s: &str) -> Result<Vec<Token>, LexerError> {
@@ -136,14 +159,29 @@ pub fn tokenize(s: &str) -> Result<Vec<Token>, LexerError> {
     Ok(tokens)
 }
 
+/// Tokenizes the input text, keeping the comments which are otherwise skipped.
+pub fn tokenize_with_comments(s: &str) -> Result<(Vec<Token>, Vec<Comment>), LexerError> {
+    let mut lexer = Lexer::new(s);
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token()? {
+        tokens.push(token);
+    }
+    Ok((tokens, lexer.comments))
+}
+
 impl Lexer {
     pub fn new(text: &str) -> Self {
         Self {
             text: text.chars().collect(),
             current: 0,
+            comments: Vec::new(),
         }
     }
 
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
     pub fn is_eof(&self) -> bool {
         self.current == self.text.len()
     }
@@ -169,14 +207,9 @@ impl Lexer {
 
     pub fn next_token(&mut self) -> Result<Option<Token>, LexerError> {
         // skip comment and whitespace
-        let mut in_comment = false;
         while !self.is_eof() {
-            if in_comment {
-                if self.advance()? == '\n' {
-                    in_comment = false;
-                }
-            } else if self.peek()? == '#' {
-                in_comment = true;
+            if self.peek()? == '#' {
+                self.skip_comment()?;
             } else if Self::is_whitespace(self.peek()?) {
                 self.advance()?;
             } else {
@@ -203,6 +236,25 @@ impl Lexer {
         .map(Option::from)
     }
 
+    fn skip_comment(&mut self) -> Result<(), LexerError> {
+        let start = self.current;
+        assert_eq!(self.advance()?, '#');
+
+        let mut text = String::new();
+        while !self.is_eof() && self.peek()? != '\n' {
+            text.push(self.advance()?);
+        }
+
+        self.comments.push(Comment {
+            text,
+            span: Span {
+                start: self.index_to_coordinate(start),
+                end: self.index_to_coordinate(self.current - 1),
+            },
+        });
+        Ok(())
+    }
+
     // TODO: consider using DFA
     fn tokenize_number(&mut self) -> Result<Token, LexerError> {
         let start = self.current;
@@ -381,12 +433,16 @@ impl Lexer {
             "HashSet" => Ok(TokenKind::HashSet),
             "HashMap" => Ok(TokenKind::HashMap),
             "Decimal" => Ok(TokenKind::Decimal),
+            "PreciseDecimal" => Ok(TokenKind::PreciseDecimal),
+            "I256" => Ok(TokenKind::I256),
+            "U256" => Ok(TokenKind::U256),
             "PackageAddress" => Ok(TokenKind::PackageAddress),
             "ComponentAddress" => Ok(TokenKind::ComponentAddress),
             "ResourceAddress" => Ok(TokenKind::ResourceAddress),
             "Hash" => Ok(TokenKind::Hash),
             "Bucket" => Ok(TokenKind::Bucket),
             "Proof" => Ok(TokenKind::Proof),
+            "Vault" => Ok(TokenKind::Vault),
             "NonFungibleId" => Ok(TokenKind::NonFungibleId),
             "NonFungibleAddress" => Ok(TokenKind::NonFungibleAddress),
 
@@ -404,6 +460,9 @@ impl Lexer {
             "ASSERT_WORKTOP_CONTAINS" => Ok(TokenKind::AssertWorktopContains),
             "ASSERT_WORKTOP_CONTAINS_BY_AMOUNT" => Ok(TokenKind::AssertWorktopContainsByAmount),
             "ASSERT_WORKTOP_CONTAINS_BY_IDS" => Ok(TokenKind::AssertWorktopContainsByIds),
+            "ASSERT_WORKTOP_IS_EMPTY" => Ok(TokenKind::AssertWorktopIsEmpty),
+            "ASSERT_WORKTOP_CONTAINS_AT_MOST" => Ok(TokenKind::AssertWorktopContainsAtMost),
+            "ASSERT_WORKTOP_CONTAINS_EXACT_IDS" => Ok(TokenKind::AssertWorktopContainsExactIds),
             "POP_FROM_AUTH_ZONE" => Ok(TokenKind::PopFromAuthZone),
             "PUSH_TO_AUTH_ZONE" => Ok(TokenKind::PushToAuthZone),
             "CLEAR_AUTH_ZONE" => Ok(TokenKind::ClearAuthZone),
@@ -419,6 +478,13 @@ impl Lexer {
             "CALL_METHOD" => Ok(TokenKind::CallMethod),
             "CALL_METHOD_WITH_ALL_RESOURCES" => Ok(TokenKind::CallMethodWithAllResources),
             "PUBLISH_PACKAGE" => Ok(TokenKind::PublishPackage),
+            "UPGRADE_PACKAGE" => Ok(TokenKind::UpgradePackage),
+            "RECALL_FROM_VAULT" => Ok(TokenKind::RecallFromVault),
+            "FREEZE_VAULT" => Ok(TokenKind::FreezeVault),
+            "UNFREEZE_VAULT" => Ok(TokenKind::UnfreezeVault),
+            "SET_COMPONENT_METADATA" => Ok(TokenKind::SetComponentMetadata),
+            "SET_PACKAGE_METADATA" => Ok(TokenKind::SetPackageMetadata),
+            "NONCE" => Ok(TokenKind::Nonce),
 
             s @ _ => Err(LexerError::UnknownIdentifier(s.into())),
         }
@@ -555,6 +621,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_comment_text() {
+        let (tokens, comments) =
+            tokenize_with_comments("# first\nCLEAR_AUTH_ZONE; #second\n#").unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(
+            comments
+                .iter()
+                .map(|c| (c.span.start.0, c.text.as_str()))
+                .collect::<Vec<(usize, &str)>>(),
+            vec![(1, " first"), (2, "second"), (3, "")]
+        );
+    }
+
     #[test]
     fn test_string() {
         lex_ok!(
@@ -1,10 +1,12 @@
 pub mod ast;
 pub mod decompiler;
+pub mod formatter;
 pub mod generator;
 pub mod lexer;
 pub mod parser;
 
-pub use decompiler::{decompile, DecompileError};
+pub use decompiler::{decompile, decompile_with_abi, DecompileError};
+pub use formatter::{format, FormatError};
 
 use radix_engine::model::Transaction;
 
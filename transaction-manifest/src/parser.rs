@@ -99,6 +99,17 @@ impl Parser {
                 ids: self.parse_value()?,
                 resource_address: self.parse_value()?,
             },
+            TokenKind::AssertWorktopIsEmpty => Instruction::AssertWorktopIsEmpty,
+            TokenKind::AssertWorktopContainsAtMost => Instruction::AssertWorktopContainsAtMost {
+                amount: self.parse_value()?,
+                resource_address: self.parse_value()?,
+            },
+            TokenKind::AssertWorktopContainsExactIds => {
+                Instruction::AssertWorktopContainsExactIds {
+                    ids: self.parse_value()?,
+                    resource_address: self.parse_value()?,
+                }
+            }
             TokenKind::PopFromAuthZone => Instruction::PopFromAuthZone {
                 new_proof: self.parse_value()?,
             },
@@ -162,6 +173,46 @@ impl Parser {
             },
             TokenKind::PublishPackage => Instruction::PublishPackage {
                 code: self.parse_value()?,
+                metadata: if self.peek()?.kind != TokenKind::Semicolon {
+                    Some(self.parse_value()?)
+                } else {
+                    None
+                },
+                owner: if self.peek()?.kind != TokenKind::Semicolon {
+                    Some(self.parse_value()?)
+                } else {
+                    None
+                },
+            },
+            TokenKind::UpgradePackage => Instruction::UpgradePackage {
+                package_address: self.parse_value()?,
+                code: self.parse_value()?,
+            },
+            TokenKind::RecallFromVault => Instruction::RecallFromVault {
+                component_address: self.parse_value()?,
+                vault: self.parse_value()?,
+                amount: self.parse_value()?,
+            },
+            TokenKind::FreezeVault => Instruction::FreezeVault {
+                component_address: self.parse_value()?,
+                vault: self.parse_value()?,
+            },
+            TokenKind::UnfreezeVault => Instruction::UnfreezeVault {
+                component_address: self.parse_value()?,
+                vault: self.parse_value()?,
+            },
+            TokenKind::SetComponentMetadata => Instruction::SetComponentMetadata {
+                component_address: self.parse_value()?,
+                key: self.parse_value()?,
+                value: self.parse_value()?,
+            },
+            TokenKind::SetPackageMetadata => Instruction::SetPackageMetadata {
+                package_address: self.parse_value()?,
+                key: self.parse_value()?,
+                value: self.parse_value()?,
+            },
+            TokenKind::Nonce => Instruction::Nonce {
+                nonce: self.parse_value()?,
             },
             _ => {
                 return Err(ParserError::UnexpectedToken(token));
@@ -203,12 +254,16 @@ impl Parser {
             TokenKind::HashSet => self.parse_hash_set(),
             TokenKind::HashMap => self.parse_hash_map(),
             TokenKind::Decimal
+            | TokenKind::PreciseDecimal
+            | TokenKind::I256
+            | TokenKind::U256
             | TokenKind::PackageAddress
             | TokenKind::ComponentAddress
             | TokenKind::ResourceAddress
             | TokenKind::Hash
             | TokenKind::Bucket
             | TokenKind::Proof
+            | TokenKind::Vault
             | TokenKind::NonFungibleId
             | TokenKind::NonFungibleAddress => self.parse_scrypto_types(),
             TokenKind::Bytes => self.parse_bytes(),
@@ -338,6 +393,9 @@ impl Parser {
         let token = self.advance()?;
         match token.kind {
             TokenKind::Decimal => Ok(Value::Decimal(self.parse_values_one()?.into())),
+            TokenKind::PreciseDecimal => Ok(Value::PreciseDecimal(self.parse_values_one()?.into())),
+            TokenKind::I256 => Ok(Value::I256(self.parse_values_one()?.into())),
+            TokenKind::U256 => Ok(Value::U256(self.parse_values_one()?.into())),
             TokenKind::PackageAddress => Ok(Value::PackageAddress(self.parse_values_one()?.into())),
             TokenKind::ComponentAddress => {
                 Ok(Value::ComponentAddress(self.parse_values_one()?.into()))
@@ -348,6 +406,7 @@ impl Parser {
             TokenKind::Hash => Ok(Value::Hash(self.parse_values_one()?.into())),
             TokenKind::Bucket => Ok(Value::Bucket(self.parse_values_one()?.into())),
             TokenKind::Proof => Ok(Value::Proof(self.parse_values_one()?.into())),
+            TokenKind::Vault => Ok(Value::Vault(self.parse_values_one()?.into())),
             TokenKind::NonFungibleId => Ok(Value::NonFungibleId(self.parse_values_one()?.into())),
             TokenKind::NonFungibleAddress => {
                 Ok(Value::NonFungibleAddress(self.parse_values_one()?.into()))
@@ -436,13 +495,18 @@ impl Parser {
             TokenKind::HashSet => Ok(Type::HashSet),
             TokenKind::HashMap => Ok(Type::HashMap),
             TokenKind::Decimal => Ok(Type::Decimal),
+            TokenKind::PreciseDecimal => Ok(Type::PreciseDecimal),
+            TokenKind::I256 => Ok(Type::I256),
+            TokenKind::U256 => Ok(Type::U256),
             TokenKind::PackageAddress => Ok(Type::PackageAddress),
             TokenKind::ComponentAddress => Ok(Type::ComponentAddress),
             TokenKind::ResourceAddress => Ok(Type::ResourceAddress),
             TokenKind::Hash => Ok(Type::Hash),
             TokenKind::Bucket => Ok(Type::Bucket),
             TokenKind::Proof => Ok(Type::Proof),
+            TokenKind::Vault => Ok(Type::Vault),
             TokenKind::NonFungibleId => Ok(Type::NonFungibleId),
+            TokenKind::NonFungibleAddress => Ok(Type::NonFungibleAddress),
             _ => Err(ParserError::UnexpectedToken(token)),
         }
     }
@@ -517,9 +581,15 @@ mod tests {
     fn test_enum() {
         parse_value_ok!(
             r#"Enum("Variant", "Hello", 123u8)"#,
-            Value::Enum("Variant".to_string(), vec![Value::String("Hello".into()), Value::U8(123)],)
+            Value::Enum(
+                "Variant".to_string(),
+                vec![Value::String("Hello".into()), Value::U8(123)],
+            )
+        );
+        parse_value_ok!(
+            r#"Enum("Variant")"#,
+            Value::Enum("Variant".to_string(), vec![])
         );
-        parse_value_ok!(r#"Enum("Variant")"#, Value::Enum("Variant".to_string(), vec![]));
     }
 
     #[test]
@@ -672,6 +742,36 @@ mod tests {
                 ),
             }
         );
+        parse_instruction_ok!(
+            r#"ASSERT_WORKTOP_IS_EMPTY;"#,
+            Instruction::AssertWorktopIsEmpty
+        );
+        parse_instruction_ok!(
+            r#"ASSERT_WORKTOP_CONTAINS_AT_MOST  Decimal("1.0")  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d");"#,
+            Instruction::AssertWorktopContainsAtMost {
+                amount: Value::Decimal(Value::String("1.0".into()).into()),
+                resource_address: Value::ResourceAddress(
+                    Value::String("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d".into())
+                        .into()
+                ),
+            }
+        );
+        parse_instruction_ok!(
+            r#"ASSERT_WORKTOP_CONTAINS_EXACT_IDS  TreeSet<NonFungibleId>(NonFungibleId("11"), NonFungibleId("22"))  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d");"#,
+            Instruction::AssertWorktopContainsExactIds {
+                ids: Value::TreeSet(
+                    Type::NonFungibleId,
+                    vec![
+                        Value::NonFungibleId(Value::String("11".into()).into()),
+                        Value::NonFungibleId(Value::String("22".into()).into())
+                    ]
+                ),
+                resource_address: Value::ResourceAddress(
+                    Value::String("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d".into())
+                        .into()
+                ),
+            }
+        );
         parse_instruction_ok!(
             r#"CREATE_PROOF_FROM_BUCKET  Bucket("xrd_bucket")  Proof("admin_auth");"#,
             Instruction::CreateProofFromBucket {
@@ -749,5 +849,27 @@ mod tests {
                 method: Value::String("deposit_batch".into()),
             }
         );
+        parse_instruction_ok!(
+            r#"UNFREEZE_VAULT  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1")  Vault("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa05000000");"#,
+            Instruction::UnfreezeVault {
+                component_address: Value::ComponentAddress(
+                    Value::String("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into())
+                        .into()
+                ),
+                vault: Value::Vault(
+                    Value::String(
+                        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa05000000"
+                            .into()
+                    )
+                    .into()
+                ),
+            }
+        );
+        parse_instruction_ok!(
+            r#"NONCE 5u64;"#,
+            Instruction::Nonce {
+                nonce: Value::U64(5),
+            }
+        );
     }
 }